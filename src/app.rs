@@ -1,22 +1,98 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use crossterm::event::{KeyCode, KeyModifiers};
+use regex::Regex;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::error::TryRecvError;
 
+use crate::clipboard;
+use crate::columns::table_columns;
+use crate::config::SortKey;
+use crate::config::{Config, PodStatusDisplay, ThemePreset};
+use crate::error::QuiError;
 use crate::events::InputEvent;
 use crate::kube_client::{
-    ContextInfo, DeploymentInfo, KubeClient, PodInfo, PodWatcher, ServiceInfo, TerminalSession,
+    kubectl_identity, node_shell_command, token_file_identity, ConfigMapDetail, ConfigMapInfo,
+    ConnectionDetail, ContainerSummary, ContainerUsage, ContextInfo, DeletePropagation,
+    DeploymentDetail, DeploymentHistory, DeploymentInfo, DisconnectedClient, EffectiveKubeconfig,
+    EnvValue, EventInfo, EventWatcher, KubeApi, KubeClient, LogQuery, NamespaceSummary, NodeDetail,
+    PodDetail, PodInfo, PodLogWatcher, PodWatcher, PortForwardHandle, ResourceKind, RolloutState,
+    RolloutStatus, RolloutWatcher, SearchResult, SecretDetail, SecretInfo, ServiceDetail,
+    ServiceInfo, TerminalSession, TerminationInfo, SERVICE_ACCOUNT_TOKEN_FILE,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum View {
     Pods,
     Deployments,
     Services,
+    Events,
+    ConfigMaps,
+    Secrets,
     Logs,
     Clusters,
     Namespaces,
     Help,
     Terminal,
+    Activity,
+    PortForwards,
+    Detail,
+}
+
+/// Views the `:` switcher offers, by name, with short aliases it also accepts
+pub const SWITCHABLE_VIEWS: &[(&str, &[&str], View)] = &[
+    ("pods", &["po"], View::Pods),
+    ("deployments", &["deploy"], View::Deployments),
+    ("services", &["svc"], View::Services),
+    ("clusters", &["contexts", "ctx"], View::Clusters),
+    ("namespaces", &["ns"], View::Namespaces),
+    ("events", &["ev"], View::Events),
+    ("configmaps", &["cm"], View::ConfigMaps),
+    ("secrets", &["secret"], View::Secrets),
+    ("activity", &[], View::Activity),
+    ("portforwards", &["pf", "forwards"], View::PortForwards),
+    ("help", &[], View::Help),
+];
+
+impl View {
+    /// The switchable view called `name` or one of its aliases, ignoring case
+    pub fn named(name: &str) -> Option<View> {
+        let name = name.trim().to_lowercase();
+        SWITCHABLE_VIEWS
+            .iter()
+            .find(|(view_name, aliases, _)| *view_name == name || aliases.contains(&name.as_str()))
+            .map(|(_, _, view)| *view)
+    }
+
+    /// Views that list cluster resources, and so have nothing to show while disconnected
+    pub fn needs_cluster(&self) -> bool {
+        matches!(
+            self,
+            View::Pods
+                | View::Deployments
+                | View::Services
+                | View::Events
+                | View::ConfigMaps
+                | View::Secrets
+                | View::Namespaces
+                | View::Logs
+        )
+    }
+}
+
+/// What opens once a container of a multi-container pod is picked
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContainerAction {
+    Logs { follow: bool },
+    EmbeddedShell,
+    NativeShell,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -24,137 +100,749 @@ pub enum InputMode {
     Normal,
     Scale,
     TerminalChoice,
+    ContainerPicker,
+    NamespacePicker,
+    GlobalSearch,
+    Rollout,
+    EnvEdit,
+    RowPopup,
+    ConfirmContextSwitch,
+    ViewPicker,
+    MetadataEdit,
+    LogGrep,
+    ConfirmCordon,
+    ConfirmJobCleanup,
+    ConfirmDelete,
+    ConfirmRollback,
+    ConfirmNodeShell,
+    ConfirmDebugContainer,
+    ConfirmScaleToZero,
+    PortForward,
+    LogInclude,
+    LogExclude,
+    Filter,
+}
+
+/// Structured description of a single resource, rendered by the Detail view
+#[derive(Debug, Clone)]
+pub enum Detail {
+    Pod(PodDetail),
+    Node(NodeDetail),
+    Deployment(DeploymentDetail),
+    Service(ServiceDetail),
+    ConfigMap(ConfigMapDetail),
+    Secret(SecretDetail),
+    Connection(ConnectionDetail),
+    Namespace(NamespaceSummary),
+    Yaml(YamlDocument),
+    History(DeploymentHistory),
+    Describe(DescribeOutput),
+    Kubeconfig(EffectiveKubeconfig),
+}
+
+/// What `kubectl describe` printed for a resource, shown as-is
+#[derive(Debug, Clone)]
+pub struct DescribeOutput {
+    pub kind: String,
+    pub name: String,
+    pub text: String,
+}
+
+/// A resource shown as YAML, with the noisy parts hidden unless asked for
+#[derive(Debug, Clone)]
+pub struct YamlDocument {
+    pub kind: ResourceKind,
+    pub name: String,
+    pub object: serde_json::Value,
+    pub show_managed_fields: bool,
+    pub show_status: bool,
+}
+
+impl YamlDocument {
+    pub fn to_yaml(&self) -> String {
+        let mut object = self.object.clone();
+        if let Some(map) = object.as_object_mut() {
+            if !self.show_status {
+                map.remove("status");
+            }
+            if !self.show_managed_fields {
+                if let Some(metadata) = map.get_mut("metadata").and_then(|m| m.as_object_mut()) {
+                    metadata.remove("managedFields");
+                }
+            }
+        }
+        serde_yaml::to_string(&object).unwrap_or_else(|e| format!("# Failed to render YAML: {}", e))
+    }
+}
+
+/// A program that needs the real terminal, run by the main loop with the TUI suspended
+#[derive(Debug, Clone)]
+pub enum ExternalCommand {
+    /// Open a file in `$VISUAL`/`$EDITOR` (falling back to `vi`)
+    EditFile(PathBuf),
+    /// Pipe `text` into `pager`, e.g. to search a long log with less
+    Page { pager: String, text: String },
+}
+
+impl ExternalCommand {
+    /// Run to completion; blocks until the program exits
+    pub fn run(&self) -> Result<()> {
+        match self {
+            ExternalCommand::EditFile(path) => {
+                let editor = std::env::var("VISUAL")
+                    .or_else(|_| std::env::var("EDITOR"))
+                    .unwrap_or_else(|_| "vi".to_string());
+                // Through the shell so editors with arguments (e.g. "code -w") work
+                let status = Command::new("sh")
+                    .arg("-c")
+                    .arg(format!("{} \"$1\"", editor))
+                    .arg("sh")
+                    .arg(path)
+                    .status()?;
+                if !status.success() {
+                    anyhow::bail!("{} exited with {}", editor, status);
+                }
+                Ok(())
+            }
+            ExternalCommand::Page { pager, text } => {
+                // The pager reads keys from the tty, so stdin is free for the text
+                let mut child = Command::new("sh")
+                    .arg("-c")
+                    .arg(pager)
+                    .stdin(Stdio::piped())
+                    .spawn()?;
+                if let Some(mut stdin) = child.stdin.take() {
+                    // Quitting before the end closes the pipe; that's not an error
+                    let _ = stdin.write_all(text.as_bytes());
+                }
+                let status = child.wait()?;
+                if !status.success() {
+                    anyhow::bail!("{} exited with {}", pager, status);
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// How long a destructive action waits before running, so Esc can still abort it
+pub const COUNTDOWN: Duration = Duration::from_secs(3);
+
+/// How long to wait for an ephemeral debug container to start before giving up
+pub const DEBUG_CONTAINER_START_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How many lines back a log grep (`g`) searches. The API can't filter logs, so
+/// this many are fetched and matched locally instead of the usual short tail
+pub const LOG_GREP_TAIL_LINES: i64 = 50_000;
+
+/// Why grep, previous-instance logs and container switching are refused while
+/// every container is followed at once
+const MERGED_LOGS_UNSUPPORTED: &str =
+    "Not available while following all containers; filter with i/x instead";
+
+/// Follow mode counts as stalled once the last successful refresh is this old;
+/// the main loop refreshes every 2s, so this allows a couple of slow ones
+pub const LOG_STALL_AFTER: Duration = Duration::from_secs(6);
+
+/// Recent log lines of one pod, shown under the Pods list in preview mode
+#[derive(Debug, Clone)]
+pub struct LogPreview {
+    pub pod: String,
+    pub logs: String,
+}
+
+/// Usage samples kept per container for the pod detail's sparklines
+pub const USAGE_SAMPLES: usize = 40;
+
+/// How often the main loop samples the open pod's usage; metrics-server itself
+/// only rescrapes every 15s by default, so sampling faster just repeats values
+pub const USAGE_SAMPLE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Recent CPU and memory samples of the pod open in the detail, oldest first
+#[derive(Debug, Clone, Default)]
+pub struct UsageHistory {
+    pub namespace: String,
+    pub pod: String,
+    pub containers: Vec<ContainerUsageHistory>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ContainerUsageHistory {
+    pub container: String,
+    pub cpu_millis: VecDeque<u64>,
+    pub memory_bytes: VecDeque<u64>,
+}
+
+impl UsageHistory {
+    /// Append a sample, dropping the oldest once `USAGE_SAMPLES` are kept
+    fn record(&mut self, usage: &[ContainerUsage]) {
+        for sample in usage {
+            let index = match self
+                .containers
+                .iter()
+                .position(|c| c.container == sample.container)
+            {
+                Some(index) => index,
+                None => {
+                    self.containers.push(ContainerUsageHistory {
+                        container: sample.container.clone(),
+                        ..Default::default()
+                    });
+                    self.containers.len() - 1
+                }
+            };
+            let history = &mut self.containers[index];
+            history.cpu_millis.push_back(sample.cpu_millis);
+            history.memory_bytes.push_back(sample.memory_bytes);
+            if history.cpu_millis.len() > USAGE_SAMPLES {
+                history.cpu_millis.pop_front();
+                history.memory_bytes.pop_front();
+            }
+        }
+    }
+}
+
+/// Returning to a view reloads it once its data is older than this, so quick
+/// back-and-forth between views reuses what's loaded instead of hitting the API
+pub const FOCUS_REFRESH_AFTER: Duration = Duration::from_secs(10);
+
+/// How much one `<`/`>` press changes a column's relative width
+const RESIZE_STEP: i32 = 5;
+
+/// Context name shown when running in a pod without a kubeconfig, on the pod's
+/// service account
+pub const IN_CLUSTER_CONTEXT: &str = "in-cluster";
+
+/// A destructive action waiting out the countdown
+#[derive(Debug, Clone, PartialEq)]
+pub enum PendingAction {
+    ScaleDeployment { name: String, replicas: i32 },
+    ScaleDeployments { names: Vec<String>, replicas: i32 },
+    DeleteCompletedJobs(Vec<String>),
+}
+
+impl PendingAction {
+    pub fn describe(&self) -> String {
+        match self {
+            PendingAction::ScaleDeployment { name, replicas } => {
+                format!("Scaling deployment {} to {} replicas", name, replicas)
+            }
+            PendingAction::ScaleDeployments { names, replicas } => format!(
+                "Scaling {} deployments ({}) to {} replicas",
+                names.len(),
+                names.join(", "),
+                replicas
+            ),
+            PendingAction::DeleteCompletedJobs(jobs) => {
+                format!("Deleting {} completed jobs", jobs.len())
+            }
+        }
+    }
+}
+
+/// The stages of a context switch. The main loop runs one per frame, so the
+/// footer shows which one a slow or unreachable cluster is stuck on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContextSwitchStep {
+    /// Write the new current-context to the kubeconfig
+    Switching,
+    /// Build a client for the new context
+    Reconnecting,
+    /// List namespaces, which doubles as the connectivity check
+    ListingNamespaces,
+    /// Load the Pods view of the new cluster
+    LoadingPods,
+}
+
+/// A context switch in progress
+#[derive(Debug, Clone)]
+pub struct ContextSwitch {
+    pub context: ContextInfo,
+    pub step: ContextSwitchStep,
+}
+
+/// An ephemeral debug container that was added, waited on until it runs so it
+/// can be attached to
+#[derive(Debug, Clone)]
+pub struct DebugStart {
+    pub namespace: String,
+    pub pod: String,
+    pub container: String,
+    pub started: Instant,
+    pub last_check: Option<Instant>,
+}
+
+/// A queued action and when it fires
+#[derive(Debug, Clone)]
+pub struct Countdown {
+    pub action: PendingAction,
+    pub deadline: Instant,
+}
+
+impl Countdown {
+    /// Whole seconds left, rounded up so the display never shows 0 while waiting
+    pub fn seconds_left(&self) -> u64 {
+        let left = self.deadline.saturating_duration_since(Instant::now());
+        left.as_millis().div_ceil(1000) as u64
+    }
+}
+
+/// A mutating action performed during the session, shown in the Activity view
+#[derive(Debug, Clone)]
+pub struct ActivityEntry {
+    pub timestamp: DateTime<Utc>,
+    pub context: String,
+    pub namespace: String,
+    pub action: String,
 }
 
 pub struct App {
-    pub client: KubeClient,
+    pub client: Box<dyn KubeApi>,
+    // False until a context's API server has answered; only the Clusters view works meanwhile
+    pub connected: bool,
+    // The current context skips server certificate checks (insecure-skip-tls-verify)
+    pub tls_insecure: bool,
+    // Who the bearer token file (`token_file`, or the pod's service account in a
+    // cluster) authenticates as, for the header
+    pub token_identity: Option<String>,
+    pub config: Config,
+    // Color preset in use, starting from `theme` in the config; `T` cycles it
+    pub theme: ThemePreset,
     pub current_view: View,
+    // Listed namespaces, without system ones while those are hidden
     pub namespaces: Vec<String>,
+    all_namespaces: Vec<String>,
     pub current_namespace: String,
     pub namespace_index: usize,
     pub contexts: Vec<ContextInfo>,
     pub context_index: usize,
     pub current_context: String,
+    // Set by `Q`: print the context and namespace to stdout after leaving the TUI
+    pub print_location_on_exit: bool,
     pub pods: Vec<PodInfo>,
     pub pod_index: usize,
+    // Pods view ordered by node, with a header row per node
+    pub group_pods_by_node: bool,
+    // Pods pinned with `P`, by name: kept on top of the list, and listed as
+    // missing when a refresh or namespace switch loses them
+    pub pinned_pods: Vec<String>,
+    // Split the Pods view to follow the selected pod's logs underneath (`p`)
+    pub show_log_preview: bool,
+    pub log_preview: Option<LogPreview>,
     pub deployments: Vec<DeploymentInfo>,
     pub deployment_index: usize,
     pub services: Vec<ServiceInfo>,
     pub service_index: usize,
+    // Name filter of the Pods, Deployments and Services lists (`/`), by view
+    pub list_filters: HashMap<View, String>,
+    pub events: Vec<EventInfo>,
+    pub event_index: usize,
+    pub configmaps: Vec<ConfigMapInfo>,
+    pub configmap_index: usize,
+    pub secrets: Vec<SecretInfo>,
+    pub secret_index: usize,
+    // Secret values stay masked in the detail until `x`; reset whenever it closes
+    pub secret_revealed: bool,
+    // Events follow mode: new events stream in at the top instead of waiting for `r`
+    pub events_follow: bool,
+    pub event_watcher: Option<EventWatcher>,
+    // Recent Warning events in the current namespace, shown as a tab bar badge
+    pub warning_count: usize,
     pub logs: String,
     pub logs_scroll: usize,
     pub logs_follow: bool,
+    // Follow is on but scrolled away from the bottom: refreshes keep coming in
+    // without moving the view until `G`/`End` jumps back down
+    pub logs_paused: bool,
+    // Container whose logs are shown; None lets the API pick for single-container pods
+    pub logs_container: Option<String>,
+    // Set while every container's log is followed at once (`A`): lines stream into
+    // `logs` prefixed with their container instead of being refetched
+    pub log_watcher: Option<PodLogWatcher>,
+    // When the shown logs were last fetched successfully, to flag a stalled follow
+    pub logs_fetched_at: Option<Instant>,
     pub logs_pod_name: Option<String>,
+    // Index of the first line that arrived in the latest follow refresh
+    pub logs_new_from: Option<usize>,
+    // Show the container instance from before the latest restart
+    pub logs_previous: bool,
+    // Only lines containing this are shown, searched over a longer tail (`g`)
+    pub logs_grep: Option<String>,
+    // Regex filters applied when drawing the log: only lines matching the include
+    // one (`i`), minus those matching the exclude one (`x`)
+    pub logs_include: Option<Regex>,
+    pub logs_exclude: Option<Regex>,
+    // Wrap long log lines (`w`); unwrapped, they're cut at the pane's edge
+    pub logs_wrap: bool,
     pub error_message: Option<String>,
+    // The most recent error, kept after the footer stops showing it so `Y` can copy it
+    pub last_error: Option<String>,
     pub input_mode: InputMode,
     pub input_buffer: String,
     pub status_message: String,
-    pub terminal_session: Option<Arc<Mutex<TerminalSession>>>,
-    pub terminal_pod_name: Option<String>,
+    // Embedded terminals stay open until closed, so several pods can have a shell at once
+    pub terminal_sessions: Vec<Arc<Mutex<TerminalSession>>>,
+    pub active_terminal: usize,
     pub terminal_scroll: usize,
     pub terminal_choice_selection: usize,
+    // Set while the container picker is open, with the action the picked container is for
+    pub container_picker: Option<ContainerAction>,
+    pub container_picker_index: usize,
+    // Shell found in each image when `terminal.detect_shell` is on, so each image is probed once
+    pub shell_cache: HashMap<String, String>,
+    // When each view was last loaded, so returning to it within
+    // `FOCUS_REFRESH_AFTER` doesn't reload
+    view_loaded_at: HashMap<View, Instant>,
+    // Selection within the filtered namespace quick-picker
+    pub namespace_picker_index: usize,
+    // Selection within the `:` view switcher's matches
+    pub view_picker_index: usize,
+    // Resource a label/annotation prompt (`=`) applies to, fixed when it opened
+    pub metadata_target: Option<(ResourceKind, String)>,
+    // Node awaiting the cordon confirmation
+    pub cordon_node: Option<String>,
+    // Deployment awaiting the delete confirmation, and what to do with its pods
+    pub delete_target: Option<String>,
+    pub delete_propagation: DeletePropagation,
+    // Completed Jobs awaiting the bulk delete confirmation
+    pub completed_jobs: Vec<String>,
+    // Deployments marked with Space in the Deployments view; Scale acts on all of them
+    pub marked_deployments: Vec<String>,
+    // Marked deployments awaiting the confirmation to scale them all to zero
+    pub scale_to_zero_targets: Vec<String>,
+    // Requests in a row that failed for lack of a connection (timeouts, unreachable)
+    pub consecutive_failures: u32,
+    // When `consecutive_failures` reached the configured limit; shown as a banner
+    pub connection_lost_at: Option<Instant>,
+    // Node a privileged debug shell awaits confirmation for
+    pub node_shell_target: Option<String>,
+    // Pod an ephemeral debug container awaits confirmation for, with the container it targets
+    pub debug_target: Option<(String, Option<String>)>,
+    // All-namespaces search: hits for `search_query`, which may lag the input
+    pub search_results: Vec<SearchResult>,
+    pub search_index: usize,
+    pub search_query: Option<String>,
     // Pod watcher for realtime updates
     pub pod_watcher: Option<PodWatcher>, // Assuming PodWatcher is defined elsewhere
     pub auto_refresh_enabled: bool,
+    // Deployment whose rollout is being followed, with its latest status
+    pub rollout_deployment: Option<String>,
+    pub rollout_status: Option<RolloutStatus>,
+    pub rollout_watcher: Option<RolloutWatcher>,
+    // Audit trail of mutating actions taken in this session
+    pub activity: Vec<ActivityEntry>,
+    pub activity_index: usize,
+    // Running port forwards, listed in the Port Forwards view; dropping one stops it
+    pub port_forwards: Vec<PortForwardHandle>,
+    pub port_forward_index: usize,
+    // Pod the port-forward prompt is for
+    pub port_forward_pod: Option<String>,
+    // Detail pane for the selected resource
+    pub detail: Option<Detail>,
+    pub detail_scroll: usize,
+    // Revision selected in a deployment's history, and the one awaiting rollback confirmation
+    pub history_index: usize,
+    pub rollback_revision: Option<i64>,
+    pub detail_return_view: View,
+    // Usage sparklines for the pod detail; None without metrics-server
+    pub pod_usage: Option<UsageHistory>,
+    // Set by actions that need the real terminal; the main loop runs it
+    pub pending_command: Option<ExternalCommand>,
+    // Destructive action waiting to run; the main loop fires it unless Esc cancels
+    pub countdown: Option<Countdown>,
+    // Context switch the main loop is stepping through
+    pub context_switch: Option<ContextSwitch>,
+    // Debug container the main loop waits on, then attaches to
+    pub debug_start: Option<DebugStart>,
+    // Table column selected for resizing with `<`/`>`; none until Tab is pressed
+    pub column_focus: Option<usize>,
 }
 
 impl App {
+    /// The context `new` is going to connect to, for the splash shown meanwhile
+    pub fn startup_context() -> String {
+        let context = KubeClient::get_current_context().unwrap_or_default();
+        if context.is_empty()
+            && KubeClient::list_contexts().unwrap_or_default().is_empty()
+            && KubeClient::in_cluster()
+        {
+            return IN_CLUSTER_CONTEXT.to_string();
+        }
+        context
+    }
+
     pub async fn new() -> Result<Self> {
         // Try to get contexts first (this works even without a connection)
         let contexts = KubeClient::list_contexts().unwrap_or_default();
-        let current_context = KubeClient::get_current_context().unwrap_or_default();
+        let mut current_context = KubeClient::get_current_context().unwrap_or_default();
 
-        // Check if we have any contexts configured
+        // Check if we have any contexts configured; in a pod, the service account will do
         if contexts.is_empty() {
-            anyhow::bail!("No Kubernetes contexts found. Please configure kubectl first.");
+            if !KubeClient::in_cluster() {
+                anyhow::bail!("No Kubernetes contexts found. Please configure kubectl first.");
+            }
+            current_context = IN_CLUSTER_CONTEXT.to_string();
         }
 
-        // Check if current context is set
+        // A broken config file shouldn't keep the app from starting
+        let (config, config_error) = match Config::load() {
+            Ok(config) => (config, None),
+            Err(e) => (
+                Config::default(),
+                Some(format!("Failed to load config: {}", e)),
+            ),
+        };
+
+        // Without a current context there's nothing to connect to yet; let the
+        // user pick one from the Clusters view instead of sending them to kubectl
         if current_context.is_empty() {
-            anyhow::bail!("No current context set. Please run 'kubectl config use-context <context-name>' or use kubectx.");
+            let mut app = Self::with_client(
+                Box::new(DisconnectedClient),
+                config,
+                contexts,
+                current_context,
+                vec![],
+            );
+            app.current_view = View::Clusters;
+            app.connected = false;
+            app.error_message = config_error;
+            app.status_message =
+                "No current context is set. Select a context and press Enter to connect."
+                    .to_string();
+            return Ok(app);
         }
 
         // Try to create client and connect
-        let (client, namespaces, initial_view, error_message) = match KubeClient::new().await {
-            Ok(client) => {
-                // Try to list namespaces to verify connection
-                match client.list_namespaces().await {
-                    Ok(namespaces) => {
-                        if namespaces.is_empty() {
-                            (client, vec!["default".to_string()], View::Pods, None)
-                        } else {
-                            (client, namespaces, View::Pods, None)
+        let mut tls_insecure = false;
+        let (client, namespaces, connected, error_message): (Box<dyn KubeApi>, _, _, _) =
+            match KubeClient::new(&config).await {
+                Ok(client) => {
+                    tls_insecure = client.tls_verification_disabled();
+                    // Try to list namespaces to verify connection
+                    match client.list_namespaces().await {
+                        Ok(namespaces) => {
+                            if namespaces.is_empty() {
+                                (Box::new(client), vec!["default".to_string()], true, None)
+                            } else {
+                                (Box::new(client), namespaces, true, None)
+                            }
+                        }
+                        Err(e) => {
+                            // Connection failed, start on Clusters view
+                            let error_msg = format!(
+                                "Failed to connect to cluster '{}': {}. Please switch to a valid context (Press 4 for Clusters view).",
+                                current_context, e
+                            );
+                            (Box::new(client), vec![], false, Some(error_msg))
                         }
-                    }
-                    Err(e) => {
-                        // Connection failed, start on Clusters view
-                        let error_msg = format!(
-                            "Failed to connect to cluster '{}': {}. Please switch to a valid context (Press 4 for Clusters view).",
-                            current_context, e
-                        );
-                        (
-                            client,
-                            vec!["default".to_string()],
-                            View::Clusters,
-                            Some(error_msg),
-                        )
                     }
                 }
+                Err(e) => {
+                    // Client creation failed, usually a config issue; other contexts may still work
+                    let error_msg = format!(
+                        "Failed to initialize Kubernetes client: {}. Please check your kubeconfig or switch to another context.",
+                        e
+                    );
+                    (Box::new(DisconnectedClient), vec![], false, Some(error_msg))
+                }
+            };
+
+        // Who the token authenticates as, before any impersonation on top
+        let token_file = match &config.token_file {
+            Some(path) => Some(path.clone()),
+            None if current_context == IN_CLUSTER_CONTEXT => {
+                Some(PathBuf::from(SERVICE_ACCOUNT_TOKEN_FILE))
             }
-            Err(e) => {
-                // Client creation failed, this is usually a config issue
-                anyhow::bail!(
-                    "Failed to initialize Kubernetes client: {}. Please check your kubeconfig and ensure a valid context is selected.",
-                    e
-                );
-            }
+            None => None,
         };
+        let token_identity = token_file
+            .map(|path| token_file_identity(&path).unwrap_or_else(|| path.display().to_string()));
+
+        let mut app = Self::with_client(client, config, contexts, current_context, namespaces);
+        app.connected = connected;
+        app.tls_insecure = tls_insecure;
+        app.token_identity = token_identity;
+        if !connected {
+            app.current_view = View::Clusters;
+        }
+        app.error_message = error_message.or(config_error);
+
+        // Only try to refresh if we don't have an error
+        if app.error_message.is_none() {
+            let _ = app.refresh_current_view().await;
+        }
+
+        Ok(app)
+    }
 
-        let current_namespace = namespaces
-            .first()
-            .cloned()
+    /// Build the app around an already connected client, without touching the
+    /// kubeconfig or the cluster. Nothing is loaded until the first refresh.
+    pub fn with_client(
+        client: Box<dyn KubeApi>,
+        config: Config,
+        contexts: Vec<ContextInfo>,
+        current_context: String,
+        namespaces: Vec<String>,
+    ) -> Self {
+        let mut contexts = contexts;
+        order_contexts(&mut contexts, &config.favorites.contexts);
+
+        // Start in the namespace the kubeconfig context points at, like kubectl does
+        let current_namespace = contexts
+            .iter()
+            .find(|ctx| ctx.name == current_context)
+            .map(|ctx| ctx.namespace.clone())
             .unwrap_or_else(|| "default".to_string());
+        let context_index = contexts
+            .iter()
+            .position(|ctx| ctx.name == current_context)
+            .unwrap_or(0);
 
+        let theme = config.theme;
         let mut app = Self {
             client,
-            current_view: initial_view,
-            namespaces,
-            current_namespace: current_namespace.clone(),
+            connected: true,
+            tls_insecure: false,
+            token_identity: None,
+            config,
+            theme,
+            current_view: View::Pods,
+            namespaces: vec![],
+            all_namespaces: vec![],
+            current_namespace: String::new(),
             namespace_index: 0,
             contexts,
-            context_index: 0,
+            context_index,
             current_context,
+            print_location_on_exit: false,
             pods: vec![],
+            group_pods_by_node: false,
+            pinned_pods: Vec::new(),
+            show_log_preview: false,
+            log_preview: None,
             pod_index: 0,
             deployments: vec![],
             deployment_index: 0,
             services: vec![],
             service_index: 0,
+            list_filters: HashMap::new(),
+            events: vec![],
+            event_index: 0,
+            configmaps: vec![],
+            configmap_index: 0,
+            secrets: vec![],
+            secret_index: 0,
+            secret_revealed: false,
+            events_follow: false,
+            event_watcher: None,
+            warning_count: 0,
             logs: String::new(),
             logs_scroll: 0,
             logs_follow: false,
+            logs_paused: false,
+            logs_container: None,
+            log_watcher: None,
+            logs_fetched_at: None,
             logs_pod_name: None,
-            error_message,
+            logs_new_from: None,
+            logs_previous: false,
+            logs_grep: None,
+            logs_include: None,
+            logs_exclude: None,
+            logs_wrap: true,
+            error_message: None,
+            last_error: None,
             input_mode: InputMode::Normal,
             input_buffer: String::new(),
             status_message: String::new(),
-            terminal_session: None,
-            terminal_pod_name: None,
+            terminal_sessions: vec![],
+            active_terminal: 0,
             terminal_scroll: 0,
             terminal_choice_selection: 0,
+            container_picker: None,
+            container_picker_index: 0,
+            shell_cache: HashMap::new(),
+            view_loaded_at: HashMap::new(),
+            namespace_picker_index: 0,
+            view_picker_index: 0,
+            metadata_target: None,
+            cordon_node: None,
+            delete_target: None,
+            delete_propagation: DeletePropagation::default(),
+            completed_jobs: Vec::new(),
+            marked_deployments: Vec::new(),
+            scale_to_zero_targets: Vec::new(),
+            consecutive_failures: 0,
+            connection_lost_at: None,
+            node_shell_target: None,
+            debug_target: None,
+            search_results: vec![],
+            search_index: 0,
+            search_query: None,
             pod_watcher: None,
             auto_refresh_enabled: false,
+            rollout_deployment: None,
+            rollout_status: None,
+            rollout_watcher: None,
+            activity: Vec::new(),
+            activity_index: 0,
+            port_forwards: vec![],
+            port_forward_index: 0,
+            port_forward_pod: None,
+            detail: None,
+            detail_scroll: 0,
+            history_index: 0,
+            rollback_revision: None,
+            detail_return_view: View::Pods,
+            pod_usage: None,
+            pending_command: None,
+            countdown: None,
+            context_switch: None,
+            debug_start: None,
+            column_focus: None,
         };
 
-        // Only try to refresh if we don't have an error
-        if app.error_message.is_none() {
-            let _ = app.refresh_current_view().await;
+        app.set_namespaces(namespaces, current_namespace);
+        if let Some(name) = app.config.start_view.clone() {
+            match View::named(&name) {
+                Some(view) => app.current_view = view,
+                None => {
+                    app.status_message = format!(
+                        "Unknown start_view \"{}\" in config; starting on Pods",
+                        name
+                    )
+                }
+            }
         }
-
-        Ok(app)
+        app
     }
 
     pub async fn handle_event(&mut self, event: InputEvent) -> Result<bool> {
+        // Nothing to do until the next frame, which is laid out for the new size
+        // and resizes the embedded terminals to match
+        if let InputEvent::Resize { .. } = event {
+            return Ok(true);
+        }
+
+        // Ctrl+C quits from any view or input mode, except in the embedded terminal,
+        // where it interrupts the remote command and Esc is the way out
+        if event.key_code() == KeyCode::Char('c')
+            && event.modifiers().contains(KeyModifiers::CONTROL)
+            && self.current_view != View::Terminal
+        {
+            self.close_all_terminals();
+            return Ok(false);
+        }
+
+        // While a destructive action counts down, only Esc (abort) is accepted
+        if let Some(countdown) = &self.countdown {
+            if event.key_code() == KeyCode::Esc {
+                self.status_message = format!("Cancelled: {}", countdown.action.describe());
+                self.countdown = None;
+            }
+            return Ok(true);
+        }
+
         // Handle terminal view with special input handling
         if self.current_view == View::Terminal {
             return self.handle_terminal_mode(event).await;
@@ -164,35 +852,259 @@ impl App {
             InputMode::Normal => self.handle_normal_mode(event).await,
             InputMode::Scale => self.handle_scale_mode(event).await,
             InputMode::TerminalChoice => self.handle_terminal_choice_mode(event).await,
+            InputMode::ContainerPicker => self.handle_container_picker_mode(event).await,
+            InputMode::NamespacePicker => self.handle_namespace_picker_mode(event).await,
+            InputMode::GlobalSearch => self.handle_global_search_mode(event).await,
+            InputMode::ViewPicker => self.handle_view_picker_mode(event).await,
+            InputMode::Rollout => self.handle_rollout_mode(event).await,
+            InputMode::EnvEdit => self.handle_env_edit_mode(event).await,
+            InputMode::MetadataEdit => self.handle_metadata_edit_mode(event).await,
+            InputMode::LogGrep => self.handle_log_grep_mode(event).await,
+            InputMode::PortForward => self.handle_port_forward_mode(event).await,
+            InputMode::LogInclude | InputMode::LogExclude => self.handle_log_filter_mode(event),
+            InputMode::Filter => self.handle_filter_mode(event),
+            InputMode::RowPopup => {
+                if let KeyCode::Esc | KeyCode::Enter | KeyCode::Char('v') | KeyCode::Char('q') =
+                    event.key_code()
+                {
+                    self.input_mode = InputMode::Normal;
+                }
+                Ok(true)
+            }
+            InputMode::ConfirmCordon => {
+                match event.key_code() {
+                    KeyCode::Char('y') | KeyCode::Char('Y') => {
+                        self.input_mode = InputMode::Normal;
+                        self.cordon_confirmed_node().await;
+                    }
+                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.cordon_node = None;
+                        self.status_message = "Cordon cancelled".to_string();
+                    }
+                    _ => {}
+                }
+                Ok(true)
+            }
+            InputMode::ConfirmRollback => {
+                match event.key_code() {
+                    KeyCode::Char('y') | KeyCode::Char('Y') => {
+                        self.input_mode = InputMode::Normal;
+                        self.rollback_confirmed_revision().await?;
+                    }
+                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.rollback_revision = None;
+                        self.status_message = "Rollback cancelled".to_string();
+                    }
+                    _ => {}
+                }
+                Ok(true)
+            }
+            InputMode::ConfirmDelete => {
+                match event.key_code() {
+                    KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                        self.input_mode = InputMode::Normal;
+                        self.delete_confirmed_deployment().await?;
+                    }
+                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.delete_target = None;
+                        self.status_message = "Delete cancelled".to_string();
+                    }
+                    KeyCode::Char('b') => self.delete_propagation = DeletePropagation::Background,
+                    KeyCode::Char('f') => self.delete_propagation = DeletePropagation::Foreground,
+                    KeyCode::Char('o') => self.delete_propagation = DeletePropagation::Orphan,
+                    KeyCode::Tab | KeyCode::Right => self.cycle_delete_propagation(true),
+                    KeyCode::BackTab | KeyCode::Left => self.cycle_delete_propagation(false),
+                    _ => {}
+                }
+                Ok(true)
+            }
+            InputMode::ConfirmScaleToZero => {
+                match event.key_code() {
+                    KeyCode::Char('y') | KeyCode::Char('Y') => {
+                        self.input_mode = InputMode::Normal;
+                        let names = std::mem::take(&mut self.scale_to_zero_targets);
+                        self.start_countdown(PendingAction::ScaleDeployments {
+                            names,
+                            replicas: 0,
+                        });
+                    }
+                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.scale_to_zero_targets.clear();
+                        self.status_message = "Scale to zero cancelled".to_string();
+                    }
+                    _ => {}
+                }
+                Ok(true)
+            }
+            InputMode::ConfirmJobCleanup => {
+                match event.key_code() {
+                    KeyCode::Char('y') | KeyCode::Char('Y') => {
+                        self.input_mode = InputMode::Normal;
+                        let jobs = std::mem::take(&mut self.completed_jobs);
+                        self.start_countdown(PendingAction::DeleteCompletedJobs(jobs));
+                    }
+                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.completed_jobs.clear();
+                        self.status_message = "Job cleanup cancelled".to_string();
+                    }
+                    _ => {}
+                }
+                Ok(true)
+            }
+            InputMode::ConfirmNodeShell => {
+                match event.key_code() {
+                    KeyCode::Char('y') | KeyCode::Char('Y') => {
+                        self.input_mode = InputMode::Normal;
+                        self.open_node_shell().await?;
+                    }
+                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.node_shell_target = None;
+                        self.status_message = "Node shell cancelled".to_string();
+                    }
+                    _ => {}
+                }
+                Ok(true)
+            }
+            InputMode::ConfirmDebugContainer => {
+                match event.key_code() {
+                    KeyCode::Char('y') | KeyCode::Char('Y') => {
+                        self.input_mode = InputMode::Normal;
+                        self.open_debug_container().await?;
+                    }
+                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.debug_target = None;
+                        self.status_message = "Debug container cancelled".to_string();
+                    }
+                    _ => {}
+                }
+                Ok(true)
+            }
+            InputMode::ConfirmContextSwitch => {
+                match event.key_code() {
+                    KeyCode::Char('y') | KeyCode::Char('Y') => {
+                        self.input_mode = InputMode::Normal;
+                        self.switch_to_selected_context().await?;
+                    }
+                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                        self.input_mode = InputMode::Normal;
+                        self.status_message = "Context switch cancelled".to_string();
+                    }
+                    _ => {}
+                }
+                Ok(true)
+            }
         }
     }
 
     async fn handle_normal_mode(&mut self, event: InputEvent) -> Result<bool> {
+        // A key meant for a row that's no longer there would otherwise do nothing at
+        // all; acting on whatever row the selection lands on instead could be the wrong one
+        if self.clamp_selection() && !is_navigation_key(&event) {
+            self.status_message =
+                "Selection was out of range after the list changed; moved to the last row, refresh (r)"
+                    .to_string();
+            return Ok(true);
+        }
+
         match event.key_code() {
+            KeyCode::Char('n') if event.modifiers().contains(KeyModifiers::CONTROL) => {
+                self.input_mode = InputMode::NamespacePicker;
+                self.input_buffer.clear();
+                self.namespace_picker_index = 0;
+            }
+            KeyCode::Char('/') => {
+                if matches!(
+                    self.current_view,
+                    View::Pods | View::Deployments | View::Services
+                ) {
+                    self.input_mode = InputMode::Filter;
+                    self.input_buffer = self
+                        .list_filters
+                        .get(&self.current_view)
+                        .cloned()
+                        .unwrap_or_default();
+                }
+            }
+            KeyCode::Char(':') => {
+                self.input_mode = InputMode::ViewPicker;
+                self.input_buffer.clear();
+                self.view_picker_index = 0;
+            }
+            KeyCode::Char('p') if event.modifiers().contains(KeyModifiers::CONTROL) => {
+                self.ping_cluster().await;
+            }
+            KeyCode::Char('f') if event.modifiers().contains(KeyModifiers::CONTROL) => {
+                self.input_mode = InputMode::GlobalSearch;
+                self.input_buffer.clear();
+                self.search_results.clear();
+                self.search_index = 0;
+                self.search_query = None;
+            }
+            KeyCode::Char(c @ '1'..='9') if event.modifiers().contains(KeyModifiers::ALT) => {
+                self.jump_to_favorite(c as usize - '0' as usize).await?;
+            }
             KeyCode::Char('q') => return Ok(false),
+            KeyCode::Char('Q') => {
+                self.print_location_on_exit = true;
+                return Ok(false);
+            }
             KeyCode::Char('1') => {
                 self.current_view = View::Pods;
-                self.refresh_current_view().await?;
+                self.refresh_on_focus().await?;
             }
             KeyCode::Char('2') => {
                 self.current_view = View::Deployments;
-                self.refresh_current_view().await?;
+                self.refresh_on_focus().await?;
             }
             KeyCode::Char('3') => {
                 self.current_view = View::Services;
-                self.refresh_current_view().await?;
+                self.refresh_on_focus().await?;
             }
             KeyCode::Char('4') => {
                 self.current_view = View::Clusters;
-                self.refresh_current_view().await?;
+                self.refresh_on_focus().await?;
             }
             KeyCode::Char('5') | KeyCode::Char('n') => {
                 self.current_view = View::Namespaces;
-                self.refresh_current_view().await?;
+                self.refresh_on_focus().await?;
+            }
+            KeyCode::Char('6') => {
+                self.current_view = View::Events;
+                self.refresh_on_focus().await?;
+            }
+            KeyCode::Char('7') => {
+                self.current_view = View::ConfigMaps;
+                self.refresh_on_focus().await?;
+            }
+            KeyCode::Char('8') => {
+                self.current_view = View::Secrets;
+                self.refresh_on_focus().await?;
+            }
+            KeyCode::Char('x') => {
+                if let (View::Detail, Some(Detail::Secret(_))) = (self.current_view, &self.detail) {
+                    self.secret_revealed = !self.secret_revealed;
+                } else if self.current_view == View::Logs {
+                    self.input_mode = InputMode::LogExclude;
+                    self.input_buffer = self
+                        .logs_exclude
+                        .as_ref()
+                        .map(|re| re.as_str().to_string())
+                        .unwrap_or_default();
+                }
             }
             KeyCode::Char('?') | KeyCode::Char('h') => {
                 self.current_view = View::Help;
             }
+            KeyCode::Char('a') => {
+                self.current_view = View::Activity;
+            }
             KeyCode::Char('r') => {
                 self.refresh_current_view().await?;
             }
@@ -201,84 +1113,309 @@ impl App {
             }
             KeyCode::Char('l') => {
                 if self.current_view == View::Pods {
-                    self.view_pod_logs().await?;
+                    self.view_pod_logs(false).await?;
+                }
+            }
+            KeyCode::Char('L') => {
+                if self.current_view == View::Pods {
+                    self.view_pod_logs(true).await?;
+                }
+            }
+            KeyCode::Char('A') => {
+                if self.current_view == View::Pods {
+                    self.follow_all_container_logs().await;
                 }
             }
             KeyCode::Char('f') => {
                 if self.current_view == View::Logs {
                     self.toggle_log_follow();
+                } else if self.current_view == View::Events {
+                    self.toggle_events_follow().await;
+                } else {
+                    self.toggle_favorite();
+                }
+            }
+            KeyCode::Char('p') => {
+                if self.current_view == View::Logs {
+                    self.toggle_previous_logs().await?;
+                } else if self.current_view == View::Pods {
+                    self.toggle_log_preview().await;
+                }
+            }
+            KeyCode::Char('g') => {
+                if self.current_view == View::Logs && self.log_watcher.is_some() {
+                    self.status_message = MERGED_LOGS_UNSUPPORTED.to_string();
+                } else if self.current_view == View::Logs {
+                    self.input_mode = InputMode::LogGrep;
+                    self.input_buffer = self.logs_grep.clone().unwrap_or_default();
+                }
+            }
+            KeyCode::Char('y') => {
+                self.view_selected_yaml().await?;
+            }
+            KeyCode::Char('c') => {
+                self.copy_selected_reference();
+            }
+            KeyCode::Char('Y') => {
+                self.copy_last_error();
+            }
+            KeyCode::Char('m') => {
+                if let (View::Detail, Some(Detail::Yaml(doc))) =
+                    (self.current_view, &mut self.detail)
+                {
+                    doc.show_managed_fields = !doc.show_managed_fields;
                 }
             }
             KeyCode::Char('e') => {
-                if self.current_view == View::Pods {
+                if let (View::Detail, Some(Detail::Deployment(_))) =
+                    (self.current_view, &self.detail)
+                {
+                    self.input_mode = InputMode::EnvEdit;
+                    self.input_buffer.clear();
+                } else if self.current_view == View::Pods {
                     self.exec_into_pod().await?;
+                } else if self.current_view == View::Clusters {
+                    self.pending_command =
+                        Some(ExternalCommand::EditFile(KubeClient::get_kubeconfig_path()));
                 }
             }
-            KeyCode::Char('s') => {
-                if self.current_view == View::Deployments {
-                    self.input_mode = InputMode::Scale;
-                    self.input_buffer.clear();
+            KeyCode::Char('o') => {
+                if self.current_view == View::Logs {
+                    self.open_logs_in_pager();
                 }
             }
-            KeyCode::Enter => match self.current_view {
-                View::Clusters => self.switch_to_selected_context().await?,
-                View::Namespaces => self.switch_to_selected_namespace().await?,
-                _ => {}
-            },
-            KeyCode::Esc => {
-                if self.current_view == View::Help {
-                    self.current_view = View::Pods;
+            KeyCode::Char('i') => {
+                if self.current_view == View::Clusters {
+                    self.describe_selected_context();
                 } else if self.current_view == View::Logs {
-                    self.logs_follow = false;
-                    self.current_view = View::Pods;
-                } else if self.current_view == View::Terminal {
-                    self.close_terminal();
-                    self.current_view = View::Pods;
+                    self.input_mode = InputMode::LogInclude;
+                    self.input_buffer = self
+                        .logs_include
+                        .as_ref()
+                        .map(|re| re.as_str().to_string())
+                        .unwrap_or_default();
                 }
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                self.move_selection_up();
+            KeyCode::Char('V') => {
+                if self.current_view == View::Clusters {
+                    match KubeClient::effective_kubeconfig() {
+                        Ok(kubeconfig) => self.open_detail(Detail::Kubeconfig(kubeconfig)),
+                        Err(e) => self.report_error("Failed to read kubeconfig", &e),
+                    }
+                }
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                self.move_selection_down();
+            KeyCode::Char('C') => {
+                if self.current_view == View::Pods {
+                    self.request_cordon_of_pod_node();
+                }
             }
-            KeyCode::Left => {
-                self.navigate_tab_left().await?;
+            KeyCode::Char('K') => {
+                self.kubectl_describe_selected().await;
             }
-            KeyCode::Right => {
-                self.navigate_tab_right().await?;
+            KeyCode::Char('H') => {
+                if self.current_view == View::Deployments {
+                    self.open_deployment_history().await;
+                }
             }
-            _ => {}
-        }
-        Ok(true)
-    }
-
-    async fn handle_scale_mode(&mut self, event: InputEvent) -> Result<bool> {
-        match event.key_code() {
-            KeyCode::Esc => {
-                self.input_mode = InputMode::Normal;
-                self.input_buffer.clear();
+            KeyCode::Char('u') => {
+                self.request_rollback();
             }
-            KeyCode::Enter => {
-                if let Ok(replicas) = self.input_buffer.parse::<i32>() {
-                    if let Some(deployment) = self.deployments.get(self.deployment_index) {
-                        match self
-                            .client
-                            .scale_deployment(&self.current_namespace, &deployment.name, replicas)
-                            .await
-                        {
-                            Ok(_) => {
-                                self.status_message =
-                                    format!("Scaled {} to {} replicas", deployment.name, replicas);
-                                self.refresh_current_view().await?;
-                            }
-                            Err(e) => {
-                                self.error_message = Some(format!("Failed to scale: {}", e));
-                            }
-                        }
+            KeyCode::Char('S') => {
+                if self.current_view == View::Pods {
+                    self.toggle_pod_status_display();
+                }
+            }
+            KeyCode::Char('T') => {
+                self.theme = self.theme.next();
+                self.status_message = format!("Theme: {} (T for the next one)", self.theme.name());
+            }
+            KeyCode::Char(' ') => {
+                if self.current_view == View::Deployments {
+                    self.toggle_deployment_mark();
+                }
+            }
+            KeyCode::Char('P') => {
+                if self.current_view == View::Pods {
+                    self.toggle_pin();
+                }
+            }
+            KeyCode::Char('F') => {
+                if let (View::Pods, Some(pod)) = (self.current_view, self.selected_pod()) {
+                    self.port_forward_pod = Some(pod.name.clone());
+                    self.input_mode = InputMode::PortForward;
+                    self.input_buffer.clear();
+                }
+            }
+            KeyCode::Char('G') => {
+                if self.current_view == View::Pods {
+                    self.toggle_pod_grouping();
+                } else if self.current_view == View::Logs {
+                    self.resume_log_follow();
+                }
+            }
+            KeyCode::End if self.current_view == View::Logs => self.resume_log_follow(),
+            KeyCode::Char('J') => {
+                if self.current_view == View::Pods {
+                    self.request_completed_jobs_cleanup().await;
+                }
+            }
+            KeyCode::Char('N') => {
+                if self.current_view == View::Pods {
+                    self.describe_selected_pods_node().await;
+                }
+            }
+            KeyCode::Char('E') => {
+                if self.current_view == View::Pods {
+                    self.request_node_shell();
+                }
+            }
+            KeyCode::Char('D') => {
+                if self.current_view == View::Pods && !self.refuse_kubectl("A debug container") {
+                    if let Some(pod) = self.selected_pod() {
+                        let target = pod
+                            .default_container
+                            .clone()
+                            .or_else(|| pod.containers.first().map(|c| c.name.clone()));
+                        self.debug_target = Some((pod.name.clone(), target));
+                        self.input_mode = InputMode::ConfirmDebugContainer;
+                    }
+                }
+            }
+            KeyCode::Char('=') => {
+                if let Some(target) = self.selected_resource() {
+                    self.metadata_target = Some(target);
+                    self.input_mode = InputMode::MetadataEdit;
+                    self.input_buffer.clear();
+                }
+            }
+            KeyCode::Char('v') => {
+                if matches!(
+                    self.current_view,
+                    View::Pods | View::Deployments | View::Services | View::Events | View::Clusters
+                ) {
+                    self.input_mode = InputMode::RowPopup;
+                }
+            }
+            KeyCode::Tab if self.current_view == View::Logs => {
+                self.cycle_log_container(true).await?
+            }
+            KeyCode::BackTab if self.current_view == View::Logs => {
+                self.cycle_log_container(false).await?
+            }
+            KeyCode::Tab => self.focus_column(true),
+            KeyCode::BackTab => self.focus_column(false),
+            KeyCode::Char('<') => self.resize_focused_column(-RESIZE_STEP),
+            KeyCode::Char('>') => self.resize_focused_column(RESIZE_STEP),
+            KeyCode::Char('w') => {
+                if self.current_view == View::Deployments {
+                    self.watch_selected_rollout().await;
+                } else if self.current_view == View::Logs {
+                    self.logs_wrap = !self.logs_wrap;
+                    self.status_message = if self.logs_wrap {
+                        "Wrapping long log lines".to_string()
+                    } else {
+                        "Long log lines are cut at the edge (w to wrap)".to_string()
+                    };
+                }
+            }
+            KeyCode::Char('t') => {
+                if !self.terminal_sessions.is_empty() {
+                    self.current_view = View::Terminal;
+                }
+            }
+            KeyCode::Char('s') => {
+                if self.current_view == View::Deployments {
+                    self.input_mode = InputMode::Scale;
+                    self.input_buffer.clear();
+                } else if self.current_view == View::Namespaces {
+                    self.toggle_system_namespaces();
+                } else if let (View::Detail, Some(Detail::Yaml(doc))) =
+                    (self.current_view, &mut self.detail)
+                {
+                    doc.show_status = !doc.show_status;
+                }
+            }
+            KeyCode::Enter => match self.current_view {
+                View::Pods => self.describe_selected_pod().await?,
+                View::Deployments => self.describe_selected_deployment().await?,
+                View::Services => self.describe_selected_service().await?,
+                View::ConfigMaps => self.describe_selected_configmap().await?,
+                View::Secrets => self.describe_selected_secret().await?,
+                View::Clusters => self.request_context_switch().await?,
+                View::Namespaces => self.summarize_selected_namespace().await?,
+                View::Detail => {
+                    // From the namespace overview, Enter goes on to switch into it
+                    if let Some(Detail::Namespace(summary)) = &self.detail {
+                        let namespace = summary.namespace.clone();
+                        self.close_detail();
+                        self.current_view = View::Pods;
+                        self.switch_namespace(namespace).await?;
                     }
                 }
+                _ => {}
+            },
+            KeyCode::Esc => {
+                if matches!(
+                    self.current_view,
+                    View::Help | View::Activity | View::PortForwards
+                ) {
+                    self.current_view = View::Pods;
+                } else if self.current_view == View::Logs {
+                    self.logs_follow = false;
+                    self.logs_paused = false;
+                    self.log_watcher = None;
+                    self.current_view = View::Pods;
+                } else if self.current_view == View::Detail {
+                    self.close_detail();
+                } else {
+                    return Ok(true);
+                }
+                self.refresh_on_focus().await?;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.move_selection_up();
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.move_selection_down();
+            }
+            KeyCode::Left => {
+                self.navigate_tab_left().await?;
+            }
+            KeyCode::Right => {
+                self.navigate_tab_right().await?;
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    async fn handle_scale_mode(&mut self, event: InputEvent) -> Result<bool> {
+        match event.key_code() {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
+            }
+            KeyCode::Enter => {
                 self.input_mode = InputMode::Normal;
+                if let Ok(replicas) = self.input_buffer.parse::<i32>() {
+                    let mut targets = self.scale_targets();
+                    if targets.len() > 1 {
+                        if replicas == 0 {
+                            // Taking several workloads down at once gets a look at the list first
+                            self.scale_to_zero_targets = targets;
+                            self.input_mode = InputMode::ConfirmScaleToZero;
+                        } else {
+                            self.scale_deployments(&targets, replicas).await?;
+                        }
+                    } else if let Some(name) = targets.pop() {
+                        if replicas == 0 {
+                            // Scaling to zero takes the workload down; give a beat to back out
+                            self.start_countdown(PendingAction::ScaleDeployment { name, replicas });
+                        } else {
+                            self.scale_deployment(&name, replicas).await?;
+                        }
+                    }
+                }
                 self.input_buffer.clear();
             }
             KeyCode::Char(c) if c.is_ascii_digit() => {
@@ -292,6 +1429,310 @@ impl App {
         Ok(true)
     }
 
+    /// Deployments that Scale acts on: the marked ones in list order, or else the
+    /// selected one
+    pub fn scale_targets(&self) -> Vec<String> {
+        let marked: Vec<String> = self
+            .deployments
+            .iter()
+            .filter(|d| self.marked_deployments.contains(&d.name))
+            .map(|d| d.name.clone())
+            .collect();
+        if !marked.is_empty() {
+            return marked;
+        }
+        self.selected_deployment()
+            .map(|d| vec![d.name.clone()])
+            .unwrap_or_default()
+    }
+
+    fn toggle_deployment_mark(&mut self) {
+        let Some(name) = self.selected_deployment().map(|d| d.name.clone()) else {
+            return;
+        };
+        if let Some(position) = self.marked_deployments.iter().position(|d| *d == name) {
+            self.marked_deployments.remove(position);
+        } else {
+            self.marked_deployments.push(name);
+        }
+        self.status_message = match self.marked_deployments.len() {
+            0 => "No deployments marked".to_string(),
+            n => format!(
+                "{} deployments marked; s scales them all (Space unmarks)",
+                n
+            ),
+        };
+        // Mark and move on, so consecutive rows are quick to pick
+        self.move_selection_down();
+    }
+
+    /// `web: 3 → 5 (+2)` for the replica count being typed in Scale mode
+    pub fn scale_preview(&self) -> Option<String> {
+        let targets = self.scale_targets();
+        if targets.len() > 1 {
+            let target = self.input_buffer.parse::<i32>().ok();
+            return Some(format!(
+                "{} deployments ({}) → {}",
+                targets.len(),
+                targets.join(", "),
+                target.map_or("?".to_string(), |t| t.to_string())
+            ));
+        }
+        let deployment = self.selected_deployment()?;
+        let current = deployment.replicas;
+        let target = match self.input_buffer.parse::<i32>() {
+            Err(_) => return Some(format!("{}: {} → ?", deployment.name, current)),
+            Ok(target) => target,
+        };
+        let change = match target - current {
+            0 => "no change".to_string(),
+            _ if target == 0 => "stops every pod".to_string(),
+            delta => format!("{:+}", delta),
+        };
+        Some(format!(
+            "{}: {} → {} ({})",
+            deployment.name, current, target, change
+        ))
+    }
+
+    async fn handle_rollout_mode(&mut self, event: InputEvent) -> Result<bool> {
+        if let KeyCode::Esc | KeyCode::Char('q') = event.key_code() {
+            self.input_mode = InputMode::Normal;
+            self.rollout_watcher = None;
+            self.rollout_deployment = None;
+            self.rollout_status = None;
+            self.refresh_current_view().await?;
+        }
+        Ok(true)
+    }
+
+    /// Move the resize focus to the next (or previous) column, wrapping around
+    fn focus_column(&mut self, forward: bool) {
+        let count = table_columns(self.current_view, &self.config).len();
+        if count == 0 {
+            return;
+        }
+        self.column_focus = Some(match self.column_focus.map(|f| f.min(count - 1)) {
+            None if forward => 0,
+            None => count - 1,
+            Some(f) if forward => (f + 1) % count,
+            Some(f) => (f + count - 1) % count,
+        });
+    }
+
+    /// Widen or narrow the focused column and remember it in the config file
+    fn resize_focused_column(&mut self, delta: i32) {
+        let columns = table_columns(self.current_view, &self.config);
+        if columns.is_empty() {
+            return;
+        }
+        let focus = self.column_focus.unwrap_or(0).min(columns.len() - 1);
+        self.column_focus = Some(focus);
+
+        let (key, width) = columns[focus];
+        let width = (width as i32 + delta).clamp(RESIZE_STEP, 100) as u16;
+        let table = match self.current_view {
+            View::Pods => &mut self.config.pods,
+            View::Deployments => &mut self.config.deployments,
+            _ => &mut self.config.services,
+        };
+        table.widths.insert(key.to_string(), width);
+
+        if let Err(e) = self.config.save_tables() {
+            self.error_message = Some(format!("Failed to save column widths: {}", e));
+        }
+    }
+
+    /// Switch the Pods view between name order and grouped by node, keeping the
+    /// cursor on the same pod
+    fn toggle_pod_grouping(&mut self) {
+        self.group_pods_by_node = !self.group_pods_by_node;
+        let selected = self.selected_pod().map(|p| p.name.clone());
+        if !self.group_pods_by_node {
+            self.pods.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+        self.order_pods();
+        if let Some(index) = selected.and_then(|name| self.pods.iter().position(|p| p.name == name))
+        {
+            self.pod_index = index;
+        }
+        self.status_message = if self.group_pods_by_node {
+            "Pods grouped by node".to_string()
+        } else {
+            "Pods sorted by name".to_string()
+        };
+    }
+
+    /// Put `pods` in the order the Pods view shows: the configured sort if any,
+    /// and when grouping by node, grouped with unscheduled pods last
+    fn order_pods(&mut self) {
+        sort_pods(
+            &mut self.pods,
+            &self.config.pods.sort_keys(),
+            self.config.pod_status,
+        );
+        if self.group_pods_by_node {
+            // Stable, so each node's pods keep the order above
+            self.pods
+                .sort_by(|a, b| (a.node == "<none>", &a.node).cmp(&(b.node == "<none>", &b.node)));
+        } else {
+            // Grouped pods stay under their node; pinned ones are only highlighted there
+            favorites_first(&mut self.pods, &self.pinned_pods, |p| &p.name);
+        }
+    }
+
+    fn toggle_pin(&mut self) {
+        let Some(name) = self.selected_pod().map(|p| p.name.clone()) else {
+            return;
+        };
+        if let Some(position) = self.pinned_pods.iter().position(|p| *p == name) {
+            self.pinned_pods.remove(position);
+            self.status_message = format!("Unpinned {}", name);
+        } else {
+            self.pinned_pods.push(name.clone());
+            self.status_message = format!("Pinned {} (P to unpin)", name);
+        }
+        self.order_pods();
+        if let Some(index) = self.pods.iter().position(|p| p.name == name) {
+            self.pod_index = index;
+        }
+    }
+
+    /// Pinned pods that aren't in the list, e.g. replaced in a rollout or in
+    /// another namespace
+    pub fn missing_pins(&self) -> Vec<&str> {
+        self.pinned_pods
+            .iter()
+            .filter(|pin| !self.pods.iter().any(|p| &p.name == *pin))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Switch the STATUS column between the phase and the kubectl-style status
+    fn toggle_pod_status_display(&mut self) {
+        self.config.pod_status = match self.config.pod_status {
+            PodStatusDisplay::Phase => PodStatusDisplay::Computed,
+            PodStatusDisplay::Computed => PodStatusDisplay::Phase,
+        };
+        match self.config.save_pod_status() {
+            Ok(()) => {
+                self.status_message = match self.config.pod_status {
+                    PodStatusDisplay::Phase => "Pod status shows the phase".to_string(),
+                    PodStatusDisplay::Computed => {
+                        "Pod status shows the computed kubectl status".to_string()
+                    }
+                }
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to save pod status display: {}", e))
+            }
+        }
+    }
+
+    /// Start following the selected deployment's rollout in an overlay
+    async fn watch_selected_rollout(&mut self) {
+        let Some(deployment) = self.selected_deployment() else {
+            return;
+        };
+        let name = deployment.name.clone();
+        match self
+            .client
+            .watch_rollout(&self.current_namespace, &name)
+            .await
+        {
+            Ok(watcher) => {
+                self.rollout_watcher = Some(watcher);
+                self.rollout_deployment = Some(name);
+                self.rollout_status = None;
+                self.input_mode = InputMode::Rollout;
+            }
+            Err(e) => {
+                self.report_error("Failed to watch rollout", &e);
+            }
+        }
+    }
+
+    async fn scale_deployment(&mut self, name: &str, replicas: i32) -> Result<()> {
+        match self
+            .client
+            .scale_deployment(&self.current_namespace, name, replicas)
+            .await
+        {
+            Ok(_) => {
+                self.status_message = format!("Scaled {} to {} replicas", name, replicas);
+                self.record_activity(format!(
+                    "Scaled deployment {} to {} replicas",
+                    name, replicas
+                ));
+                self.refresh_current_view().await?;
+            }
+            Err(e) => {
+                self.report_error("Failed to scale", &e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Scale each of `names`, carrying on past failures, and report how each went
+    async fn scale_deployments(&mut self, names: &[String], replicas: i32) -> Result<()> {
+        let mut failed = Vec::new();
+        for name in names {
+            match self
+                .client
+                .scale_deployment(&self.current_namespace, name, replicas)
+                .await
+            {
+                Ok(_) => self.record_activity(format!(
+                    "Scaled deployment {} to {} replicas",
+                    name, replicas
+                )),
+                Err(e) => failed.push(format!("{}: {}", name, e)),
+            }
+        }
+
+        let scaled = names.len() - failed.len();
+        self.status_message = format!(
+            "Scaled {} of {} deployments to {} replicas",
+            scaled,
+            names.len(),
+            replicas
+        );
+        if !failed.is_empty() {
+            self.error_message = Some(format!("Failed to scale {}", failed.join("; ")));
+        }
+        self.refresh_current_view().await
+    }
+
+    fn start_countdown(&mut self, action: PendingAction) {
+        self.countdown = Some(Countdown {
+            action,
+            deadline: Instant::now() + COUNTDOWN,
+        });
+    }
+
+    /// Run the counted-down action once its deadline has passed (called from the main loop)
+    pub async fn run_due_action(&mut self) -> Result<()> {
+        match &self.countdown {
+            Some(countdown) if Instant::now() >= countdown.deadline => {}
+            _ => return Ok(()),
+        }
+        let Some(countdown) = self.countdown.take() else {
+            return Ok(());
+        };
+        match countdown.action {
+            PendingAction::ScaleDeployment { name, replicas } => {
+                self.scale_deployment(&name, replicas).await?;
+            }
+            PendingAction::ScaleDeployments { names, replicas } => {
+                self.scale_deployments(&names, replicas).await?;
+            }
+            PendingAction::DeleteCompletedJobs(jobs) => {
+                self.delete_completed_jobs(&jobs).await?;
+            }
+        }
+        Ok(())
+    }
+
     async fn handle_terminal_choice_mode(&mut self, event: InputEvent) -> Result<bool> {
         match event.key_code() {
             KeyCode::Esc => {
@@ -301,20 +1742,23 @@ impl App {
             KeyCode::Char('1') => {
                 // User chose embedded terminal
                 self.input_mode = InputMode::Normal;
-                self.open_embedded_terminal().await?;
+                self.pick_container_for(ContainerAction::EmbeddedShell)
+                    .await?;
             }
             KeyCode::Char('2') => {
                 // User chose native terminal tab
                 self.input_mode = InputMode::Normal;
-                self.open_native_terminal().await?;
+                self.pick_container_for(ContainerAction::NativeShell)
+                    .await?;
             }
             KeyCode::Enter => {
                 self.input_mode = InputMode::Normal;
-                if self.terminal_choice_selection == 0 {
-                    self.open_embedded_terminal().await?;
+                let action = if self.terminal_choice_selection == 0 {
+                    ContainerAction::EmbeddedShell
                 } else {
-                    self.open_native_terminal().await?;
-                }
+                    ContainerAction::NativeShell
+                };
+                self.pick_container_for(action).await?;
             }
             KeyCode::Up | KeyCode::Char('k') => {
                 if self.terminal_choice_selection > 0 {
@@ -331,410 +1775,2872 @@ impl App {
         Ok(true)
     }
 
-    async fn open_embedded_terminal(&mut self) -> Result<()> {
-        if let Some(pod) = self.pods.get(self.pod_index) {
-            self.status_message = format!("Connecting to pod: {}...", pod.name);
-
-            let namespace = self.current_namespace.clone();
-            let pod_name = pod.name.clone();
-
-            // Spawn terminal creation in a blocking task to avoid blocking the UI
-            // Try bash first (better for Ruby/Rails), fall back to sh if it fails
-            let result = tokio::task::spawn_blocking(move || {
-                // Try bash first
-                match TerminalSession::new_with_shell(&namespace, &pod_name, Some("/bin/bash")) {
-                    Ok(session) => Ok(session),
-                    Err(_) => {
-                        // Fall back to sh
-                        TerminalSession::new_with_shell(&namespace, &pod_name, Some("/bin/sh"))
-                    }
-                }
-            })
-            .await;
-
-            match result {
-                Ok(Ok(session)) => {
-                    self.terminal_session = Some(Arc::new(Mutex::new(session)));
-                    self.terminal_pod_name = Some(pod.name.clone());
-                    self.current_view = View::Terminal;
-                    self.status_message =
-                        format!("Connected to pod: {} | Press Esc to exit", pod.name);
-                }
-                Ok(Err(e)) => {
-                    self.error_message = Some(format!("Failed to exec into pod: {}. Make sure kubectl is installed and the pod has /bin/bash or /bin/sh", e));
+    async fn handle_container_picker_mode(&mut self, event: InputEvent) -> Result<bool> {
+        let count = self
+            .selected_pod()
+            .map(|p| p.containers.len())
+            .unwrap_or_default();
+        match event.key_code() {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+                self.container_picker = None;
+            }
+            KeyCode::Enter => {
+                self.run_picked_container(self.container_picker_index)
+                    .await?;
+            }
+            KeyCode::Char(c @ '1'..='9') => {
+                let index = c as usize - '1' as usize;
+                if index < count {
+                    self.run_picked_container(index).await?;
                 }
-                Err(e) => {
-                    self.error_message = Some(format!("Failed to spawn terminal task: {}", e));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.container_picker_index = self.container_picker_index.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if self.container_picker_index + 1 < count {
+                    self.container_picker_index += 1;
                 }
             }
+            _ => {}
         }
-        Ok(())
+        Ok(true)
     }
 
-    async fn open_native_terminal(&mut self) -> Result<()> {
-        if let Some(pod) = self.pods.get(self.pod_index) {
-            let namespace = self.current_namespace.clone();
-            let pod_name = pod.name.clone();
+    /// Run `action` on the selected pod. A pod with several containers gets the
+    /// container picker first, starting on the one kubectl would use.
+    async fn pick_container_for(&mut self, action: ContainerAction) -> Result<()> {
+        let Some(pod) = self.selected_pod() else {
+            return Ok(());
+        };
+        let container = pod.log_container();
+        if pod.containers.len() < 2 {
+            return self.run_container_action(action, container).await;
+        }
+        self.container_picker_index = container
+            .and_then(|name| pod.containers.iter().position(|c| c.name == name))
+            .unwrap_or(0);
+        self.container_picker = Some(action);
+        self.input_mode = InputMode::ContainerPicker;
+        Ok(())
+    }
 
-            // Open a new terminal tab
-            match KubeClient::open_pod_terminal(&namespace, &pod_name) {
-                Ok(_) => {
-                    self.status_message = format!(
-                        "Opened terminal tab for pod: {} | You can now run 'irb', 'rails c', or any interactive command",
-                        pod.name
-                    );
-                }
-                Err(e) => {
-                    self.error_message = Some(format!(
-                        "Failed to open terminal tab: {}. Falling back to manual command...",
-                        e
-                    ));
-                    // Show the manual command as a fallback
-                    self.status_message = format!(
-                        "Run this command in your terminal: kubectl exec -it -n {} {} -- /bin/bash",
-                        namespace, pod_name
-                    );
-                }
-            }
+    async fn run_picked_container(&mut self, index: usize) -> Result<()> {
+        self.input_mode = InputMode::Normal;
+        let container = self
+            .selected_pod()
+            .and_then(|p| p.containers.get(index))
+            .map(|c| c.name.clone());
+        if let (Some(action), Some(container)) = (self.container_picker.take(), container) {
+            self.run_container_action(action, Some(container)).await?;
         }
         Ok(())
     }
 
-    fn move_selection_up(&mut self) {
-        match self.current_view {
-            View::Pods => {
-                if self.pod_index > 0 {
-                    self.pod_index -= 1;
-                }
+    async fn run_container_action(
+        &mut self,
+        action: ContainerAction,
+        container: Option<String>,
+    ) -> Result<()> {
+        match action {
+            ContainerAction::Logs { follow } => self.open_pod_logs(follow, container).await,
+            ContainerAction::EmbeddedShell => self.open_embedded_terminal(container).await,
+            ContainerAction::NativeShell => self.open_native_terminal(container).await,
+        }
+    }
+
+    async fn handle_namespace_picker_mode(&mut self, event: InputEvent) -> Result<bool> {
+        match event.key_code() {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
             }
-            View::Deployments => {
-                if self.deployment_index > 0 {
-                    self.deployment_index -= 1;
+            KeyCode::Enter => {
+                let selected = self
+                    .namespace_picker_matches()
+                    .get(self.namespace_picker_index)
+                    .map(|ns| ns.to_string());
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
+                if let Some(namespace) = selected {
+                    self.switch_namespace(namespace).await?;
                 }
             }
-            View::Services => {
-                if self.service_index > 0 {
-                    self.service_index -= 1;
-                }
+            KeyCode::Up => {
+                self.namespace_picker_index = self.namespace_picker_index.saturating_sub(1);
             }
-            View::Clusters => {
-                if self.context_index > 0 {
-                    self.context_index -= 1;
+            KeyCode::Down => {
+                if self.namespace_picker_index + 1 < self.namespace_picker_matches().len() {
+                    self.namespace_picker_index += 1;
                 }
             }
-            View::Namespaces => {
-                if self.namespace_index > 0 {
-                    self.namespace_index -= 1;
-                }
+            KeyCode::Char(c) => {
+                self.input_buffer.push(c);
+                self.namespace_picker_index = 0;
             }
-            View::Logs => {
-                if self.logs_scroll > 0 {
-                    self.logs_scroll -= 1;
-                    self.logs_follow = false; // Disable follow when manually scrolling
-                }
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+                self.namespace_picker_index = 0;
             }
-            View::Help | View::Terminal => {}
+            _ => {}
         }
+        Ok(true)
     }
 
-    fn move_selection_down(&mut self) {
-        match self.current_view {
-            View::Pods => {
-                if self.pod_index < self.pods.len().saturating_sub(1) {
-                    self.pod_index += 1;
-                }
+    async fn handle_view_picker_mode(&mut self, event: InputEvent) -> Result<bool> {
+        match event.key_code() {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
             }
-            View::Deployments => {
-                if self.deployment_index < self.deployments.len().saturating_sub(1) {
-                    self.deployment_index += 1;
+            KeyCode::Enter => {
+                let selected = self
+                    .view_picker_matches()
+                    .get(self.view_picker_index)
+                    .map(|(_, view)| *view);
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
+                if let Some(view) = selected {
+                    self.current_view = view;
+                    self.refresh_on_focus().await?;
                 }
             }
-            View::Services => {
-                if self.service_index < self.services.len().saturating_sub(1) {
-                    self.service_index += 1;
+            // Complete the input to the highlighted view's name
+            KeyCode::Tab => {
+                if let Some((name, _)) = self.view_picker_matches().get(self.view_picker_index) {
+                    self.input_buffer = name.to_string();
+                    self.view_picker_index = 0;
                 }
             }
-            View::Clusters => {
-                if self.context_index < self.contexts.len().saturating_sub(1) {
-                    self.context_index += 1;
-                }
+            KeyCode::Up => {
+                self.view_picker_index = self.view_picker_index.saturating_sub(1);
             }
-            View::Namespaces => {
-                if self.namespace_index < self.namespaces.len().saturating_sub(1) {
-                    self.namespace_index += 1;
+            KeyCode::Down => {
+                if self.view_picker_index + 1 < self.view_picker_matches().len() {
+                    self.view_picker_index += 1;
                 }
             }
-            View::Logs => {
-                let log_lines = self.logs.lines().count();
-                if self.logs_scroll < log_lines.saturating_sub(1) {
-                    self.logs_scroll += 1;
-                    self.logs_follow = false; // Disable follow when manually scrolling
-                }
+            KeyCode::Char(c) => {
+                self.input_buffer.push(c);
+                self.view_picker_index = 0;
             }
-            View::Help | View::Terminal => {}
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+                self.view_picker_index = 0;
+            }
+            _ => {}
         }
+        Ok(true)
     }
 
-    async fn refresh_current_view(&mut self) -> Result<()> {
-        self.error_message = None;
-        match self.current_view {
-            View::Pods => {
-                // Start watcher if not already running
-                if self.pod_watcher.is_none() {
-                    match self.client.watch_pods(&self.current_namespace).await {
-                        Ok(watcher) => {
-                            self.pod_watcher = Some(watcher);
-                            self.auto_refresh_enabled = true;
-                        }
-                        Err(e) => {
-                            // Fallback to manual refresh if watch fails
-                            self.error_message = Some(format!(
-                                "Watch API failed (using manual refresh): {}. Press 'r' to refresh manually.",
-                                e
-                            ));
-                            self.auto_refresh_enabled = false;
-                        }
-                    }
-                }
+    /// Views matching the `:` switcher input: name prefixes and aliases first,
+    /// then names merely containing it
+    pub fn view_picker_matches(&self) -> Vec<(&'static str, View)> {
+        let query = self.input_buffer.trim().to_lowercase();
+        let mut matches: Vec<(bool, &'static str, View)> = SWITCHABLE_VIEWS
+            .iter()
+            .filter_map(|(name, aliases, view)| {
+                let prefix = name.starts_with(&query)
+                    || aliases.iter().any(|alias| alias.starts_with(&query));
+                (prefix || name.contains(&query)).then_some((!prefix, *name, *view))
+            })
+            .collect();
+        // Stable, so each group keeps the table's order
+        matches.sort_by_key(|(contained_only, _, _)| *contained_only);
+        matches
+            .into_iter()
+            .map(|(_, name, view)| (name, view))
+            .collect()
+    }
 
-                // Initial fetch
-                match self.client.list_pods(&self.current_namespace).await {
-                    Ok(pods) => {
-                        self.pods = pods;
-                        if self.pod_index >= self.pods.len() {
-                            self.pod_index = self.pods.len().saturating_sub(1);
-                        }
-                    }
-                    Err(e) => {
-                        self.error_message = Some(format!("Failed to list pods: {}", e));
+    async fn handle_global_search_mode(&mut self, event: InputEvent) -> Result<bool> {
+        match event.key_code() {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
+            }
+            KeyCode::Enter => {
+                // First Enter searches; once the hits match the input, Enter jumps
+                if self.search_query.as_deref() == Some(self.input_buffer.as_str())
+                    && !self.search_results.is_empty()
+                {
+                    let selected = self.search_results.get(self.search_index).cloned();
+                    self.input_mode = InputMode::Normal;
+                    self.input_buffer.clear();
+                    if let Some(result) = selected {
+                        self.jump_to_resource(result).await?;
                     }
+                } else if !self.input_buffer.is_empty() {
+                    self.run_global_search().await;
                 }
             }
-            View::Deployments => {
-                match self.client.list_deployments(&self.current_namespace).await {
-                    Ok(deployments) => {
-                        self.deployments = deployments;
-                        if self.deployment_index >= self.deployments.len() {
-                            self.deployment_index = self.deployments.len().saturating_sub(1);
-                        }
-                    }
-                    Err(e) => {
-                        self.error_message = Some(format!("Failed to list deployments: {}", e));
-                    }
+            KeyCode::Up => {
+                self.search_index = self.search_index.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                if self.search_index + 1 < self.search_results.len() {
+                    self.search_index += 1;
                 }
             }
-            View::Services => match self.client.list_services(&self.current_namespace).await {
-                Ok(services) => {
-                    self.services = services;
-                    if self.service_index >= self.services.len() {
-                        self.service_index = self.services.len().saturating_sub(1);
-                    }
+            KeyCode::Char(c) => {
+                self.input_buffer.push(c);
+            }
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    async fn run_global_search(&mut self) {
+        let query = self.input_buffer.clone();
+        match self.client.search_resources(&query).await {
+            Ok(mut results) => {
+                results.retain(|r| !self.config.namespaces.hides(&r.namespace));
+                results.sort_by(|a, b| {
+                    (a.kind.as_str(), &a.namespace, &a.name).cmp(&(
+                        b.kind.as_str(),
+                        &b.namespace,
+                        &b.name,
+                    ))
+                });
+                self.status_message = format!("{} match(es) for '{}'", results.len(), query);
+                self.search_results = results;
+                self.search_index = 0;
+                self.search_query = Some(query);
+            }
+            Err(e) => {
+                self.report_error("Search failed", &e);
+            }
+        }
+    }
+
+    /// Switch to the resource's namespace and list view, with it selected
+    async fn jump_to_resource(&mut self, result: SearchResult) -> Result<()> {
+        self.current_view = match result.kind {
+            ResourceKind::Pod => View::Pods,
+            ResourceKind::Deployment => View::Deployments,
+            ResourceKind::Service => View::Services,
+        };
+        if result.namespace != self.current_namespace {
+            self.switch_namespace(result.namespace.clone()).await?;
+        } else {
+            self.refresh_current_view().await?;
+        }
+
+        let index = match result.kind {
+            ResourceKind::Pod => self.pods.iter().position(|p| p.name == result.name),
+            ResourceKind::Deployment => self.deployments.iter().position(|d| d.name == result.name),
+            ResourceKind::Service => self.services.iter().position(|s| s.name == result.name),
+        };
+        // A name filter hiding the resource would leave nothing selected
+        if !self.shown_in_list(self.current_view, &result.name) {
+            self.list_filters.remove(&self.current_view);
+        }
+        match (result.kind, index) {
+            (ResourceKind::Pod, Some(i)) => self.pod_index = i,
+            (ResourceKind::Deployment, Some(i)) => self.deployment_index = i,
+            (ResourceKind::Service, Some(i)) => self.service_index = i,
+            (_, None) => {}
+        }
+        Ok(())
+    }
+
+    /// Namespaces matching the quick-picker filter (case-insensitive substring)
+    pub fn namespace_picker_matches(&self) -> Vec<&str> {
+        let filter = self.input_buffer.to_lowercase();
+        self.namespaces
+            .iter()
+            .filter(|ns| ns.to_lowercase().contains(&filter))
+            .map(|ns| ns.as_str())
+            .collect()
+    }
+
+    async fn open_embedded_terminal(&mut self, container: Option<String>) -> Result<()> {
+        if let Some(pod_name) = self.selected_pod().map(|p| p.name.clone()) {
+            self.status_message = format!("Connecting to pod: {}...", pod_name);
+
+            let namespace = self.current_namespace.clone();
+            let shell = self
+                .shell_for(&namespace, &pod_name, container.as_deref())
+                .await;
+            match Self::spawn_terminal_session(
+                namespace,
+                pod_name.clone(),
+                container,
+                shell,
+                kubectl_identity(&self.config),
+            )
+            .await
+            {
+                Ok(session) => {
+                    self.terminal_sessions.push(Arc::new(Mutex::new(session)));
+                    self.active_terminal = self.terminal_sessions.len() - 1;
+                    self.terminal_scroll = 0;
+                    self.current_view = View::Terminal;
+                    self.status_message = format!(
+                        "Connected to pod: {} | Esc: back to pods (shell keeps running) | Ctrl+D: close",
+                        pod_name
+                    );
                 }
                 Err(e) => {
-                    self.error_message = Some(format!("Failed to list services: {}", e));
+                    self.error_message = Some(e.to_string());
                 }
-            },
-            View::Clusters => match KubeClient::list_contexts() {
-                Ok(contexts) => {
-                    self.contexts = contexts;
-                    if self.context_index >= self.contexts.len() {
-                        self.context_index = self.contexts.len().saturating_sub(1);
-                    }
+            }
+        }
+        Ok(())
+    }
+
+    /// Shells, node shells, debug attaches and `kubectl describe` run kubectl, which
+    /// authenticates as the kubeconfig user. With a `token_file` that's not who the
+    /// header says we act as, so refuse rather than act as someone else. True if refused.
+    fn refuse_kubectl(&mut self, action: &str) -> bool {
+        let Some(path) = &self.config.token_file else {
+            return false;
+        };
+        self.error_message = Some(format!(
+            "{} runs kubectl, which would authenticate as the kubeconfig user instead of the token in {}; unavailable while token_file is set",
+            action,
+            path.display()
+        ));
+        true
+    }
+
+    /// Ask before opening a shell on the selected pod's node; it needs a privileged pod
+    fn request_node_shell(&mut self) {
+        let Some(pod) = self.selected_pod() else {
+            return;
+        };
+        if pod.node.is_empty() || pod.node == "<none>" {
+            self.status_message = format!("Pod {} isn't scheduled on a node yet", pod.name);
+            return;
+        }
+        let node = pod.node.clone();
+        if self.refuse_kubectl("A node shell") {
+            return;
+        }
+        self.node_shell_target = Some(node);
+        self.input_mode = InputMode::ConfirmNodeShell;
+    }
+
+    /// Start `kubectl debug node/...` in an embedded terminal. When that fails, e.g.
+    /// kubectl is too old for `--profile`, copy the command so it can be run by hand.
+    async fn open_node_shell(&mut self) -> Result<()> {
+        let Some(node) = self.node_shell_target.take() else {
+            return Ok(());
+        };
+        let namespace = self.current_namespace.clone();
+        self.status_message = format!("Starting debug pod on node {}...", node);
+
+        let (ns, name) = (namespace.clone(), node.clone());
+        let identity = kubectl_identity(&self.config);
+        let result =
+            tokio::task::spawn_blocking(move || TerminalSession::node_shell(&ns, &name, &identity))
+                .await;
+        match result {
+            Ok(Ok(session)) => {
+                self.terminal_sessions.push(Arc::new(Mutex::new(session)));
+                self.active_terminal = self.terminal_sessions.len() - 1;
+                self.terminal_scroll = 0;
+                self.current_view = View::Terminal;
+                self.record_activity(format!("Opened shell on node {}", node));
+                self.status_message = format!(
+                    "Shell on node {} (host filesystem at /) | Esc: back | Ctrl+D: close; the debug pod stays in {}",
+                    node, namespace
+                );
+            }
+            Ok(Err(e)) => self.node_shell_fallback(&namespace, &node, &e.to_string()),
+            Err(e) => self.node_shell_fallback(&namespace, &node, &e.to_string()),
+        }
+        Ok(())
+    }
+
+    /// Add an ephemeral debug container to the pod awaiting confirmation, for
+    /// images that ship without a shell; the main loop attaches once it runs
+    async fn open_debug_container(&mut self) -> Result<()> {
+        let Some((pod, target)) = self.debug_target.take() else {
+            return Ok(());
+        };
+        let namespace = self.current_namespace.clone();
+        self.status_message = format!("Starting debug container in pod {}...", pod);
+
+        let container = match self
+            .client
+            .add_debug_container(&namespace, &pod, target.as_deref())
+            .await
+        {
+            Ok(container) => container,
+            Err(e) => {
+                self.report_error("Failed to add debug container", &e);
+                return Ok(());
+            }
+        };
+        self.record_activity(format!(
+            "Added debug container {} to pod {}",
+            container, pod
+        ));
+
+        // Pulling the image can take a while; the main loop waits while the UI runs
+        self.status_message = format!(
+            "Waiting for debug container {} in pod {} to start...",
+            container, pod
+        );
+        self.debug_start = Some(DebugStart {
+            namespace,
+            pod,
+            container,
+            started: Instant::now(),
+            last_check: None,
+        });
+        Ok(())
+    }
+
+    /// Check at most once a second whether the debug container being waited on
+    /// runs yet, and attach to it once it does (called from the main loop)
+    pub async fn advance_debug_container(&mut self) {
+        let Some(start) = &mut self.debug_start else {
+            return;
+        };
+        if start
+            .last_check
+            .is_some_and(|checked| checked.elapsed() < Duration::from_secs(1))
+        {
+            return;
+        }
+        start.last_check = Some(Instant::now());
+        let start = start.clone();
+
+        match self
+            .client
+            .debug_container_running(&start.namespace, &start.pod, &start.container)
+            .await
+        {
+            Ok(true) => {
+                self.debug_start = None;
+                self.attach_debug_container(start.namespace, start.pod, start.container)
+                    .await;
+            }
+            Ok(false) if start.started.elapsed() >= DEBUG_CONTAINER_START_TIMEOUT => {
+                self.debug_start = None;
+                self.error_message = Some(format!(
+                    "Debug container {} didn't start within {}s",
+                    start.container,
+                    DEBUG_CONTAINER_START_TIMEOUT.as_secs()
+                ));
+            }
+            Ok(false) => {
+                self.status_message = format!(
+                    "Waiting for debug container {} in pod {} to start ({}s)...",
+                    start.container,
+                    start.pod,
+                    start.started.elapsed().as_secs()
+                );
+            }
+            Err(e) => {
+                self.debug_start = None;
+                self.report_error("Debug container didn't start", &e);
+            }
+        }
+    }
+
+    async fn attach_debug_container(&mut self, namespace: String, pod: String, container: String) {
+        let (ns, name, c) = (namespace, pod.clone(), container.clone());
+        let identity = kubectl_identity(&self.config);
+        let result =
+            tokio::task::spawn_blocking(move || TerminalSession::attach(&ns, &name, &c, &identity))
+                .await;
+        match result {
+            Ok(Ok(session)) => {
+                self.terminal_sessions.push(Arc::new(Mutex::new(session)));
+                self.active_terminal = self.terminal_sessions.len() - 1;
+                self.terminal_scroll = 0;
+                self.current_view = View::Terminal;
+                self.status_message = format!(
+                    "Attached to {} in pod {} | Esc: back to pods | Ctrl+D: close",
+                    container, pod
+                );
+            }
+            Ok(Err(e)) => {
+                self.error_message = Some(format!("Failed to attach to {}: {}", container, e));
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to spawn terminal task: {}", e));
+            }
+        }
+    }
+
+    fn node_shell_fallback(&mut self, namespace: &str, node: &str, reason: &str) {
+        let command = node_shell_command(namespace, node, &kubectl_identity(&self.config));
+        self.error_message = Some(format!("Failed to open node shell: {}", reason));
+        self.status_message = match clipboard::copy(&command) {
+            Ok(_) => format!("Copied, run it yourself: {}", command),
+            Err(_) => format!("Run this command in your terminal: {}", command),
+        };
+    }
+
+    /// The shell to exec into `pod_name` with, if `terminal.detect_shell` is on and
+    /// one was found. Results are cached by image; a failed probe isn't cached.
+    async fn shell_for(
+        &mut self,
+        namespace: &str,
+        pod_name: &str,
+        container: Option<&str>,
+    ) -> Option<String> {
+        if !self.config.terminal.detect_shell || namespace != self.current_namespace {
+            return None;
+        }
+        let pod = self.pods.iter().find(|p| p.name == pod_name)?;
+        let image = match container {
+            Some(container) => pod
+                .containers
+                .iter()
+                .find(|c| c.name == container)?
+                .image
+                .clone(),
+            None => pod.image.clone(),
+        };
+        if let Some(shell) = self.shell_cache.get(&image) {
+            return Some(shell.clone());
+        }
+
+        let namespace = namespace.to_string();
+        let pod = pod_name.to_string();
+        let container = container.map(str::to_string);
+        let identity = kubectl_identity(&self.config);
+        let detected = tokio::task::spawn_blocking(move || {
+            KubeClient::detect_shell(&namespace, &pod, container.as_deref(), &identity)
+        })
+        .await;
+        match detected {
+            Ok(Ok(shell)) => {
+                self.shell_cache.insert(image, shell.clone());
+                Some(shell)
+            }
+            _ => None,
+        }
+    }
+
+    /// Start a kubectl exec session with `shell`, or when that's unknown try bash
+    /// first and fall back to sh
+    async fn spawn_terminal_session(
+        namespace: String,
+        pod_name: String,
+        container: Option<String>,
+        shell: Option<String>,
+        identity: Vec<String>,
+    ) -> Result<TerminalSession> {
+        // Spawn terminal creation in a blocking task to avoid blocking the UI
+        // Try bash first (better for Ruby/Rails), fall back to sh if it fails
+        let result = tokio::task::spawn_blocking(move || {
+            let container = container.as_deref();
+            if let Some(shell) = shell {
+                return TerminalSession::new_with_shell(
+                    &namespace,
+                    &pod_name,
+                    container,
+                    Some(&shell),
+                    &identity,
+                );
+            }
+            // Try bash first
+            match TerminalSession::new_with_shell(
+                &namespace,
+                &pod_name,
+                container,
+                Some("/bin/bash"),
+                &identity,
+            ) {
+                Ok(session) => Ok(session),
+                Err(_) => {
+                    // Fall back to sh
+                    TerminalSession::new_with_shell(
+                        &namespace,
+                        &pod_name,
+                        container,
+                        Some("/bin/sh"),
+                        &identity,
+                    )
                 }
-                Err(e) => {
-                    self.error_message = Some(format!("Failed to list contexts: {}", e));
+            }
+        })
+        .await;
+
+        match result {
+            Ok(Ok(session)) => Ok(session),
+            Ok(Err(e)) => Err(anyhow::anyhow!("Failed to exec into pod: {}. Make sure kubectl is installed and the pod has /bin/bash or /bin/sh", e)),
+            Err(e) => Err(anyhow::anyhow!("Failed to spawn terminal task: {}", e)),
+        }
+    }
+
+    /// Re-establish the active terminal's exec to the same pod, e.g. after it restarted
+    async fn reconnect_active_terminal(&mut self) -> Result<()> {
+        let Some(session) = self.terminal_sessions.get(self.active_terminal).cloned() else {
+            return Ok(());
+        };
+
+        let (namespace, pod_name, container, exec_container) = match session.lock() {
+            Ok(mut session) => {
+                let _ = session.close();
+                (
+                    session.namespace.clone(),
+                    session.pod_name.clone(),
+                    session.container.clone(),
+                    session.exec_container.clone(),
+                )
+            }
+            Err(_) => return Ok(()),
+        };
+
+        if let Some(container) = container {
+            // Only works while the debug container's shell is still running; an
+            // ephemeral container that exited can't be restarted
+            let (ns, name, c) = (namespace, pod_name.clone(), container.clone());
+            let identity = kubectl_identity(&self.config);
+            let result = tokio::task::spawn_blocking(move || {
+                TerminalSession::attach(&ns, &name, &c, &identity)
+            })
+            .await;
+            match result {
+                Ok(Ok(new_session)) => {
+                    self.terminal_sessions[self.active_terminal] =
+                        Arc::new(Mutex::new(new_session));
+                    self.terminal_scroll = 0;
+                    self.status_message =
+                        format!("Reattached to {} in pod {}", container, pod_name);
                 }
-            },
-            View::Namespaces => {
-                // Namespaces are already loaded, just ensure index is valid
-                if self.namespace_index >= self.namespaces.len() {
-                    self.namespace_index = self.namespaces.len().saturating_sub(1);
+                _ => {
+                    self.error_message = Some(format!(
+                        "Can't reattach to {}; it has probably exited. Press D in Pods for a new debug container",
+                        container
+                    ));
+                }
+            }
+            return Ok(());
+        }
+
+        if let Some(node) = pod_name.strip_prefix("node/") {
+            // Reconnecting means another privileged debug pod, so ask again like
+            // Shift+E does; the terminal view would swallow the answer
+            self.terminal_sessions.remove(self.active_terminal);
+            self.active_terminal = self.active_terminal.saturating_sub(1);
+            self.current_view = View::Pods;
+            self.node_shell_target = Some(node.to_string());
+            self.input_mode = InputMode::ConfirmNodeShell;
+            // Only the listed namespace's pods are known
+            let listed = namespace == self.current_namespace;
+            let prefix = format!("node-debugger-{}-", node);
+            let leftover = self
+                .pods
+                .iter()
+                .filter(|p| listed && p.name.starts_with(&prefix))
+                .max_by_key(|p| p.created)
+                .map(|p| p.name.clone())
+                .unwrap_or_else(|| format!("{}...", prefix));
+            self.status_message = format!(
+                "The previous debug pod {} in {} is still running; delete it once you're done",
+                leftover, namespace
+            );
+            return Ok(());
+        }
+
+        self.status_message = format!("Reconnecting to pod: {}...", pod_name);
+        let shell = self
+            .shell_for(&namespace, &pod_name, exec_container.as_deref())
+            .await;
+        match Self::spawn_terminal_session(
+            namespace,
+            pod_name.clone(),
+            exec_container,
+            shell,
+            kubectl_identity(&self.config),
+        )
+        .await
+        {
+            Ok(new_session) => {
+                self.terminal_sessions[self.active_terminal] = Arc::new(Mutex::new(new_session));
+                self.terminal_scroll = 0;
+                self.status_message = format!("Reconnected to pod: {}", pod_name);
+            }
+            Err(e) => {
+                self.error_message = Some(e.to_string());
+            }
+        }
+        Ok(())
+    }
+
+    async fn open_native_terminal(&mut self, container: Option<String>) -> Result<()> {
+        if let Some(pod_name) = self.selected_pod().map(|p| p.name.clone()) {
+            let namespace = self.current_namespace.clone();
+            let shell = self
+                .shell_for(&namespace, &pod_name, container.as_deref())
+                .await;
+
+            // Open a new terminal tab
+            let identity = kubectl_identity(&self.config);
+            match KubeClient::open_pod_terminal(
+                &namespace,
+                &pod_name,
+                container.as_deref(),
+                shell.as_deref(),
+                &identity,
+            ) {
+                Ok(_) => {
+                    self.status_message = format!(
+                        "Opened terminal tab for pod: {} | You can now run 'irb', 'rails c', or any interactive command",
+                        pod_name
+                    );
+                }
+                Err(e) => {
+                    self.error_message = Some(format!(
+                        "Failed to open terminal tab: {}. Falling back to manual command...",
+                        e
+                    ));
+                    // Show the manual command as a fallback
+                    self.status_message = format!(
+                        "Run this command in your terminal: kubectl exec -it -n {} {}{}{} -- /bin/bash",
+                        namespace,
+                        pod_name,
+                        container.map(|c| format!(" -c {}", c)).unwrap_or_default(),
+                        identity.iter().map(|flag| format!(" {}", flag)).collect::<String>()
+                    );
                 }
             }
-            View::Logs | View::Help | View::Terminal => {}
         }
         Ok(())
     }
 
-    async fn delete_current_item(&mut self) -> Result<()> {
+    pub fn move_selection_up(&mut self) {
         match self.current_view {
             View::Pods => {
-                if let Some(pod) = self.pods.get(self.pod_index) {
-                    match self
-                        .client
-                        .delete_pod(&self.current_namespace, &pod.name)
-                        .await
-                    {
-                        Ok(_) => {
-                            self.status_message = format!("Deleted pod {}", pod.name);
-                            self.refresh_current_view().await?;
+                if let Some(index) = self.next_shown(View::Pods, self.pod_index, false) {
+                    self.pod_index = index;
+                }
+            }
+            View::Deployments => {
+                if let Some(index) =
+                    self.next_shown(View::Deployments, self.deployment_index, false)
+                {
+                    self.deployment_index = index;
+                }
+            }
+            View::Services => {
+                if let Some(index) = self.next_shown(View::Services, self.service_index, false) {
+                    self.service_index = index;
+                }
+            }
+            View::Events => {
+                if self.event_index > 0 {
+                    self.event_index -= 1;
+                }
+            }
+            View::ConfigMaps => {
+                if self.configmap_index > 0 {
+                    self.configmap_index -= 1;
+                }
+            }
+            View::Secrets => {
+                if self.secret_index > 0 {
+                    self.secret_index -= 1;
+                }
+            }
+            View::Clusters => {
+                if self.context_index > 0 {
+                    self.context_index -= 1;
+                }
+            }
+            View::Namespaces => {
+                if self.namespace_index > 0 {
+                    self.namespace_index -= 1;
+                }
+            }
+            View::Activity => {
+                if self.activity_index > 0 {
+                    self.activity_index -= 1;
+                }
+            }
+            View::PortForwards => {
+                if self.port_forward_index > 0 {
+                    self.port_forward_index -= 1;
+                }
+            }
+            View::Detail => {
+                if let Some(Detail::History(_)) = &self.detail {
+                    self.history_index = self.history_index.saturating_sub(1);
+                } else {
+                    self.detail_scroll = self.detail_scroll.saturating_sub(1);
+                }
+            }
+            View::Logs => {
+                if self.logs_scroll > 0 {
+                    self.logs_scroll -= 1;
+                    // Keep following in the background, but stop pulling the view down
+                    self.logs_paused = self.logs_follow;
+                }
+            }
+            View::Help | View::Terminal => {}
+        }
+    }
+
+    pub fn move_selection_down(&mut self) {
+        match self.current_view {
+            View::Pods => {
+                if let Some(index) = self.next_shown(View::Pods, self.pod_index, true) {
+                    self.pod_index = index;
+                }
+            }
+            View::Deployments => {
+                if let Some(index) = self.next_shown(View::Deployments, self.deployment_index, true)
+                {
+                    self.deployment_index = index;
+                }
+            }
+            View::Services => {
+                if let Some(index) = self.next_shown(View::Services, self.service_index, true) {
+                    self.service_index = index;
+                }
+            }
+            View::Events => {
+                if self.event_index < self.events.len().saturating_sub(1) {
+                    self.event_index += 1;
+                }
+            }
+            View::ConfigMaps => {
+                if self.configmap_index < self.configmaps.len().saturating_sub(1) {
+                    self.configmap_index += 1;
+                }
+            }
+            View::Secrets => {
+                if self.secret_index < self.secrets.len().saturating_sub(1) {
+                    self.secret_index += 1;
+                }
+            }
+            View::Clusters => {
+                if self.context_index < self.contexts.len().saturating_sub(1) {
+                    self.context_index += 1;
+                }
+            }
+            View::Namespaces => {
+                if self.namespace_index < self.namespaces.len().saturating_sub(1) {
+                    self.namespace_index += 1;
+                }
+            }
+            View::Activity => {
+                if self.activity_index < self.activity.len().saturating_sub(1) {
+                    self.activity_index += 1;
+                }
+            }
+            View::PortForwards => {
+                if self.port_forward_index < self.port_forwards.len().saturating_sub(1) {
+                    self.port_forward_index += 1;
+                }
+            }
+            View::Detail => {
+                if let Some(Detail::History(history)) = &self.detail {
+                    if self.history_index < history.revisions.len().saturating_sub(1) {
+                        self.history_index += 1;
+                    }
+                } else {
+                    self.detail_scroll = self.detail_scroll.saturating_add(1);
+                }
+            }
+            View::Logs => {
+                let log_lines = self.shown_log_line_count();
+                if self.logs_scroll < log_lines.saturating_sub(1) {
+                    self.logs_scroll += 1;
+                }
+                // Back at the bottom, a paused follow picks up again
+                if self.logs_scroll >= log_lines.saturating_sub(1) {
+                    self.logs_paused = false;
+                }
+            }
+            View::Help | View::Terminal => {}
+        }
+    }
+
+    async fn refresh_current_view(&mut self) -> Result<()> {
+        // The views render a "not connected" notice; listing would only fail
+        if !self.connected && self.current_view.needs_cluster() {
+            return Ok(());
+        }
+
+        self.clear_error();
+        self.view_loaded_at
+            .insert(self.current_view, Instant::now());
+        match self.current_view {
+            View::Pods => {
+                // Start watcher if not already running
+                if self.pod_watcher.is_none() {
+                    match self.client.watch_pods(&self.current_namespace).await {
+                        Ok(watcher) => {
+                            self.pod_watcher = Some(watcher);
+                            self.auto_refresh_enabled = true;
                         }
                         Err(e) => {
-                            self.error_message = Some(format!("Failed to delete pod: {}", e));
+                            // Fallback to manual refresh if watch fails
+                            self.report_error(
+                                "Watch API failed, refresh manually with 'r' instead",
+                                &e,
+                            );
+                            self.auto_refresh_enabled = false;
+                        }
+                    }
+                }
+
+                // Initial fetch
+                match self.client.list_pods(&self.current_namespace).await {
+                    Ok(pods) => {
+                        self.pods = pods;
+                        self.order_pods();
+                        if self.pod_index >= self.pods.len() {
+                            self.pod_index = self.pods.len().saturating_sub(1);
                         }
+                        self.keep_selection_shown(View::Pods);
+                    }
+                    Err(e) => {
+                        self.report_error("Failed to list pods", &e);
                     }
                 }
             }
             View::Deployments => {
-                if let Some(deployment) = self.deployments.get(self.deployment_index) {
+                match self.client.list_deployments(&self.current_namespace).await {
+                    Ok(deployments) => {
+                        self.deployments = deployments;
+                        sort_deployments(
+                            &mut self.deployments,
+                            &self.config.deployments.sort_keys(),
+                        );
+                        if self.deployment_index >= self.deployments.len() {
+                            self.deployment_index = self.deployments.len().saturating_sub(1);
+                        }
+                        self.keep_selection_shown(View::Deployments);
+                    }
+                    Err(e) => {
+                        self.report_error("Failed to list deployments", &e);
+                    }
+                }
+            }
+            View::Services => match self.client.list_services(&self.current_namespace).await {
+                Ok(services) => {
+                    self.services = services;
+                    sort_services(&mut self.services, &self.config.services.sort_keys());
+                    if self.service_index >= self.services.len() {
+                        self.service_index = self.services.len().saturating_sub(1);
+                    }
+                    self.keep_selection_shown(View::Services);
+                }
+                Err(e) => {
+                    self.report_error("Failed to list services", &e);
+                }
+            },
+            View::Events => match self.client.list_events(&self.current_namespace).await {
+                Ok(events) => {
+                    self.warning_count = events
+                        .iter()
+                        .filter(|e| {
+                            e.type_ == "Warning"
+                                && e.last_seen.is_some_and(|t| {
+                                    t >= chrono::Utc::now() - chrono::Duration::hours(1)
+                                })
+                        })
+                        .count();
+                    self.events = events;
+                    if let Some(spec) = &self.config.events.sort {
+                        sort_events(&mut self.events, &SortKey::parse_list(spec));
+                    }
+                    if self.event_index >= self.events.len() {
+                        self.event_index = self.events.len().saturating_sub(1);
+                    }
+                }
+                Err(e) => {
+                    self.report_error("Failed to list events", &e);
+                }
+            },
+            View::ConfigMaps => match self.client.list_configmaps(&self.current_namespace).await {
+                Ok(configmaps) => {
+                    self.configmaps = configmaps;
+                    if self.configmap_index >= self.configmaps.len() {
+                        self.configmap_index = self.configmaps.len().saturating_sub(1);
+                    }
+                }
+                Err(e) => {
+                    self.report_error("Failed to list configmaps", &e);
+                }
+            },
+            View::Secrets => match self.client.list_secrets(&self.current_namespace).await {
+                Ok(secrets) => {
+                    self.secrets = secrets;
+                    if self.secret_index >= self.secrets.len() {
+                        self.secret_index = self.secrets.len().saturating_sub(1);
+                    }
+                }
+                Err(e) => {
+                    self.report_error("Failed to list secrets", &e);
+                }
+            },
+            View::Clusters => match KubeClient::list_contexts() {
+                Ok(contexts) => self.set_contexts(contexts),
+                Err(e) => {
+                    self.report_error("Failed to list contexts", &e);
+                }
+            },
+            View::Namespaces => {
+                // Namespaces are already loaded, just ensure index is valid
+                if self.namespace_index >= self.namespaces.len() {
+                    self.namespace_index = self.namespaces.len().saturating_sub(1);
+                }
+            }
+            View::Logs
+            | View::Help
+            | View::Terminal
+            | View::Activity
+            | View::PortForwards
+            | View::Detail => {}
+        }
+        Ok(())
+    }
+
+    /// Check the current cluster answers, and how fast, without leaving the view
+    async fn ping_cluster(&mut self) {
+        match self.client.ping().await {
+            Ok(latency) => {
+                self.status_message = format!(
+                    "{} answered in {} ms",
+                    self.current_context,
+                    latency.as_millis()
+                );
+            }
+            Err(e) => {
+                self.report_error(&format!("Ping to {} failed", self.current_context), &e);
+            }
+        }
+    }
+
+    /// Reload the view just switched to, unless it was loaded within
+    /// `FOCUS_REFRESH_AFTER`. Pods also reload when their watcher was stopped on
+    /// leaving, so the list goes back to updating live.
+    async fn refresh_on_focus(&mut self) -> Result<()> {
+        let recent = self
+            .view_loaded_at
+            .get(&self.current_view)
+            .is_some_and(|loaded| loaded.elapsed() < FOCUS_REFRESH_AFTER);
+        let unwatched = self.current_view == View::Pods && self.pod_watcher.is_none();
+        if recent && !unwatched {
+            return Ok(());
+        }
+        self.refresh_current_view().await
+    }
+
+    async fn delete_current_item(&mut self) -> Result<()> {
+        match self.current_view {
+            View::PortForwards => self.stop_selected_port_forward(),
+            View::Pods => {
+                if let Some(pod) = self.selected_pod().cloned() {
                     match self
                         .client
-                        .delete_deployment(&self.current_namespace, &deployment.name)
+                        .delete_pod(&self.current_namespace, &pod.name)
                         .await
                     {
                         Ok(_) => {
-                            self.status_message = format!("Deleted deployment {}", deployment.name);
+                            self.status_message = format!("Deleted pod {}", pod.name);
+                            self.record_activity(format!("Deleted pod {}", pod.name));
                             self.refresh_current_view().await?;
                         }
                         Err(e) => {
-                            self.error_message =
-                                Some(format!("Failed to delete deployment: {}", e));
+                            self.report_error("Failed to delete pod", &e);
                         }
                     }
                 }
             }
-            _ => {}
+            View::Deployments => {
+                // Deleting a deployment can take its pods along or not, so ask how
+                if let Some(deployment) = self.selected_deployment() {
+                    self.delete_target = Some(deployment.name.clone());
+                    self.delete_propagation = DeletePropagation::default();
+                    self.input_mode = InputMode::ConfirmDelete;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn cycle_delete_propagation(&mut self, forward: bool) {
+        let all = DeletePropagation::ALL;
+        let current = all
+            .iter()
+            .position(|p| *p == self.delete_propagation)
+            .unwrap_or(0);
+        let next = if forward {
+            (current + 1) % all.len()
+        } else {
+            (current + all.len() - 1) % all.len()
+        };
+        self.delete_propagation = all[next];
+    }
+
+    async fn delete_confirmed_deployment(&mut self) -> Result<()> {
+        let Some(name) = self.delete_target.take() else {
+            return Ok(());
+        };
+        let propagation = self.delete_propagation;
+        match self
+            .client
+            .delete_deployment(&self.current_namespace, &name, propagation)
+            .await
+        {
+            Ok(_) => {
+                self.status_message = match propagation {
+                    DeletePropagation::Orphan => {
+                        format!("Deleted deployment {}; its pods keep running", name)
+                    }
+                    _ => format!("Deleted deployment {}", name),
+                };
+                self.record_activity(format!(
+                    "Deleted deployment {} ({} propagation)",
+                    name,
+                    propagation.as_str()
+                ));
+                self.refresh_current_view().await?;
+            }
+            Err(e) => {
+                self.report_error("Failed to delete deployment", &e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn describe_selected_pod(&mut self) -> Result<()> {
+        if let Some(pod) = self.selected_pod() {
+            match self
+                .client
+                .describe_pod(&self.current_namespace, &pod.name)
+                .await
+            {
+                Ok(detail) => {
+                    self.open_detail(Detail::Pod(detail));
+                    // First sample right away rather than after a full interval
+                    self.sample_pod_usage().await;
+                }
+                Err(e) => {
+                    self.report_error("Failed to describe pod", &e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// `kubectl describe node` for the node the selected pod runs on
+    async fn describe_selected_pods_node(&mut self) {
+        let Some(node) = self.selected_pod().map(|p| p.node.clone()) else {
+            return;
+        };
+        if node.is_empty() || node == "<none>" {
+            self.status_message = "The selected pod isn't scheduled on a node yet".to_string();
+            return;
+        }
+        match self.client.describe_node(&node).await {
+            Ok(detail) => self.open_detail(Detail::Node(detail)),
+            Err(e) => {
+                self.report_error("Failed to describe node", &e);
+            }
+        }
+    }
+
+    async fn describe_selected_deployment(&mut self) -> Result<()> {
+        if let Some(deployment) = self.selected_deployment() {
+            match self
+                .client
+                .describe_deployment(&self.current_namespace, &deployment.name)
+                .await
+            {
+                Ok(detail) => self.open_detail(Detail::Deployment(detail)),
+                Err(e) => {
+                    self.report_error("Failed to describe deployment", &e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn open_deployment_history(&mut self) {
+        let Some(deployment) = self.selected_deployment() else {
+            return;
+        };
+        match self
+            .client
+            .deployment_history(&self.current_namespace, &deployment.name)
+            .await
+        {
+            Ok(history) => {
+                self.history_index = 0;
+                self.open_detail(Detail::History(history));
+            }
+            Err(e) => {
+                self.report_error("Failed to get rollout history", &e);
+            }
+        }
+    }
+
+    /// Ask before rolling back to the revision selected in the history
+    fn request_rollback(&mut self) {
+        let Some(Detail::History(history)) = &self.detail else {
+            return;
+        };
+        let Some(revision) = history.revisions.get(self.history_index) else {
+            return;
+        };
+        if history.current_revision == Some(revision.revision) {
+            self.status_message = format!(
+                "{} is already at revision {}",
+                history.name, revision.revision
+            );
+            return;
+        }
+        self.rollback_revision = Some(revision.revision);
+        self.input_mode = InputMode::ConfirmRollback;
+    }
+
+    async fn rollback_confirmed_revision(&mut self) -> Result<()> {
+        let Some(revision) = self.rollback_revision.take() else {
+            return Ok(());
+        };
+        let Some(Detail::History(history)) = &self.detail else {
+            return Ok(());
+        };
+        let name = history.name.clone();
+        match self
+            .client
+            .rollback_deployment(&self.current_namespace, &name, revision)
+            .await
+        {
+            Ok(()) => {
+                self.status_message = format!(
+                    "Rolling {} back to revision {} (w watches the rollout)",
+                    name, revision
+                );
+                self.record_activity(format!(
+                    "Rolled back deployment {} to revision {}",
+                    name, revision
+                ));
+                self.close_detail();
+                self.refresh_current_view().await?;
+            }
+            Err(e) => {
+                self.report_error(&format!("Failed to roll back {}", name), &e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn handle_env_edit_mode(&mut self, event: InputEvent) -> Result<bool> {
+        match event.key_code() {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
+            }
+            KeyCode::Enter => {
+                let input = std::mem::take(&mut self.input_buffer);
+                self.input_mode = InputMode::Normal;
+                self.apply_env_edit(&input).await?;
+            }
+            KeyCode::Char(c) => {
+                self.input_buffer.push(c);
+            }
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    async fn handle_port_forward_mode(&mut self, event: InputEvent) -> Result<bool> {
+        match event.key_code() {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
+                self.port_forward_pod = None;
+            }
+            KeyCode::Enter => {
+                let input = std::mem::take(&mut self.input_buffer);
+                self.input_mode = InputMode::Normal;
+                let Some(pod) = self.port_forward_pod.take() else {
+                    return Ok(true);
+                };
+                match parse_port_forward(&input) {
+                    Some((local, remote)) => self.start_port_forward(&pod, local, remote).await,
+                    None => {
+                        self.error_message = Some(format!(
+                            "Invalid ports '{}': use REMOTE, LOCAL:REMOTE or :REMOTE",
+                            input
+                        ));
+                    }
+                }
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() || c == ':' => {
+                self.input_buffer.push(c);
+            }
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    async fn start_port_forward(&mut self, pod: &str, local_port: u16, remote_port: u16) {
+        match self
+            .client
+            .port_forward(&self.current_namespace, pod, local_port, remote_port)
+            .await
+        {
+            Ok(handle) => {
+                self.status_message = format!(
+                    "Forwarding {} -> {}:{} (:pf to list and stop)",
+                    handle.local_addr, pod, remote_port
+                );
+                self.port_forwards.push(handle);
+            }
+            Err(e) => {
+                self.report_error("Failed to start port forward", &e);
+            }
+        }
+    }
+
+    /// Stop the selected forward, freeing its local port
+    fn stop_selected_port_forward(&mut self) {
+        if self.port_forward_index >= self.port_forwards.len() {
+            return;
+        }
+        let handle = self.port_forwards.remove(self.port_forward_index);
+        handle.cancel();
+        self.status_message = format!(
+            "Stopped forwarding {} -> {}:{}",
+            handle.local_addr, handle.pod, handle.remote_port
+        );
+        if self.port_forward_index >= self.port_forwards.len() {
+            self.port_forward_index = self.port_forwards.len().saturating_sub(1);
+        }
+    }
+
+    /// Typing narrows the list as it goes; Enter keeps the filter, Esc clears it
+    fn handle_filter_mode(&mut self, event: InputEvent) -> Result<bool> {
+        match event.key_code() {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
+                self.list_filters.remove(&self.current_view);
+            }
+            KeyCode::Enter => {
+                let query = std::mem::take(&mut self.input_buffer);
+                self.input_mode = InputMode::Normal;
+                if query.is_empty() {
+                    self.list_filters.remove(&self.current_view);
+                } else {
+                    self.list_filters.insert(self.current_view, query);
+                }
+            }
+            KeyCode::Char(c) => {
+                self.input_buffer.push(c);
+            }
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+            }
+            _ => {}
+        }
+        self.keep_selection_shown(self.current_view);
+        Ok(true)
+    }
+
+    fn handle_log_filter_mode(&mut self, event: InputEvent) -> Result<bool> {
+        match event.key_code() {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
+            }
+            KeyCode::Enter => {
+                let pattern = std::mem::take(&mut self.input_buffer);
+                let exclude = self.input_mode == InputMode::LogExclude;
+                self.input_mode = InputMode::Normal;
+                self.set_log_filter(&pattern, exclude);
+            }
+            KeyCode::Char(c) => {
+                self.input_buffer.push(c);
+            }
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    /// Set the include or exclude filter, or clear it for an empty pattern. An
+    /// invalid regex leaves the current filter in place.
+    fn set_log_filter(&mut self, pattern: &str, exclude: bool) {
+        let regex = if pattern.is_empty() {
+            None
+        } else {
+            match Regex::new(pattern) {
+                Ok(regex) => Some(regex),
+                Err(e) => {
+                    self.error_message = Some(format!("Invalid regex: {}", e));
+                    return;
+                }
+            }
+        };
+        if exclude {
+            self.logs_exclude = regex;
+        } else {
+            self.logs_include = regex;
+        }
+
+        let shown = self.shown_log_line_count();
+        self.logs_scroll = if self.logs_follow {
+            shown.saturating_sub(1)
+        } else {
+            self.logs_scroll.min(shown.saturating_sub(1))
+        };
+        self.status_message = format!(
+            "Showing {} of {} lines (i to include, x to exclude, empty clears)",
+            shown,
+            self.logs.lines().count()
+        );
+    }
+
+    /// Whether a log line gets past the include and exclude filters
+    pub fn log_line_shown(&self, line: &str) -> bool {
+        self.logs_include
+            .as_ref()
+            .is_none_or(|re| re.is_match(line))
+            && !self
+                .logs_exclude
+                .as_ref()
+                .is_some_and(|re| re.is_match(line))
+    }
+
+    /// Lines of the log left after the filters; scrolling moves through these
+    pub fn shown_log_line_count(&self) -> usize {
+        if self.logs_include.is_none() && self.logs_exclude.is_none() {
+            return self.logs.lines().count();
+        }
+        self.logs
+            .lines()
+            .filter(|line| self.log_line_shown(line))
+            .count()
+    }
+
+    async fn handle_log_grep_mode(&mut self, event: InputEvent) -> Result<bool> {
+        match event.key_code() {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
+            }
+            KeyCode::Enter => {
+                let pattern = std::mem::take(&mut self.input_buffer);
+                self.input_mode = InputMode::Normal;
+                self.apply_log_grep(&pattern).await?;
+            }
+            KeyCode::Char(c) => {
+                self.input_buffer.push(c);
+            }
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    async fn handle_metadata_edit_mode(&mut self, event: InputEvent) -> Result<bool> {
+        match event.key_code() {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
+                self.metadata_target = None;
+            }
+            KeyCode::Enter => {
+                let input = std::mem::take(&mut self.input_buffer);
+                self.input_mode = InputMode::Normal;
+                self.apply_metadata_edit(&input).await?;
+            }
+            KeyCode::Char(c) => {
+                self.input_buffer.push(c);
+            }
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    /// Apply `key=value` (set) or `key-` (remove) as a label on the resource picked
+    /// with `=`, or as an annotation when prefixed with `@`
+    async fn apply_metadata_edit(&mut self, input: &str) -> Result<()> {
+        self.clear_error();
+        let Some((kind, name)) = self.metadata_target.take() else {
+            return Ok(());
+        };
+        let (annotation, input) = match input.trim().strip_prefix('@') {
+            Some(rest) => (true, rest),
+            None => (false, input.trim()),
+        };
+        let (key, value) = match input.split_once('=') {
+            Some((key, value)) => (key.trim(), Some(value.trim().to_string())),
+            None => match input.strip_suffix('-') {
+                Some(key) => (key.trim(), None),
+                None => {
+                    self.error_message = Some("Expected key=value, or key- to remove".to_string());
+                    return Ok(());
+                }
+            },
+        };
+        if key.is_empty() {
+            self.error_message = Some("The key can't be empty".to_string());
+            return Ok(());
+        }
+
+        let change = match &value {
+            Some(value) => format!("{}={}", key, value),
+            None => format!("{}-", key),
+        };
+        let entries = BTreeMap::from([(key.to_string(), value)]);
+        let namespace = self.current_namespace.clone();
+        let result = if annotation {
+            self.client
+                .patch_annotations(kind, &namespace, &name, &entries)
+                .await
+        } else {
+            self.client
+                .patch_labels(kind, &namespace, &name, &entries)
+                .await
+        };
+        let what = if annotation { "Annotated" } else { "Labeled" };
+        match result {
+            Ok(()) => {
+                self.status_message = format!("{} {}/{} {}", what, kind.as_str(), name, change);
+                self.record_activity(format!("{} {} {} {}", what, kind.as_str(), name, change));
+            }
+            Err(e) => {
+                self.report_error(&format!("Failed to update {}/{}", kind.as_str(), name), &e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply `[container:]NAME=value` to the deployment in the detail view. The
+    /// container may be omitted when exactly one container sets NAME to a plain value.
+    async fn apply_env_edit(&mut self, input: &str) -> Result<()> {
+        self.clear_error();
+        let Some(Detail::Deployment(detail)) = &self.detail else {
+            return Ok(());
+        };
+        let Some((target, value)) = input.split_once('=') else {
+            self.error_message = Some("Expected NAME=value".to_string());
+            return Ok(());
+        };
+
+        let (container, variable) = match target.split_once(':') {
+            Some((container, variable)) => (container.trim().to_string(), variable.trim()),
+            None => {
+                let variable = target.trim();
+                let candidates: Vec<&str> = detail
+                    .containers
+                    .iter()
+                    .filter(|c| {
+                        c.env
+                            .iter()
+                            .any(|e| e.name == variable && matches!(e.value, EnvValue::Plain(_)))
+                    })
+                    .map(|c| c.name.as_str())
+                    .collect();
+                match candidates.as_slice() {
+                    [container] => (container.to_string(), variable),
+                    [] => {
+                        self.error_message = Some(format!(
+                            "No container sets {} to a plain value; only those can be edited",
+                            variable
+                        ));
+                        return Ok(());
+                    }
+                    _ => {
+                        self.error_message = Some(format!(
+                            "{} is set in several containers; use container:{}=value",
+                            variable, variable
+                        ));
+                        return Ok(());
+                    }
+                }
+            }
+        };
+
+        let (namespace, name) = (detail.namespace.clone(), detail.name.clone());
+        match self
+            .client
+            .set_deployment_env(&namespace, &name, &container, variable, value)
+            .await
+        {
+            Ok(()) => {
+                self.status_message = format!("Set {} in {}/{}", variable, name, container);
+                self.record_activity(format!(
+                    "Set env {} on deployment {} (container {})",
+                    variable, name, container
+                ));
+                // Reload in place so the new value shows without losing the scroll position
+                if let Ok(detail) = self.client.describe_deployment(&namespace, &name).await {
+                    self.detail = Some(Detail::Deployment(detail));
+                }
+            }
+            Err(e) => {
+                self.report_error(&format!("Failed to set {}", variable), &e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn describe_selected_service(&mut self) -> Result<()> {
+        if let Some(service) = self.selected_service() {
+            match self
+                .client
+                .describe_service(&self.current_namespace, &service.name)
+                .await
+            {
+                Ok(detail) => self.open_detail(Detail::Service(detail)),
+                Err(e) => {
+                    self.report_error("Failed to describe service", &e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn describe_selected_configmap(&mut self) -> Result<()> {
+        if let Some(configmap) = self.configmaps.get(self.configmap_index) {
+            match self
+                .client
+                .describe_configmap(&self.current_namespace, &configmap.name)
+                .await
+            {
+                Ok(detail) => self.open_detail(Detail::ConfigMap(detail)),
+                Err(e) => {
+                    self.report_error("Failed to describe configmap", &e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn describe_selected_secret(&mut self) -> Result<()> {
+        if let Some(secret) = self.secrets.get(self.secret_index) {
+            match self
+                .client
+                .describe_secret(&self.current_namespace, &secret.name)
+                .await
+            {
+                Ok(detail) => self.open_detail(Detail::Secret(detail)),
+                Err(e) => {
+                    self.report_error("Failed to describe secret", &e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Show how the selected context connects, read from the kubeconfig only so it
+    /// works while the cluster is unreachable
+    fn describe_selected_context(&mut self) {
+        if let Some(context) = self.contexts.get(self.context_index) {
+            match KubeClient::describe_context(&context.name) {
+                Ok(mut detail) => {
+                    // The QUI config's proxy wins over the cluster's `proxy-url`
+                    if let Some(proxy_url) = &self.config.proxy_url {
+                        detail.proxy_url = Some(match &detail.proxy_url {
+                            Some(own) => {
+                                format!("{} (QUI config, overrides kubeconfig {})", proxy_url, own)
+                            }
+                            None => format!("{} (QUI config)", proxy_url),
+                        });
+                    }
+                    self.open_detail(Detail::Connection(detail));
+                }
+                Err(e) => {
+                    self.report_error("Failed to read context", &e);
+                }
+            }
+        }
+    }
+
+    /// What `Q` leaves on stdout: the context and namespace being viewed, and a
+    /// kubectl prefix that targets them without touching the kubeconfig
+    pub fn exit_location(&self) -> Option<String> {
+        if !self.print_location_on_exit {
+            return None;
+        }
+        Some(format!(
+            "context:   {ctx}\nnamespace: {ns}\n\nkubectl --context {ctx} -n {ns}\nkubectl config set-context --current --namespace {ns}",
+            ctx = self.current_context,
+            ns = self.current_namespace,
+        ))
+    }
+
+    /// The pod under the cursor in the list the Pods view is showing. Every pod
+    /// action goes through this so they agree on what `pod_index` points at.
+    /// A pod the name filter hides isn't selected.
+    pub fn selected_pod(&self) -> Option<&PodInfo> {
+        self.pods
+            .get(self.pod_index)
+            .filter(|p| self.shown_in_list(View::Pods, &p.name))
+    }
+
+    /// The deployment under the cursor, unless the name filter hides it
+    pub fn selected_deployment(&self) -> Option<&DeploymentInfo> {
+        self.deployments
+            .get(self.deployment_index)
+            .filter(|d| self.shown_in_list(View::Deployments, &d.name))
+    }
+
+    /// The service under the cursor, unless the name filter hides it
+    pub fn selected_service(&self) -> Option<&ServiceInfo> {
+        self.services
+            .get(self.service_index)
+            .filter(|s| self.shown_in_list(View::Services, &s.name))
+    }
+
+    /// The name filter of a list view: what's being typed while the filter bar
+    /// is open on it, otherwise the one last applied
+    pub fn list_filter(&self, view: View) -> Option<&str> {
+        if self.input_mode == InputMode::Filter && self.current_view == view {
+            return Some(self.input_buffer.as_str()).filter(|q| !q.is_empty());
+        }
+        self.list_filters.get(&view).map(String::as_str)
+    }
+
+    /// Whether the list view's name filter lets `name` through (case-insensitive
+    /// substring)
+    pub fn shown_in_list(&self, view: View, name: &str) -> bool {
+        self.list_filter(view)
+            .is_none_or(|q| name.to_lowercase().contains(&q.to_lowercase()))
+    }
+
+    /// Indices of the rows a filterable list view shows, in list order
+    fn shown_indices(&self, view: View) -> Vec<usize> {
+        let names: Vec<&str> = match view {
+            View::Pods => self.pods.iter().map(|p| p.name.as_str()).collect(),
+            View::Deployments => self.deployments.iter().map(|d| d.name.as_str()).collect(),
+            View::Services => self.services.iter().map(|s| s.name.as_str()).collect(),
+            _ => return Vec::new(),
+        };
+        names
+            .into_iter()
+            .enumerate()
+            .filter(|(_, name)| self.shown_in_list(view, name))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// The shown row after (or before) `from` in a filterable list view
+    fn next_shown(&self, view: View, from: usize, down: bool) -> Option<usize> {
+        let shown = self.shown_indices(view);
+        if down {
+            shown.into_iter().find(|&i| i > from)
+        } else {
+            shown.into_iter().rev().find(|&i| i < from)
+        }
+    }
+
+    /// Move a list view's cursor onto its first shown row if the filter hides
+    /// the row it's on
+    fn keep_selection_shown(&mut self, view: View) {
+        let index = match view {
+            View::Pods => self.pod_index,
+            View::Deployments => self.deployment_index,
+            View::Services => self.service_index,
+            _ => return,
+        };
+        let shown = self.shown_indices(view);
+        if shown.contains(&index) {
+            return;
+        }
+        let Some(&first) = shown.first() else {
+            return;
+        };
+        match view {
+            View::Pods => self.pod_index = first,
+            View::Deployments => self.deployment_index = first,
+            _ => self.service_index = first,
+        }
+    }
+
+    /// Pull the current view's selection back onto the list if it shrank underneath
+    /// it. True if the selection had pointed past the end.
+    fn clamp_selection(&mut self) -> bool {
+        let (index, len) = match self.current_view {
+            View::Pods => (&mut self.pod_index, self.pods.len()),
+            View::Deployments => (&mut self.deployment_index, self.deployments.len()),
+            View::Services => (&mut self.service_index, self.services.len()),
+            View::Events => (&mut self.event_index, self.events.len()),
+            View::ConfigMaps => (&mut self.configmap_index, self.configmaps.len()),
+            View::Secrets => (&mut self.secret_index, self.secrets.len()),
+            View::Clusters => (&mut self.context_index, self.contexts.len()),
+            View::Namespaces => (&mut self.namespace_index, self.namespaces.len()),
+            View::Activity => (&mut self.activity_index, self.activity.len()),
+            View::PortForwards => (&mut self.port_forward_index, self.port_forwards.len()),
+            _ => return false,
+        };
+        // An empty list has nothing to select; that isn't out of range
+        if len == 0 || *index < len {
+            return false;
+        }
+        *index = len - 1;
+        true
+    }
+
+    /// Name and kind of the selected row in the current resource list
+    fn selected_resource(&self) -> Option<(ResourceKind, String)> {
+        match self.current_view {
+            View::Pods => self
+                .selected_pod()
+                .map(|p| (ResourceKind::Pod, p.name.clone())),
+            View::Deployments => self
+                .selected_deployment()
+                .map(|d| (ResourceKind::Deployment, d.name.clone())),
+            View::Services => self
+                .selected_service()
+                .map(|s| (ResourceKind::Service, s.name.clone())),
+            _ => None,
+        }
+    }
+
+    /// Stop showing the current error, keeping it as the last one
+    fn clear_error(&mut self) {
+        if let Some(error) = self.error_message.take() {
+            self.last_error = Some(error);
+        }
+    }
+
+    /// Copy the full text of the error on screen, or the last one shown, e.g.
+    /// for a bug report. The error is dismissed so the confirmation shows.
+    fn copy_last_error(&mut self) {
+        let Some(error) = self
+            .error_message
+            .clone()
+            .or_else(|| self.last_error.clone())
+        else {
+            self.status_message = "No error to copy".to_string();
+            return;
+        };
+        match clipboard::copy(&error) {
+            Ok(_) => {
+                self.clear_error();
+                self.status_message = "Error copied".to_string();
+            }
+            Err(e) => {
+                self.clear_error();
+                self.error_message = Some(format!("Failed to copy: {}", e));
+            }
+        }
+    }
+
+    /// Copy a namespace-qualified reference to the selected resource
+    fn copy_selected_reference(&mut self) {
+        if let Some((kind, name)) = self.selected_resource() {
+            let reference =
+                self.config
+                    .format_reference(&self.current_namespace, kind.as_str(), &name);
+            match clipboard::copy(&reference) {
+                Ok(_) => {
+                    self.status_message = format!("Copied: {}", reference);
+                }
+                Err(e) => {
+                    self.error_message = Some(format!("Failed to copy: {}", e));
+                }
+            }
+        }
+    }
+
+    async fn view_selected_yaml(&mut self) -> Result<()> {
+        if let Some((kind, name)) = self.selected_resource() {
+            match self
+                .client
+                .get_resource_json(kind, &self.current_namespace, &name)
+                .await
+            {
+                Ok(object) => self.open_detail(Detail::Yaml(YamlDocument {
+                    kind,
+                    name,
+                    object,
+                    show_managed_fields: false,
+                    show_status: true,
+                })),
+                Err(e) => {
+                    self.report_error(&format!("Failed to get {}", kind.as_str()), &e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// What `K` describes: the selected row, or the resource shown in the detail
+    fn describe_target(&self) -> Option<(&'static str, String)> {
+        match (self.current_view, &self.detail) {
+            (View::Detail, Some(Detail::Pod(detail))) => Some(("pod", detail.name.clone())),
+            (View::Detail, Some(Detail::Deployment(detail))) => {
+                Some(("deployment", detail.name.clone()))
+            }
+            (View::Detail, Some(Detail::Service(detail))) => Some(("service", detail.name.clone())),
+            (View::Detail, Some(Detail::Node(detail))) => Some(("node", detail.name.clone())),
+            (View::Detail, _) => None,
+            _ => self
+                .selected_resource()
+                .map(|(kind, name)| (kind.as_str(), name)),
+        }
+    }
+
+    /// Show `kubectl describe` for whatever the native detail views leave out
+    async fn kubectl_describe_selected(&mut self) {
+        let Some((kind, name)) = self.describe_target() else {
+            return;
+        };
+        if self.refuse_kubectl("kubectl describe") {
+            return;
+        }
+        match self
+            .client
+            .kubectl_describe(kind, &self.current_namespace, &name)
+            .await
+        {
+            Ok(text) => {
+                let output = Detail::Describe(DescribeOutput {
+                    kind: kind.to_string(),
+                    name,
+                    text,
+                });
+                // From a native detail, Esc goes back to the list rather than to it
+                if self.current_view == View::Detail {
+                    self.detail = Some(output);
+                    self.detail_scroll = 0;
+                } else {
+                    self.open_detail(output);
+                }
+            }
+            Err(e) => self.report_error("Failed to run kubectl describe", &e),
+        }
+    }
+
+    fn open_detail(&mut self, detail: Detail) {
+        self.detail = Some(detail);
+        self.detail_scroll = 0;
+        self.secret_revealed = false;
+        self.detail_return_view = self.current_view;
+        self.current_view = View::Detail;
+    }
+
+    fn close_detail(&mut self) {
+        self.detail = None;
+        self.detail_scroll = 0;
+        self.secret_revealed = false;
+        self.current_view = self.detail_return_view;
+    }
+
+    /// Open the selected pod's logs, picking the container first if it has several
+    async fn view_pod_logs(&mut self, follow: bool) -> Result<()> {
+        self.pick_container_for(ContainerAction::Logs { follow })
+            .await
+    }
+
+    /// Open the selected pod's logs of `container`; with `follow`, already
+    /// following at the bottom
+    async fn open_pod_logs(&mut self, follow: bool, container: Option<String>) -> Result<()> {
+        if let Some(pod) = self.selected_pod().cloned() {
+            // The API wants a container once there are several
+            self.logs_container = container;
+            self.log_watcher = None;
+            // A grep and the filters belong to the pod they were typed for
+            self.logs_grep = None;
+            self.logs_include = None;
+            self.logs_exclude = None;
+            match self.fetch_logs(&pod.name, false).await {
+                Ok(logs) => {
+                    self.logs = logs;
+                    self.logs_new_from = None;
+                    self.logs_previous = false;
+                    self.logs_scroll = 0; // Reset scroll position
+                    self.logs_pod_name = Some(pod.name.clone()); // Store pod name for follow mode
+                    self.logs_fetched_at = Some(Instant::now());
+                    self.logs_follow = follow;
+                    self.logs_paused = false;
+                    if follow {
+                        self.logs_scroll = self.shown_log_line_count().saturating_sub(1);
+                        self.status_message =
+                            "Log follow mode enabled (press 'f' to disable)".to_string();
+                    }
+                    self.current_view = View::Logs;
+                }
+                Err(e) => {
+                    self.report_error("Failed to get logs", &e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Follow every container of the selected pod at once, each line prefixed
+    /// with its container, until the Logs view is left
+    async fn follow_all_container_logs(&mut self) {
+        let Some(pod) = self.selected_pod().cloned() else {
+            return;
+        };
+        let containers: Vec<String> = pod.containers.iter().map(|c| c.name.clone()).collect();
+        match self
+            .client
+            .follow_pod_logs(
+                &self.current_namespace,
+                &pod.name,
+                &containers,
+                self.config.logs.tail_lines,
+                self.config.logs.timestamps,
+            )
+            .await
+        {
+            Ok(watcher) => {
+                self.log_watcher = Some(watcher);
+                self.logs_container = None;
+                self.logs_grep = None;
+                self.logs_include = None;
+                self.logs_exclude = None;
+                self.logs.clear();
+                self.logs_new_from = None;
+                self.logs_previous = false;
+                self.logs_scroll = 0;
+                self.logs_pod_name = Some(pod.name.clone());
+                self.logs_fetched_at = Some(Instant::now());
+                self.logs_follow = true;
+                self.logs_paused = false;
+                self.status_message = format!(
+                    "Following all {} containers of {} (Esc to stop)",
+                    containers.len(),
+                    pod.name
+                );
+                self.current_view = View::Logs;
+            }
+            Err(e) => {
+                self.report_error("Failed to follow logs", &e);
+            }
+        }
+    }
+
+    /// Append the lines streamed in since the last call (non-blocking), keeping
+    /// `logs.max_lines` of them. The stream stops once the Logs view is left.
+    pub fn try_update_log_stream(&mut self) {
+        if self.log_watcher.is_none() {
+            return;
+        }
+        if self.current_view != View::Logs {
+            self.log_watcher = None;
+            return;
+        }
+        let Some(watcher) = &mut self.log_watcher else {
+            return;
+        };
+        let mut received = Vec::new();
+        while let Ok(line) = watcher.rx.try_recv() {
+            received.push(line);
+        }
+        if received.is_empty() {
+            return;
+        }
+
+        let before = self.logs.lines().count();
+        for line in &received {
+            if !self.logs.is_empty() {
+                self.logs.push('\n');
+            }
+            let text = self.local_log_line(&line.line);
+            self.logs
+                .push_str(&format!("[{}] {}", line.container, text));
+        }
+        let max_lines = self.config.logs.max_lines;
+        let dropped = (before + received.len()).saturating_sub(max_lines);
+        if dropped > 0 {
+            self.logs = cap_lines(std::mem::take(&mut self.logs), max_lines);
+        }
+        self.logs_new_from = self
+            .config
+            .logs
+            .highlight_new_lines
+            .then_some(before.saturating_sub(dropped));
+        self.logs_fetched_at = Some(Instant::now());
+        if self.logs_follow && !self.logs_paused {
+            self.logs_scroll = self.shown_log_line_count().saturating_sub(1);
+        } else {
+            // Keep the lines being read in place as the oldest ones go
+            self.logs_scroll = self.logs_scroll.saturating_sub(dropped);
+        }
+    }
+
+    fn log_query(&self, previous: bool) -> LogQuery {
+        let tail_lines = if self.logs_grep.is_some() {
+            self.config.logs.tail_lines.max(LOG_GREP_TAIL_LINES)
+        } else {
+            self.config.logs.tail_lines
+        };
+        LogQuery {
+            container: self.logs_container.clone(),
+            previous,
+            tail_lines,
+            timestamps: self.config.logs.timestamps,
+        }
+    }
+
+    /// Fetch the shown pod's log, keeping only the lines matching `logs_grep` if set
+    async fn fetch_logs(&self, pod_name: &str, previous: bool) -> Result<String> {
+        let logs = self
+            .client
+            .get_pod_logs(&self.current_namespace, pod_name, &self.log_query(previous))
+            .await?;
+        let logs = match &self.logs_grep {
+            Some(pattern) => logs
+                .lines()
+                .filter(|line| line.contains(pattern.as_str()))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            None => logs,
+        };
+        Ok(self.prepare_logs(logs))
+    }
+
+    /// Refetch the log filtered by `pattern` (or unfiltered for an empty one)
+    async fn apply_log_grep(&mut self, pattern: &str) -> Result<()> {
+        let Some(pod_name) = self.logs_pod_name.clone() else {
+            return Ok(());
+        };
+        let previous_grep = std::mem::replace(
+            &mut self.logs_grep,
+            Some(pattern.to_string()).filter(|p| !p.is_empty()),
+        );
+        match self.fetch_logs(&pod_name, self.logs_previous).await {
+            Ok(logs) => {
+                self.logs = logs;
+                self.logs_new_from = None;
+                self.logs_fetched_at = Some(Instant::now());
+                self.logs_scroll = 0;
+                self.status_message = match &self.logs_grep {
+                    Some(pattern) => format!(
+                        "{} lines matching \"{}\" (g to change, empty to clear)",
+                        self.logs.lines().count(),
+                        pattern
+                    ),
+                    None => "Showing the full log".to_string(),
+                };
+            }
+            Err(e) => {
+                self.logs_grep = previous_grep;
+                self.report_error("Failed to get logs", &e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Cap a freshly fetched log to the buffer limit and show its timestamps
+    /// in the configured time zone
+    fn prepare_logs(&self, logs: String) -> String {
+        let logs = cap_lines(logs, self.config.logs.max_lines);
+        if !self.config.logs.timestamps {
+            return logs;
+        }
+        logs.lines()
+            .map(|line| self.local_log_line(line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Show a line's leading RFC 3339 timestamp in the configured timezone
+    fn local_log_line(&self, line: &str) -> String {
+        if !self.config.logs.timestamps {
+            return line.to_string();
+        }
+        match line.split_once(' ') {
+            Some((stamp, rest)) => match DateTime::parse_from_rfc3339(stamp) {
+                Ok(time) => format!(
+                    "{} {}",
+                    self.config
+                        .timezone
+                        .format(&time.with_timezone(&Utc), "%Y-%m-%d %H:%M:%S%.3f"),
+                    rest
+                ),
+                Err(_) => line.to_string(),
+            },
+            None => line.to_string(),
+        }
+    }
+
+    async fn toggle_events_follow(&mut self) {
+        if self.events_follow {
+            self.stop_events_follow();
+            self.status_message = "Event follow mode disabled".to_string();
+            return;
+        }
+        match self.client.watch_events(&self.current_namespace).await {
+            Ok(watcher) => {
+                self.event_watcher = Some(watcher);
+                self.events_follow = true;
+                self.event_index = 0;
+                self.status_message = "Following events live (press 'f' to stop)".to_string();
+            }
+            Err(e) => {
+                self.report_error("Failed to watch events", &e);
+            }
+        }
+    }
+
+    fn stop_events_follow(&mut self) {
+        self.events_follow = false;
+        self.event_watcher = None;
+    }
+
+    async fn toggle_log_preview(&mut self) {
+        self.show_log_preview = !self.show_log_preview;
+        self.log_preview = None;
+        if self.show_log_preview {
+            self.sync_log_preview().await;
+            self.status_message = "Previewing logs of the selected pod (p to close)".to_string();
+        } else {
+            self.status_message = "Log preview closed".to_string();
+        }
+    }
+
+    /// Load the preview for the selected pod if it isn't the one shown. Called
+    /// every frame, so moving the selection swaps the logs without a keypress.
+    pub async fn sync_log_preview(&mut self) {
+        if !self.show_log_preview || self.current_view != View::Pods {
+            return;
+        }
+        let selected = self.selected_pod().map(|p| p.name.clone());
+        if selected.as_deref() == self.log_preview.as_ref().map(|p| p.pod.as_str()) {
+            return;
+        }
+        match selected {
+            Some(pod) => self.fetch_log_preview(pod).await,
+            None => self.log_preview = None,
+        }
+    }
+
+    /// Fetch the preview's tail. Errors go into the pane instead of a popup, since
+    /// they'd otherwise interrupt scrolling past a pod that hasn't started yet.
+    async fn fetch_log_preview(&mut self, pod_name: String) {
+        let container = self
+            .pods
+            .iter()
+            .find(|p| p.name == pod_name)
+            .and_then(PodInfo::log_container);
+        let query = LogQuery {
+            container,
+            previous: false,
+            tail_lines: self.config.logs.tail_lines,
+            timestamps: self.config.logs.timestamps,
+        };
+        let logs = match self
+            .client
+            .get_pod_logs(&self.current_namespace, &pod_name, &query)
+            .await
+        {
+            Ok(logs) => self.prepare_logs(logs),
+            Err(e) => format!("Failed to get logs: {}", e),
+        };
+        self.log_preview = Some(LogPreview {
+            pod: pod_name,
+            logs,
+        });
+    }
+
+    /// Hand the shown log to the configured pager, `$PAGER` or `less`
+    fn open_logs_in_pager(&mut self) {
+        let pager = self
+            .config
+            .logs
+            .pager
+            .clone()
+            .or_else(|| std::env::var("PAGER").ok())
+            .filter(|p| !p.trim().is_empty())
+            .unwrap_or_else(|| "less".to_string());
+        self.status_message = format!("Opened the log in {}", pager);
+        self.pending_command = Some(ExternalCommand::Page {
+            pager,
+            text: self.logs.clone(),
+        });
+    }
+
+    fn toggle_log_follow(&mut self) {
+        self.logs_follow = !self.logs_follow;
+        self.logs_paused = false;
+        if self.logs_follow {
+            // Staleness is measured from here, not from however long the view sat unfollowed
+            self.logs_fetched_at = Some(Instant::now());
+            // Scroll to bottom when enabling follow mode
+            let log_lines = self.shown_log_line_count();
+            self.logs_scroll = log_lines.saturating_sub(1);
+            self.status_message = "Log follow mode enabled (press 'f' to disable)".to_string();
+        } else {
+            self.status_message = "Log follow mode disabled".to_string();
+        }
+    }
+
+    /// Jump to the end of the logs and follow from there (`G`/`End`)
+    fn resume_log_follow(&mut self) {
+        if !self.logs_follow {
+            self.logs_fetched_at = Some(Instant::now());
+        }
+        self.logs_follow = true;
+        self.logs_paused = false;
+        self.logs_scroll = self.shown_log_line_count().saturating_sub(1);
+        self.status_message = "Following the end of the log".to_string();
+    }
+
+    /// How long follow mode has gone without a successful refresh, once that's
+    /// longer than `LOG_STALL_AFTER`
+    pub fn logs_stalled_for(&self) -> Option<Duration> {
+        // A stream has no refreshes to miss; a quiet container is just quiet
+        if !self.logs_follow || self.log_watcher.is_some() {
+            return None;
+        }
+        let age = self.logs_fetched_at?.elapsed();
+        (age > LOG_STALL_AFTER).then_some(age)
+    }
+
+    /// Containers of the pod whose logs are shown, with their current state
+    pub fn logs_pod_containers(&self) -> &[ContainerSummary] {
+        self.logs_pod_name
+            .as_ref()
+            .and_then(|name| self.pods.iter().find(|p| &p.name == name))
+            .map(|p| p.containers.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// How the shown container's previous instance ended, if it has restarted.
+    /// Without a container picked, that's the pod's only (or first restarted) one.
+    pub fn logs_last_termination(&self) -> Option<&TerminationInfo> {
+        let pod = self
+            .logs_pod_name
+            .as_ref()
+            .and_then(|name| self.pods.iter().find(|p| &p.name == name))?;
+        match &self.logs_container {
+            Some(container) => pod
+                .containers
+                .iter()
+                .find(|c| &c.name == container)?
+                .last_termination
+                .as_ref(),
+            None => pod.last_termination.as_ref(),
+        }
+    }
+
+    /// Show the next (or previous) container's logs in the same pod
+    async fn cycle_log_container(&mut self, forward: bool) -> Result<()> {
+        let Some(pod_name) = self.logs_pod_name.clone() else {
+            return Ok(());
+        };
+        if self.log_watcher.is_some() {
+            self.status_message = MERGED_LOGS_UNSUPPORTED.to_string();
+            return Ok(());
+        }
+        let names: Vec<String> = self
+            .logs_pod_containers()
+            .iter()
+            .map(|c| c.name.clone())
+            .collect();
+        if names.len() < 2 {
+            return Ok(());
+        }
+        let current = self
+            .logs_container
+            .as_ref()
+            .and_then(|c| names.iter().position(|n| n == c))
+            .unwrap_or(0);
+        let next = if forward {
+            (current + 1) % names.len()
+        } else {
+            (current + names.len() - 1) % names.len()
+        };
+
+        let previous_container = self.logs_container.replace(names[next].clone());
+        match self.fetch_logs(&pod_name, self.logs_previous).await {
+            Ok(logs) => {
+                self.logs = logs;
+                self.logs_new_from = None;
+                self.logs_fetched_at = Some(Instant::now());
+                self.logs_paused = false;
+                self.logs_scroll = if self.logs_follow {
+                    self.shown_log_line_count().saturating_sub(1)
+                } else {
+                    0
+                };
+                self.status_message = format!("Showing logs of container {}", names[next]);
+            }
+            Err(e) => {
+                self.logs_container = previous_container;
+                self.report_error(
+                    &format!("Failed to get logs of container {}", names[next]),
+                    &e,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    async fn toggle_previous_logs(&mut self) -> Result<()> {
+        if self.log_watcher.is_some() {
+            self.status_message = MERGED_LOGS_UNSUPPORTED.to_string();
+            return Ok(());
+        }
+        if let Some(pod_name) = self.logs_pod_name.clone() {
+            let previous = !self.logs_previous;
+            match self.fetch_logs(&pod_name, previous).await {
+                Ok(logs) => {
+                    self.logs = logs;
+                    self.logs_new_from = None;
+                    self.logs_previous = previous;
+                    self.logs_scroll = 0;
+                    self.status_message = if previous {
+                        "Showing logs from the instance before the last restart".to_string()
+                    } else {
+                        "Showing logs from the current instance".to_string()
+                    };
+                }
+                Err(e) => {
+                    let action = if previous {
+                        "No previous instance logs available"
+                    } else {
+                        "Failed to get logs"
+                    };
+                    self.report_error(action, &e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn refresh_logs(&mut self) -> Result<()> {
+        // The preview always follows
+        if self.show_log_preview && self.current_view == View::Pods {
+            if let Some(preview) = &self.log_preview {
+                self.fetch_log_preview(preview.pod.clone()).await;
+            }
+        }
+        // A merged stream needs no refetching; `try_update_log_stream` appends it
+        if self.logs_follow && self.current_view == View::Logs && self.log_watcher.is_none() {
+            if let Some(pod_name) = &self.logs_pod_name.clone() {
+                match self.fetch_logs(pod_name, self.logs_previous).await {
+                    Ok(logs) => {
+                        self.logs_new_from = if self.config.logs.highlight_new_lines {
+                            first_new_line(&self.logs, &logs)
+                        } else {
+                            None
+                        };
+                        self.logs = logs;
+                        self.logs_fetched_at = Some(Instant::now());
+                        // Auto-scroll to bottom in follow mode, unless scrolled up to read
+                        if !self.logs_paused {
+                            let log_lines = self.shown_log_line_count();
+                            self.logs_scroll = log_lines.saturating_sub(1);
+                        }
+                    }
+                    Err(_) => {
+                        // No popup for a background refresh; the title shows the
+                        // follow as stalled once `logs_fetched_at` gets old
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Called by the main loop once an external program has exited and the TUI is back
+    pub async fn finish_external_command(&mut self, command: ExternalCommand, result: Result<()>) {
+        if let Err(e) = result {
+            self.error_message = Some(format!("External command failed: {}", e));
+        }
+
+        match command {
+            ExternalCommand::EditFile(path) => {
+                if path == KubeClient::get_kubeconfig_path() {
+                    // Pick up contexts added, renamed or removed in the editor
+                    match KubeClient::list_contexts() {
+                        Ok(contexts) => {
+                            self.set_contexts(contexts);
+                            self.status_message = format!("Reloaded {}", path.display());
+                        }
+                        Err(e) => {
+                            self.error_message =
+                                Some(format!("Failed to reload {}: {}", path.display(), e));
+                        }
+                    }
+                }
+            }
+            ExternalCommand::Page { .. } => {}
+        }
+    }
+
+    /// Replace the namespace list and make `current` the active namespace. The
+    /// header, the `▶` marker and the Namespaces selection all read from here, so
+    /// `current` is added to the list if the cluster didn't return it (e.g. a
+    /// context default namespace the user can't list).
+    fn set_namespaces(&mut self, mut namespaces: Vec<String>, current: String) {
+        if !namespaces.contains(&current) {
+            let position = namespaces.partition_point(|ns| *ns < current);
+            namespaces.insert(position, current.clone());
+        }
+        self.all_namespaces = namespaces.clone();
+        // The namespace being viewed stays listed even when it's a system one
+        let filter = &self.config.namespaces;
+        namespaces.retain(|ns| *ns == current || !filter.hides(ns));
+        favorites_first(&mut namespaces, &self.config.favorites.namespaces, |ns| ns);
+        self.namespace_index = namespaces.iter().position(|ns| *ns == current).unwrap_or(0);
+        self.namespaces = namespaces;
+        self.current_namespace = current;
+    }
+
+    /// Show or hide system namespaces and save the choice to the config file
+    fn toggle_system_namespaces(&mut self) {
+        self.config.namespaces.hide_system = !self.config.namespaces.hide_system;
+        let selected = self.namespaces.get(self.namespace_index).cloned();
+        self.set_namespaces(self.all_namespaces.clone(), self.current_namespace.clone());
+        if let Some(index) =
+            selected.and_then(|name| self.namespaces.iter().position(|ns| *ns == name))
+        {
+            self.namespace_index = index;
+        }
+
+        let hidden = self.all_namespaces.len() - self.namespaces.len();
+        self.status_message = if self.config.namespaces.hide_system {
+            format!("Hiding {} system namespace(s)", hidden)
+        } else {
+            "Showing system namespaces".to_string()
+        };
+        if let Err(e) = self.config.save_namespace_filter() {
+            self.error_message = Some(format!("Failed to save namespace filter: {}", e));
+        }
+    }
+
+    /// Replace the context list, favorites first, keeping the selection in range
+    fn set_contexts(&mut self, mut contexts: Vec<ContextInfo>) {
+        order_contexts(&mut contexts, &self.config.favorites.contexts);
+        self.contexts = contexts;
+        if self.context_index >= self.contexts.len() {
+            self.context_index = self.contexts.len().saturating_sub(1);
+        }
+    }
+
+    /// Pin or unpin the selected namespace or context and save the choice to the config file
+    fn toggle_favorite(&mut self) {
+        let (name, favorites) = match self.current_view {
+            View::Namespaces => match self.namespaces.get(self.namespace_index) {
+                Some(ns) => (ns.clone(), &mut self.config.favorites.namespaces),
+                None => return,
+            },
+            View::Clusters => match self.contexts.get(self.context_index) {
+                Some(ctx) => (ctx.name.clone(), &mut self.config.favorites.contexts),
+                None => return,
+            },
+            _ => return,
+        };
+
+        let added = if let Some(position) = favorites.iter().position(|f| *f == name) {
+            favorites.remove(position);
+            false
+        } else {
+            favorites.push(name.clone());
+            true
+        };
+
+        // Re-sort and keep the cursor on the item that was toggled
+        match self.current_view {
+            View::Namespaces => {
+                favorites_first(
+                    &mut self.namespaces,
+                    &self.config.favorites.namespaces,
+                    |ns| ns,
+                );
+                self.namespace_index = self
+                    .namespaces
+                    .iter()
+                    .position(|ns| *ns == name)
+                    .unwrap_or(0);
+            }
+            _ => {
+                order_contexts(&mut self.contexts, &self.config.favorites.contexts);
+                self.context_index = self
+                    .contexts
+                    .iter()
+                    .position(|ctx| ctx.name == name)
+                    .unwrap_or(0);
+            }
+        }
+
+        match self.config.save_favorites() {
+            Ok(()) if added => self.status_message = format!("Added {} to favorites", name),
+            Ok(()) => self.status_message = format!("Removed {} from favorites", name),
+            Err(e) => self.error_message = Some(format!("Failed to save favorites: {}", e)),
+        }
+    }
+
+    /// Jump to the nth favorite (1-based): a context in the Clusters view, a namespace elsewhere
+    async fn jump_to_favorite(&mut self, n: usize) -> Result<()> {
+        if self.current_view == View::Clusters {
+            let favorites = &self.config.favorites.contexts;
+            let index = self
+                .contexts
+                .iter()
+                .enumerate()
+                .filter(|(_, ctx)| favorites.contains(&ctx.name))
+                .nth(n - 1)
+                .map(|(i, _)| i);
+            if let Some(index) = index {
+                self.context_index = index;
+                self.request_context_switch().await?;
+            }
+        } else if self.connected {
+            let favorites = &self.config.favorites.namespaces;
+            let namespace = self
+                .namespaces
+                .iter()
+                .filter(|ns| favorites.contains(ns))
+                .nth(n - 1)
+                .cloned();
+            if let Some(namespace) = namespace {
+                if self.current_view == View::Namespaces {
+                    self.current_view = View::Pods;
+                }
+                self.switch_namespace(namespace).await?;
+            }
         }
         Ok(())
     }
 
-    async fn view_pod_logs(&mut self) -> Result<()> {
-        if let Some(pod) = self.pods.get(self.pod_index) {
-            match self
-                .client
-                .get_pod_logs(&self.current_namespace, &pod.name)
-                .await
-            {
-                Ok(logs) => {
-                    self.logs = logs;
-                    self.logs_scroll = 0; // Reset scroll position
-                    self.logs_pod_name = Some(pod.name.clone()); // Store pod name for follow mode
-                    self.current_view = View::Logs;
-                }
-                Err(e) => {
-                    self.error_message = Some(format!("Failed to get logs: {}", e));
-                }
+    /// Ask whether to cordon the node the selected pod runs on
+    fn request_cordon_of_pod_node(&mut self) {
+        let Some(pod) = self.selected_pod() else {
+            return;
+        };
+        if pod.node.is_empty() || pod.node == "<none>" {
+            self.error_message = Some(format!("Pod {} isn't scheduled on a node", pod.name));
+            return;
+        }
+        self.cordon_node = Some(pod.node.clone());
+        self.input_mode = InputMode::ConfirmCordon;
+    }
+
+    async fn cordon_confirmed_node(&mut self) {
+        let Some(node) = self.cordon_node.take() else {
+            return;
+        };
+        match self.client.cordon_node(&node).await {
+            Ok(()) => {
+                self.status_message =
+                    format!("Cordoned node {}; new pods won't be scheduled there", node);
+                self.record_activity(format!("Cordoned node {}", node));
+            }
+            Err(e) => {
+                self.report_error(&format!("Failed to cordon {}", node), &e);
             }
         }
-        Ok(())
     }
 
-    fn toggle_log_follow(&mut self) {
-        self.logs_follow = !self.logs_follow;
-        if self.logs_follow {
-            // Scroll to bottom when enabling follow mode
-            let log_lines = self.logs.lines().count();
-            self.logs_scroll = log_lines.saturating_sub(1);
-            self.status_message = "Log follow mode enabled (press 'f' to disable)".to_string();
-        } else {
-            self.status_message = "Log follow mode disabled".to_string();
+    /// Look up the namespace's completed Jobs and ask once, with the count, before deleting them
+    async fn request_completed_jobs_cleanup(&mut self) {
+        match self
+            .client
+            .list_completed_jobs(&self.current_namespace)
+            .await
+        {
+            Ok(jobs) if jobs.is_empty() => {
+                self.status_message =
+                    format!("No completed jobs in namespace {}", self.current_namespace);
+            }
+            Ok(jobs) => {
+                self.completed_jobs = jobs;
+                self.input_mode = InputMode::ConfirmJobCleanup;
+            }
+            Err(e) => {
+                self.report_error("Failed to list jobs", &e);
+            }
         }
     }
 
-    pub async fn refresh_logs(&mut self) -> Result<()> {
-        if self.logs_follow && self.current_view == View::Logs {
-            if let Some(pod_name) = &self.logs_pod_name.clone() {
-                match self
-                    .client
-                    .get_pod_logs(&self.current_namespace, pod_name)
-                    .await
-                {
-                    Ok(logs) => {
-                        self.logs = logs;
-                        // Auto-scroll to bottom in follow mode
-                        let log_lines = self.logs.lines().count();
-                        self.logs_scroll = log_lines.saturating_sub(1);
-                    }
-                    Err(_) => {
-                        // Silently ignore errors in background refresh
-                    }
+    async fn delete_completed_jobs(&mut self, jobs: &[String]) -> Result<()> {
+        let mut deleted = 0;
+        for job in jobs {
+            match self.client.delete_job(&self.current_namespace, job).await {
+                Ok(()) => deleted += 1,
+                Err(e) => {
+                    self.report_error(&format!("Failed to delete job {}", job), &e);
                 }
             }
         }
+        if deleted > 0 {
+            self.status_message = format!("Deleted {} of {} completed jobs", deleted, jobs.len());
+            self.record_activity(format!("Deleted {} completed jobs", deleted));
+            self.refresh_current_view().await?;
+        }
         Ok(())
     }
 
+    /// Switch to the selected context, or ask first if `confirm_context_switch` is set
+    async fn request_context_switch(&mut self) -> Result<()> {
+        if self.contexts.get(self.context_index).is_none() {
+            return Ok(());
+        }
+        if self.config.confirm_context_switch {
+            self.input_mode = InputMode::ConfirmContextSwitch;
+            Ok(())
+        } else {
+            self.switch_to_selected_context().await
+        }
+    }
+
+    /// Start switching to the selected context; `advance_context_switch` does the work
     async fn switch_to_selected_context(&mut self) -> Result<()> {
-        if let Some(context) = self.contexts.get(self.context_index) {
+        if let Some(context) = self.contexts.get(self.context_index).cloned() {
             // Clear any previous errors
-            self.error_message = None;
+            self.clear_error();
             self.status_message = format!("Switching to context: {}...", context.name);
+            self.context_switch = Some(ContextSwitch {
+                context,
+                step: ContextSwitchStep::Switching,
+            });
+        }
+        Ok(())
+    }
+
+    /// Run the current step of a context switch and announce the next one
+    /// (called from the main loop, which redraws in between)
+    pub async fn advance_context_switch(&mut self) -> Result<()> {
+        let Some(ContextSwitch { context, step }) = self.context_switch.take() else {
+            return Ok(());
+        };
+        let next = |step| {
+            Some(ContextSwitch {
+                context: context.clone(),
+                step,
+            })
+        };
 
-            match KubeClient::switch_context(&context.name) {
+        match step {
+            ContextSwitchStep::Switching => match KubeClient::switch_context(&context.name) {
                 Ok(_) => {
                     self.current_context = context.name.clone();
-
-                    // Reinitialize client with new context
-                    match KubeClient::new().await {
-                        Ok(new_client) => {
-                            self.client = new_client;
-
-                            // Try to verify connection by listing namespaces
-                            match self.client.list_namespaces().await {
-                                Ok(namespaces) => {
-                                    self.namespaces = namespaces;
-                                    self.current_namespace = if !context.namespace.is_empty() {
-                                        context.namespace.clone()
-                                    } else {
-                                        self.namespaces
-                                            .first()
-                                            .cloned()
-                                            .unwrap_or_else(|| "default".to_string())
-                                    };
-
-                                    // Success! Clear any errors and show success message
-                                    self.error_message = None;
-                                    self.status_message = format!(
-                                        "Successfully connected to context: {} (namespace: {})",
-                                        context.name, self.current_namespace
-                                    );
-
-                                    // Switch to Pods view and refresh
-                                    self.current_view = View::Pods;
-                                    self.refresh_current_view().await?;
-                                }
-                                Err(e) => {
-                                    self.error_message = Some(format!(
-                                        "Switched to '{}' but failed to connect: {}. The cluster may be down or unreachable.",
-                                        context.name, e
-                                    ));
-                                    self.namespaces = vec!["default".to_string()];
-                                    self.current_namespace = "default".to_string();
-                                }
-                            }
-
-                            // Refresh context list to update current indicator
-                            self.refresh_current_view().await?;
-                        }
-                        Err(e) => {
-                            self.error_message = Some(format!(
-                                "Switched to '{}' but failed to initialize client: {}. Check your kubeconfig.",
-                                context.name, e
-                            ));
-                        }
-                    }
+                    self.record_activity(format!("Switched context to {}", context.name));
+                    self.status_message = format!("Reconnecting to {}...", context.name);
+                    self.context_switch = next(ContextSwitchStep::Reconnecting);
+                }
+                Err(e) => {
+                    self.report_error("Failed to switch context", &e);
+                }
+            },
+            ContextSwitchStep::Reconnecting => match KubeClient::new(&self.config).await {
+                Ok(new_client) => {
+                    self.tls_insecure = new_client.tls_verification_disabled();
+                    self.client = Box::new(new_client);
+                    // The old watchers would keep streaming the previous cluster's objects
+                    self.cleanup_pod_watcher();
+                    self.stop_events_follow();
+                    self.view_loaded_at.clear();
+                    self.marked_deployments.clear();
+                    self.consecutive_failures = 0;
+                    self.connection_lost_at = None;
+                    self.status_message = format!("Listing namespaces in {}...", context.name);
+                    self.context_switch = next(ContextSwitchStep::ListingNamespaces);
+                }
+                Err(e) => {
+                    self.report_error(
+                        &format!(
+                            "Switched to '{}' but failed to initialize the client from the kubeconfig",
+                            context.name
+                        ),
+                        &e,
+                    );
+                    self.client = Box::new(DisconnectedClient);
+                    self.connected = false;
+                    self.tls_insecure = false;
+                    self.refresh_context_list();
+                }
+            },
+            // Listing namespaces verifies the connection
+            ContextSwitchStep::ListingNamespaces => match self.client.list_namespaces().await {
+                Ok(namespaces) => {
+                    self.set_namespaces(namespaces, context.namespace.clone());
+                    self.connected = true;
+                    self.current_view = View::Pods;
+                    self.status_message = format!(
+                        "Loading pods in {}/{}...",
+                        context.name, self.current_namespace
+                    );
+                    self.context_switch = next(ContextSwitchStep::LoadingPods);
                 }
                 Err(e) => {
-                    self.error_message = Some(format!("Failed to switch context: {}", e));
+                    self.report_error(
+                        &format!("Switched to '{}' but failed to connect", context.name),
+                        &e,
+                    );
+                    self.set_namespaces(vec![], context.namespace.clone());
+                    self.connected = false;
+                    self.refresh_context_list();
+                }
+            },
+            ContextSwitchStep::LoadingPods => {
+                self.refresh_current_view().await?;
+                if self.error_message.is_none() {
+                    self.status_message = format!(
+                        "Successfully connected to context: {} (namespace: {})",
+                        context.name, self.current_namespace
+                    );
                 }
+                self.refresh_context_list();
             }
         }
         Ok(())
     }
 
-    async fn switch_to_selected_namespace(&mut self) -> Result<()> {
+    /// Reread the contexts so the current-context marker follows a switch
+    fn refresh_context_list(&mut self) {
+        if let Ok(contexts) = KubeClient::list_contexts() {
+            self.set_contexts(contexts);
+        }
+    }
+
+    /// Show the overview of the selected namespace; Enter from there switches into it
+    async fn summarize_selected_namespace(&mut self) -> Result<()> {
         if let Some(namespace) = self.namespaces.get(self.namespace_index).cloned() {
-            self.cleanup_pod_watcher(); // Stop watching old namespace
-            self.current_namespace = namespace.clone();
-            self.status_message = format!("Switched to namespace: {}", namespace);
-            self.current_view = View::Pods;
-            self.refresh_current_view().await?;
+            match self.client.namespace_summary(&namespace).await {
+                Ok(summary) => self.open_detail(Detail::Namespace(summary)),
+                Err(e) => {
+                    self.report_error("Failed to summarize namespace", &e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Switch namespace and reload whatever view is showing
+    async fn switch_namespace(&mut self, namespace: String) -> Result<()> {
+        self.cleanup_pod_watcher(); // Stop watching old namespace
+        self.stop_events_follow();
+        if let Some(index) = self.namespaces.iter().position(|ns| *ns == namespace) {
+            self.namespace_index = index;
         }
+        self.current_namespace = namespace.clone();
+        // Whatever other views loaded belongs to the old namespace
+        self.view_loaded_at.clear();
+        self.marked_deployments.clear();
+        self.status_message = format!("Switched to namespace: {}", namespace);
+        self.refresh_current_view().await?;
         Ok(())
     }
 
     async fn exec_into_pod(&mut self) -> Result<()> {
-        if self.pods.get(self.pod_index).is_some() {
+        if self.selected_pod().is_some() && !self.refuse_kubectl("Exec") {
             // Show terminal choice menu
             self.input_mode = InputMode::TerminalChoice;
             self.terminal_choice_selection = 0;
@@ -744,22 +4650,56 @@ impl App {
     }
 
     async fn handle_terminal_mode(&mut self, event: InputEvent) -> Result<bool> {
-        // Handle Ctrl+D to exit terminal
+        // Handle Ctrl+D to close the active terminal
         if let KeyCode::Char('d') = event.key_code() {
             if event.modifiers().contains(KeyModifiers::CONTROL) {
-                self.close_terminal();
-                self.current_view = View::Pods;
+                self.close_active_terminal();
+                if self.terminal_sessions.is_empty() {
+                    self.current_view = View::Pods;
+                }
                 return Ok(true);
             }
         }
 
-        // Handle Esc to exit terminal
+        // Handle Ctrl+R to reconnect the active terminal
+        if let KeyCode::Char('r') = event.key_code() {
+            if event.modifiers().contains(KeyModifiers::CONTROL) {
+                self.reconnect_active_terminal().await?;
+                return Ok(true);
+            }
+        }
+
+        // Handle Esc to go back to pods, leaving the terminals running
         if let KeyCode::Esc = event.key_code() {
-            self.close_terminal();
             self.current_view = View::Pods;
+            self.refresh_on_focus().await?;
+            if !self.terminal_sessions.is_empty() {
+                self.status_message = format!(
+                    "{} terminal(s) still open | Press t to return",
+                    self.terminal_sessions.len()
+                );
+            }
             return Ok(true);
         }
 
+        // Alt+Left/Right and Alt+1-9 switch between terminal tabs
+        let count = self.terminal_sessions.len();
+        if count > 0 && event.modifiers().contains(KeyModifiers::ALT) {
+            let target = match event.key_code() {
+                KeyCode::Left => Some((self.active_terminal + count - 1) % count),
+                KeyCode::Right => Some((self.active_terminal + 1) % count),
+                KeyCode::Char(c @ '1'..='9') => Some(c as usize - '1' as usize),
+                _ => None,
+            };
+            if let Some(index) = target {
+                if index < count {
+                    self.active_terminal = index;
+                    self.terminal_scroll = 0;
+                }
+                return Ok(true);
+            }
+        }
+
         // Handle Page Up/Down for scrolling (don't send to terminal)
         match event.key_code() {
             KeyCode::PageUp => {
@@ -776,7 +4716,7 @@ impl App {
         }
 
         // Forward all other input to the terminal
-        if let Some(session) = &self.terminal_session {
+        if let Some(session) = self.terminal_sessions.get(self.active_terminal) {
             if let Ok(mut session) = session.lock() {
                 session.send_input(&event)?;
             }
@@ -788,19 +4728,65 @@ impl App {
         Ok(true)
     }
 
-    fn close_terminal(&mut self) {
-        if let Some(session) = &self.terminal_session {
+    fn close_active_terminal(&mut self) {
+        if self.active_terminal >= self.terminal_sessions.len() {
+            return;
+        }
+
+        let session = self.terminal_sessions.remove(self.active_terminal);
+        if let Ok(mut session) = session.lock() {
+            let _ = session.close();
+        }
+        self.active_terminal = self
+            .active_terminal
+            .min(self.terminal_sessions.len().saturating_sub(1));
+        self.terminal_scroll = 0;
+    }
+
+    fn close_all_terminals(&mut self) {
+        for session in self.terminal_sessions.drain(..) {
             if let Ok(mut session) = session.lock() {
                 let _ = session.close();
             }
         }
-        self.terminal_session = None;
-        self.terminal_pod_name = None;
+        self.active_terminal = 0;
         self.terminal_scroll = 0;
     }
 
+    /// Pod name (with the container, if one was picked) and liveness of the open
+    /// terminals, in tab order
+    pub fn terminal_tabs(&self) -> Vec<(String, bool)> {
+        self.terminal_sessions
+            .iter()
+            .map(|session| {
+                session
+                    .lock()
+                    .map(|mut session| {
+                        let name = match &session.exec_container {
+                            Some(container) => format!("{}/{}", session.pod_name, container),
+                            None => session.pod_name.clone(),
+                        };
+                        (name, session.is_alive())
+                    })
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// Fit every open terminal to a `rows`x`cols` screen. Called with the terminal
+    /// view's size on each frame; sessions already that size are left alone.
+    pub fn resize_terminals(&mut self, rows: u16, cols: u16) {
+        for session in &self.terminal_sessions {
+            if let Ok(mut session) = session.lock() {
+                if let Err(e) = session.resize(rows, cols) {
+                    self.error_message = Some(format!("Failed to resize terminal: {}", e));
+                }
+            }
+        }
+    }
+
     pub fn get_terminal_screen(&self) -> Option<Vec<String>> {
-        if let Some(session) = &self.terminal_session {
+        if let Some(session) = self.terminal_sessions.get(self.active_terminal) {
             if let Ok(mut session) = session.lock() {
                 return Some(session.get_screen());
             }
@@ -818,14 +4804,196 @@ impl App {
         if let Some(watcher) = &mut self.pod_watcher {
             // Try to receive updates without blocking
             if let Ok(updated_pods) = watcher.rx.try_recv() {
+                // Keep the cursor on the same pod: a new pod sorting above it would
+                // otherwise shift the selection onto a different one
+                let selected = self.selected_pod().map(|p| p.name.clone());
                 self.pods = updated_pods;
+                self.order_pods();
+                if let Some(index) =
+                    selected.and_then(|name| self.pods.iter().position(|p| p.name == name))
+                {
+                    self.pod_index = index;
+                }
 
                 // Adjust selection if pods were removed
                 if self.pod_index >= self.pods.len() && !self.pods.is_empty() {
                     self.pod_index = self.pods.len().saturating_sub(1);
                 }
+                self.keep_selection_shown(View::Pods);
+            }
+        }
+    }
+
+    /// Merge streamed events into the Events view (non-blocking). The newest stay
+    /// on top, and the selection follows them unless it was moved off the top.
+    pub fn try_update_events(&mut self) {
+        if self.event_watcher.is_none() {
+            return;
+        }
+        // Only the Events view shows the stream
+        if self.current_view != View::Events {
+            self.stop_events_follow();
+            return;
+        }
+        let Some(watcher) = &mut self.event_watcher else {
+            return;
+        };
+        let mut received = Vec::new();
+        while let Ok(event) = watcher.rx.try_recv() {
+            received.push(event);
+        }
+        if received.is_empty() {
+            return;
+        }
+
+        let at_top = self.event_index == 0;
+        let selected = self.events.get(self.event_index).map(|e| e.name.clone());
+        for event in received {
+            // An updated event (count bumped, seen again) replaces its old row
+            self.events.retain(|e| e.name != event.name);
+            self.events.push(event);
+        }
+        self.events.sort_by_key(|e| std::cmp::Reverse(e.last_seen));
+        if let Some(spec) = &self.config.events.sort {
+            sort_events(&mut self.events, &SortKey::parse_list(spec));
+        }
+        self.event_index = if at_top {
+            0
+        } else {
+            selected
+                .and_then(|name| self.events.iter().position(|e| e.name == name))
+                .unwrap_or(0)
+        };
+    }
+
+    /// Take the latest rollout status from the watcher (non-blocking)
+    pub fn try_update_rollout(&mut self) {
+        let Some(watcher) = &mut self.rollout_watcher else {
+            return;
+        };
+        loop {
+            match watcher.rx.try_recv() {
+                Ok(status) => self.rollout_status = Some(status),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    // The watch task went away without a final word
+                    let live = self
+                        .rollout_status
+                        .as_ref()
+                        .is_none_or(|s| s.state == RolloutState::Progressing);
+                    if live {
+                        self.rollout_status = Some(RolloutStatus::watch_stopped(
+                            self.rollout_status.as_ref(),
+                            "the watch ended",
+                        ));
+                    }
+                    break;
+                }
+            }
+        }
+        if let Some(status) = &self.rollout_status {
+            if status.state != RolloutState::Progressing {
+                // The watch ends with the rollout; keep the final status on screen
+                self.rollout_watcher = None;
+            }
+        }
+    }
+
+    /// Update the Warning events badge (called periodically from the main loop)
+    pub async fn refresh_warning_count(&mut self) {
+        if !self.connected {
+            return;
+        }
+        // Errors are ignored so a missing events permission doesn't spam the footer
+        if let Ok(count) = self
+            .client
+            .count_recent_warnings(&self.current_namespace)
+            .await
+        {
+            self.warning_count = count;
+        }
+    }
+
+    /// Add a usage sample for the pod open in the detail (called from the main
+    /// loop). Errors are ignored; the sparklines just stop growing.
+    pub async fn sample_pod_usage(&mut self) {
+        let Some(Detail::Pod(detail)) = &self.detail else {
+            return;
+        };
+        if !self.connected {
+            return;
+        }
+        let (namespace, pod) = (detail.namespace.clone(), detail.name.clone());
+        match self.client.pod_metrics(&namespace, &pod).await {
+            Ok(Some(usage)) => {
+                // Start over when a different pod is open
+                let same_pod = self
+                    .pod_usage
+                    .as_ref()
+                    .is_some_and(|h| h.namespace == namespace && h.pod == pod);
+                if !same_pod {
+                    self.pod_usage = Some(UsageHistory {
+                        namespace,
+                        pod,
+                        containers: vec![],
+                    });
+                }
+                if let Some(history) = &mut self.pod_usage {
+                    history.record(&usage);
+                }
             }
+            // No metrics-server, or no sample for this pod yet
+            Ok(None) => self.pod_usage = None,
+            Err(_) => {}
+        }
+    }
+
+    /// Ping the cluster in the background (called from the main loop), so a
+    /// dropped connection is noticed even when nothing is being refreshed
+    pub async fn check_connection(&mut self) {
+        if !self.connected || self.context_switch.is_some() {
+            return;
+        }
+        match self.client.ping().await {
+            Ok(_) => self.note_connection_ok(),
+            Err(e) if QuiError::classify(&e).is_transient() => self.note_connection_failure(),
+            // The server answered, just not with a version
+            Err(_) => self.note_connection_ok(),
+        }
+    }
+
+    fn note_connection_failure(&mut self) {
+        self.consecutive_failures += 1;
+        if self.consecutive_failures >= self.config.connection_loss.failures.max(1)
+            && self.connection_lost_at.is_none()
+        {
+            self.connection_lost_at = Some(Instant::now());
+        }
+    }
+
+    fn note_connection_ok(&mut self) {
+        self.consecutive_failures = 0;
+        if self.connection_lost_at.take().is_some() {
+            self.status_message = format!("Connection to {} restored", self.current_context);
+        }
+    }
+
+    /// Time left before quitting over a lost connection, when `exit_after_secs` is set
+    pub fn connection_exit_in(&self) -> Option<Duration> {
+        let lost_at = self.connection_lost_at?;
+        let grace = Duration::from_secs(self.config.connection_loss.exit_after_secs?);
+        Some(grace.saturating_sub(lost_at.elapsed()))
+    }
+
+    /// Why the app should quit now, once a lost connection has outlasted its grace period
+    pub fn connection_exit_reason(&self) -> Option<String> {
+        if self.connection_exit_in()? > Duration::ZERO {
+            return None;
         }
+        Some(format!(
+            "Lost the connection to {} ({} requests in a row failed); exited instead of showing stale data",
+            self.current_context, self.consecutive_failures
+        ))
     }
 
     /// Clean up pod watcher to prevent memory leaks
@@ -841,6 +5009,9 @@ impl App {
             View::Services,
             View::Clusters,
             View::Namespaces,
+            View::Events,
+            View::ConfigMaps,
+            View::Secrets,
             View::Help,
         ];
 
@@ -857,7 +5028,7 @@ impl App {
             }
 
             self.current_view = tabs[new_index];
-            self.refresh_current_view().await?;
+            self.refresh_on_focus().await?;
         }
 
         Ok(())
@@ -870,6 +5041,9 @@ impl App {
             View::Services,
             View::Clusters,
             View::Namespaces,
+            View::Events,
+            View::ConfigMaps,
+            View::Secrets,
             View::Help,
         ];
 
@@ -882,48 +5056,373 @@ impl App {
             }
 
             self.current_view = tabs[new_index];
-            self.refresh_current_view().await?;
+            self.refresh_on_focus().await?;
         }
         Ok(())
     }
 
+    /// Record a mutating action in the session audit trail and, if configured, the activity log file
+    /// Show a failed client call by category: something already gone is only a
+    /// status note, permission and connectivity problems say what to do next
+    fn report_error(&mut self, action: &str, err: &anyhow::Error) {
+        let category = QuiError::classify(err);
+        if category.is_transient() {
+            self.note_connection_failure();
+        }
+        match category {
+            QuiError::NotFound(message) => {
+                self.status_message =
+                    format!("{}: {} (it may have just been deleted)", action, message);
+            }
+            category => {
+                self.error_message = Some(match category.hint() {
+                    Some(hint) => format!("{}: {} ({})", action, err, hint),
+                    None => format!("{}: {}", action, err),
+                });
+            }
+        }
+    }
+
+    fn record_activity(&mut self, action: String) {
+        let entry = ActivityEntry {
+            timestamp: Utc::now(),
+            context: self.current_context.clone(),
+            namespace: self.current_namespace.clone(),
+            action,
+        };
+
+        if let Some(path) = &self.config.activity_log {
+            let line = format!(
+                "{} context={} namespace={} {}\n",
+                entry.timestamp.to_rfc3339(),
+                entry.context,
+                entry.namespace,
+                entry.action
+            );
+            let written = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .and_then(|mut file| file.write_all(line.as_bytes()));
+            if let Err(e) = written {
+                self.error_message = Some(format!(
+                    "Failed to write activity log {}: {}",
+                    path.display(),
+                    e
+                ));
+            }
+        }
+
+        self.activity.push(entry);
+    }
+
     pub fn get_help_text(&self) -> Vec<(&str, &str)> {
         let mut help = vec![
-            ("q", "Quit"),
+            ("q/^C", "Quit"),
             ("←/→", "Switch Tab"),
-            ("1-5", "Jump to Tab"),
+            ("1-8", "Jump to Tab"),
+            ("^N", "Namespace"),
+            ("^F", "Search"),
             ("r", "Refresh"),
+            ("a", "Activity"),
             ("↑/k", "Up"),
             ("↓/j", "Down"),
         ];
 
         match self.current_view {
             View::Pods => {
+                help.push(("Enter", "Describe"));
+                help.push(("y", "YAML"));
                 help.push(("l", "Logs"));
+                help.push(("A", "All Containers"));
+                help.push(("c", "Copy Ref"));
                 help.push(("e", "Exec"));
+                if !self.terminal_sessions.is_empty() {
+                    help.push(("t", "Terminals"));
+                }
+                help.push(("F", "Port Forward"));
                 help.push(("d", "Delete"));
+                help.push(("/", "Filter"));
             }
             View::Deployments => {
+                help.push(("Enter", "Describe"));
+                help.push(("y", "YAML"));
+                help.push(("c", "Copy Ref"));
                 help.push(("s", "Scale"));
+                help.push(("Space", "Mark"));
+                help.push(("w", "Rollout"));
                 help.push(("d", "Delete"));
+                help.push(("/", "Filter"));
+            }
+            View::Services => {
+                help.push(("Enter", "Describe"));
+                help.push(("y", "YAML"));
+                help.push(("c", "Copy Ref"));
+                help.push(("/", "Filter"));
+            }
+            View::ConfigMaps => {
+                help.push(("Enter", "Show Data"));
+            }
+            View::Secrets => {
+                help.push(("Enter", "Show Keys"));
             }
             View::Clusters => {
                 help.push(("Enter", "Switch"));
+                help.push(("i", "Connection Info"));
+                help.push(("V", "Kubeconfig"));
+                help.push(("e", "Edit Kubeconfig"));
+                help.push(("f", "Favorite"));
+                help.push(("Alt+1-9", "Jump"));
             }
             View::Namespaces => {
-                help.push(("Enter", "Switch"));
+                help.push(("Enter", "Overview"));
+                help.push(("f", "Favorite"));
+                help.push(("Alt+1-9", "Jump"));
             }
             View::Logs => {
                 help.push(("↑/↓", "Scroll"));
                 help.push(("f", "Follow"));
+                help.push(("p", "Previous"));
+                help.push(("i/x", "Include/Exclude"));
                 help.push(("Esc", "Back"));
             }
-            View::Help => {
+            View::Help | View::Activity => {
                 help.push(("Esc", "Close"));
             }
+            View::PortForwards => {
+                help.push(("d", "Stop"));
+                help.push(("Esc", "Close"));
+            }
+            View::Detail => {
+                help.push(("↑/↓", "Scroll"));
+                if let Some(Detail::Yaml(_)) = self.detail {
+                    help.push(("m", "Managed Fields"));
+                    help.push(("s", "Status"));
+                }
+                if let Some(Detail::Namespace(_)) = self.detail {
+                    help.push(("Enter", "Switch"));
+                }
+                if let Some(Detail::Deployment(_)) = self.detail {
+                    help.push(("e", "Edit Env"));
+                }
+                if let Some(Detail::Secret(_)) = self.detail {
+                    help.push((
+                        "x",
+                        if self.secret_revealed {
+                            "Hide"
+                        } else {
+                            "Reveal"
+                        },
+                    ));
+                }
+                if matches!(
+                    self.detail,
+                    Some(
+                        Detail::Pod(_)
+                            | Detail::Deployment(_)
+                            | Detail::Service(_)
+                            | Detail::Node(_)
+                    )
+                ) {
+                    help.push(("K", "kubectl describe"));
+                }
+                help.push(("Esc", "Back"));
+            }
             _ => {}
         }
 
         help
     }
 }
+
+/// Keep only the last `max_lines` lines of a log buffer
+fn cap_lines(logs: String, max_lines: usize) -> String {
+    let total = logs.lines().count();
+    if total <= max_lines {
+        return logs;
+    }
+    logs.lines()
+        .skip(total - max_lines)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Find where the lines that weren't in `previous` start in `current`.
+/// The tail window slides as lines arrive, so `current` starts with the end of
+/// `previous`: the longest such overlap is what we had already seen. Matching
+/// the whole overlap rather than just the last line copes with repeated lines.
+fn first_new_line(previous: &str, current: &str) -> Option<usize> {
+    let seen: Vec<&str> = previous.lines().collect();
+    let lines: Vec<&str> = current.lines().collect();
+    let overlap = (1..=seen.len().min(lines.len()))
+        .rev()
+        .find(|&len| seen[seen.len() - len..] == lines[..len])?;
+    (overlap < lines.len()).then_some(overlap)
+}
+
+/// Favorite contexts first, then each part by cluster and name, so contexts
+/// sharing a cluster sit together
+fn order_contexts(contexts: &mut [ContextInfo], favorites: &[String]) {
+    contexts.sort_by(|a, b| (&a.cluster, &a.name).cmp(&(&b.cluster, &b.name)));
+    favorites_first(contexts, favorites, |ctx| &ctx.name);
+}
+
+/// Text of a pod column, as shown in the table
+pub fn pod_value(pod: &PodInfo, key: &str, status: PodStatusDisplay) -> String {
+    match key {
+        "name" => pod.name.clone(),
+        "ready" => pod.ready.clone(),
+        "status" => match status {
+            PodStatusDisplay::Phase => pod.status.clone(),
+            PodStatusDisplay::Computed => pod.computed_status.clone(),
+        },
+        "restarts" => pod.restarts.to_string(),
+        "age" => pod.age.clone(),
+        "node" => pod.node.clone(),
+        "ip" => pod.ip.clone(),
+        "init" => pod
+            .init
+            .as_ref()
+            .map(|init| init.to_string())
+            .unwrap_or_else(|| "-".to_string()),
+        _ => String::new(),
+    }
+}
+
+/// Text of a deployment column, as shown in the table
+pub fn deployment_value(dep: &DeploymentInfo, key: &str) -> String {
+    match key {
+        "name" => dep.name.clone(),
+        "ready" => dep.ready.clone(),
+        "up-to-date" => dep.up_to_date.to_string(),
+        "available" => dep.available.to_string(),
+        "age" => dep.age.clone(),
+        _ => String::new(),
+    }
+}
+
+/// Text of a service column, as shown in the table
+pub fn service_value(svc: &ServiceInfo, key: &str) -> String {
+    match key {
+        "name" => svc.name.clone(),
+        "type" => svc.service_type.clone(),
+        "cluster-ip" => svc.cluster_ip.clone(),
+        "ports" => svc.ports.clone(),
+        "age" => svc.age.clone(),
+        _ => String::new(),
+    }
+}
+
+/// Order `items` by `keys`, the first that tells two items apart deciding.
+/// `compare` gets the column key; unknown keys compare equal.
+fn sort_by_keys<T>(items: &mut [T], keys: &[SortKey], compare: impl Fn(&T, &T, &str) -> Ordering) {
+    if keys.is_empty() {
+        return;
+    }
+    items.sort_by(|a, b| {
+        keys.iter()
+            .map(|k| {
+                let ordering = compare(a, b, &k.key);
+                if k.descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+}
+
+pub fn sort_pods(pods: &mut [PodInfo], keys: &[SortKey], status: PodStatusDisplay) {
+    sort_by_keys(pods, keys, |a, b, key| match key {
+        "restarts" => a.restarts.cmp(&b.restarts),
+        "age" => a.created.cmp(&b.created),
+        "init" => a
+            .init
+            .as_ref()
+            .map(|i| (i.done, i.total))
+            .cmp(&b.init.as_ref().map(|i| (i.done, i.total))),
+        "name" | "ready" | "status" | "node" | "ip" => {
+            pod_value(a, key, status).cmp(&pod_value(b, key, status))
+        }
+        _ => Ordering::Equal,
+    });
+}
+
+pub fn sort_deployments(deployments: &mut [DeploymentInfo], keys: &[SortKey]) {
+    sort_by_keys(deployments, keys, |a, b, key| match key {
+        "up-to-date" => a.up_to_date.cmp(&b.up_to_date),
+        "available" => a.available.cmp(&b.available),
+        "age" => a.created.cmp(&b.created),
+        "name" | "ready" => deployment_value(a, key).cmp(&deployment_value(b, key)),
+        _ => Ordering::Equal,
+    });
+}
+
+pub fn sort_services(services: &mut [ServiceInfo], keys: &[SortKey]) {
+    sort_by_keys(services, keys, |a, b, key| match key {
+        "age" => a.created.cmp(&b.created),
+        "name" | "type" | "cluster-ip" | "ports" => {
+            service_value(a, key).cmp(&service_value(b, key))
+        }
+        _ => Ordering::Equal,
+    });
+}
+
+pub fn sort_events(events: &mut [EventInfo], keys: &[SortKey]) {
+    sort_by_keys(events, keys, |a, b, key| match key {
+        "last-seen" => a.last_seen.cmp(&b.last_seen),
+        "type" => a.type_.cmp(&b.type_),
+        "reason" => a.reason.cmp(&b.reason),
+        "object" => a.object.cmp(&b.object),
+        "count" => a.count.cmp(&b.count),
+        "message" => a.message.cmp(&b.message),
+        _ => Ordering::Equal,
+    });
+}
+
+/// Ports for a forward as kubectl takes them: `REMOTE` (the same port locally),
+/// `LOCAL:REMOTE`, or `:REMOTE` for any free local port
+pub fn parse_port_forward(input: &str) -> Option<(u16, u16)> {
+    let (local, remote) = match input.trim().split_once(':') {
+        Some(("", remote)) => (0, remote.parse().ok()?),
+        Some((local, remote)) => (local.parse().ok()?, remote.parse().ok()?),
+        None => {
+            let port = input.trim().parse().ok()?;
+            (port, port)
+        }
+    };
+    (remote != 0).then_some((local, remote))
+}
+
+/// Keys that only move around, switch views, refresh or recolor, so they're still handled
+/// after an out-of-range selection is pulled back onto the list
+fn is_navigation_key(event: &InputEvent) -> bool {
+    if event
+        .modifiers()
+        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+    {
+        return true;
+    }
+    matches!(
+        event.key_code(),
+        KeyCode::Up
+            | KeyCode::Down
+            | KeyCode::Left
+            | KeyCode::Right
+            | KeyCode::PageUp
+            | KeyCode::PageDown
+            | KeyCode::Home
+            | KeyCode::End
+            | KeyCode::Esc
+            | KeyCode::Char(
+                'j' | 'k' | 'r' | 'q' | 'Q' | '?' | 'h' | 'a' | 'n' | ':' | 'T' | '1'..='8'
+            )
+    )
+}
+
+/// Move favorites to the front, keeping the original order within each group
+fn favorites_first<T>(items: &mut [T], favorites: &[String], name: impl Fn(&T) -> &str) {
+    items.sort_by_key(|item| !favorites.iter().any(|f| f == name(item)));
+}
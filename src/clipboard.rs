@@ -0,0 +1,43 @@
+use anyhow::Result;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Copy text to the system clipboard using the platform's clipboard command
+pub fn copy(text: &str) -> Result<()> {
+    #[cfg(target_os = "macos")]
+    let candidates: &[(&str, &[&str])] = &[("pbcopy", &[])];
+
+    #[cfg(target_os = "linux")]
+    let candidates: &[(&str, &[&str])] = &[
+        ("wl-copy", &[]),
+        ("xclip", &["-selection", "clipboard"]),
+        ("xsel", &["--clipboard", "--input"]),
+    ];
+
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    let candidates: &[(&str, &[&str])] = &[];
+
+    for (program, args) in candidates {
+        let Ok(mut child) = Command::new(program)
+            .args(*args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+
+        if let Some(stdin) = child.stdin.as_mut() {
+            stdin.write_all(text.as_bytes())?;
+        }
+        // Close stdin so the tool sees EOF
+        drop(child.stdin.take());
+
+        if child.wait()?.success() {
+            return Ok(());
+        }
+    }
+
+    anyhow::bail!("No clipboard tool found. Install wl-copy, xclip or xsel.")
+}
@@ -0,0 +1,81 @@
+use crate::app::View;
+use crate::config::{Config, TableConfig};
+
+/// A table column: the key used in the config file, its header and default width (%)
+pub type ColumnSpec = (&'static str, &'static str, u16);
+
+// The first `DEFAULT_*_COLUMNS` entries are shown when the config doesn't pick columns
+pub const POD_COLUMNS: &[ColumnSpec] = &[
+    ("name", "NAME", 30),
+    ("ready", "READY", 15),
+    ("status", "STATUS", 20),
+    ("restarts", "RESTARTS", 15),
+    ("age", "AGE", 20),
+    ("node", "NODE", 25),
+    ("ip", "IP", 15),
+    ("init", "INIT", 15),
+];
+pub const DEFAULT_POD_COLUMNS: usize = 5;
+
+pub const DEPLOYMENT_COLUMNS: &[ColumnSpec] = &[
+    ("name", "NAME", 30),
+    ("ready", "READY", 20),
+    ("up-to-date", "UP-TO-DATE", 20),
+    ("available", "AVAILABLE", 15),
+    ("age", "AGE", 15),
+];
+pub const DEFAULT_DEPLOYMENT_COLUMNS: usize = 5;
+
+pub const SERVICE_COLUMNS: &[ColumnSpec] = &[
+    ("name", "NAME", 25),
+    ("type", "TYPE", 15),
+    ("cluster-ip", "CLUSTER-IP", 20),
+    ("ports", "PORTS", 25),
+    ("age", "AGE", 15),
+];
+pub const DEFAULT_SERVICE_COLUMNS: usize = 5;
+
+/// Resolve the configured column keys against a view's known columns,
+/// falling back to the defaults when nothing valid is configured
+pub fn visible_columns(
+    all: &'static [ColumnSpec],
+    configured: Option<&Vec<String>>,
+    default_count: usize,
+) -> Vec<&'static ColumnSpec> {
+    let selected: Vec<&ColumnSpec> = configured
+        .map(|keys| {
+            keys.iter()
+                .filter_map(|key| all.iter().find(|c| c.0.eq_ignore_ascii_case(key)))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if selected.is_empty() {
+        all.iter().take(default_count).collect()
+    } else {
+        selected
+    }
+}
+
+/// Width of a column: the user's resized value if any, else the default
+pub fn column_width(column: &ColumnSpec, table: &TableConfig) -> u16 {
+    table.widths.get(column.0).copied().unwrap_or(column.2)
+}
+
+/// Key and current width of each visible column of a resizable table view
+pub fn table_columns(view: View, config: &Config) -> Vec<(&'static str, u16)> {
+    let (all, table, default_count) = match view {
+        View::Pods => (POD_COLUMNS, &config.pods, DEFAULT_POD_COLUMNS),
+        View::Deployments => (
+            DEPLOYMENT_COLUMNS,
+            &config.deployments,
+            DEFAULT_DEPLOYMENT_COLUMNS,
+        ),
+        View::Services => (SERVICE_COLUMNS, &config.services, DEFAULT_SERVICE_COLUMNS),
+        _ => return vec![],
+    };
+    visible_columns(all, table.columns.as_ref(), default_count)
+        .into_iter()
+        .map(|c| (c.0, column_width(c, table)))
+        .collect()
+}
@@ -0,0 +1,448 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// User settings read from `~/.config/qui/config.yaml` (or `$QUI_CONFIG`).
+/// Every field is optional so an empty or missing file yields the defaults.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// File that mutating actions are appended to, in addition to the Activity view
+    pub activity_log: Option<PathBuf>,
+    /// HTTP proxy used to reach the API server, overriding any kubeconfig setting
+    pub proxy_url: Option<String>,
+    /// User to impersonate on every request (like `kubectl --as`)
+    pub impersonate: Option<String>,
+    /// Groups to impersonate on every request (like `kubectl --as-group`)
+    pub impersonate_groups: Vec<String>,
+    /// Bearer token file (e.g. a mounted service account token) used instead of
+    /// the kubeconfig user's credentials; reread as it rotates
+    pub token_file: Option<PathBuf>,
+    /// Template for the resource reference copied with `c`; `{namespace}`,
+    /// `{kind}` and `{name}` are substituted
+    pub copy_format: Option<String>,
+    /// Time zone for absolute timestamps; relative ages are unaffected
+    pub timezone: DisplayTimeZone,
+    /// Ask before switching kubeconfig context, so a stray Enter in the
+    /// Clusters view can't land you in production
+    pub confirm_context_switch: bool,
+    /// View to land on once connected, by the name the `:` switcher uses
+    /// (`deployments`, `svc`, ...); unknown names fall back to Pods
+    pub start_view: Option<String>,
+    /// What the Pods STATUS column shows, toggled with `S`
+    pub pod_status: PodStatusDisplay,
+    /// Color preset to start with; `T` cycles through them while running
+    pub theme: ThemePreset,
+    pub age_colors: AgeColorsConfig,
+    pub favorites: FavoritesConfig,
+    pub namespaces: NamespaceFilterConfig,
+    pub logs: LogsConfig,
+    pub terminal: TerminalConfig,
+    pub connection_loss: ConnectionLossConfig,
+    pub pods: TableConfig,
+    pub deployments: TableConfig,
+    pub services: TableConfig,
+    pub events: EventsConfig,
+    /// File the settings were loaded from and are saved back to; the default
+    /// location when unset
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DisplayTimeZone {
+    #[default]
+    Utc,
+    Local,
+}
+
+impl DisplayTimeZone {
+    /// Format a timestamp in this zone; `%Z` renders as `UTC` or the local offset
+    pub fn format(&self, time: &DateTime<Utc>, format: &str) -> String {
+        match self {
+            DisplayTimeZone::Utc => time.format(format).to_string(),
+            DisplayTimeZone::Local => time.with_timezone(&Local).format(format).to_string(),
+        }
+    }
+}
+
+/// The pod phase as the API reports it, or the status kubectl computes from the
+/// containers' states (CrashLoopBackOff, Init:0/1, Terminating, ...)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PodStatusDisplay {
+    #[default]
+    Phase,
+    Computed,
+}
+
+/// Built-in color presets. Each recolors the default palette, e.g. for a
+/// light terminal or a washed-out projector.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemePreset {
+    #[default]
+    Default,
+    Light,
+    HighContrast,
+    Monochrome,
+}
+
+impl ThemePreset {
+    /// The preset `T` switches to next, wrapping around
+    pub fn next(self) -> Self {
+        match self {
+            ThemePreset::Default => ThemePreset::Light,
+            ThemePreset::Light => ThemePreset::HighContrast,
+            ThemePreset::HighContrast => ThemePreset::Monochrome,
+            ThemePreset::Monochrome => ThemePreset::Default,
+        }
+    }
+
+    /// Name as written in the config file
+    pub fn name(self) -> &'static str {
+        match self {
+            ThemePreset::Default => "default",
+            ThemePreset::Light => "light",
+            ThemePreset::HighContrast => "high-contrast",
+            ThemePreset::Monochrome => "monochrome",
+        }
+    }
+}
+
+/// Per-view table settings
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TableConfig {
+    /// Column keys to show, in order; unset means the view's default columns
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub columns: Option<Vec<String>>,
+    /// Relative column widths by key, set with `<`/`>`; unset columns keep their default
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub widths: BTreeMap<String, u16>,
+    /// Order rows are listed in, see `SortKey::parse_list`; unset keeps the API's order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+}
+
+impl TableConfig {
+    pub fn sort_keys(&self) -> Vec<SortKey> {
+        self.sort
+            .as_deref()
+            .map(SortKey::parse_list)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct EventsConfig {
+    /// Like `TableConfig::sort`; unset lists the newest first
+    pub sort: Option<String>,
+}
+
+/// One column of a configured sort order
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortKey {
+    /// Column key, lowercase, as in `columns`
+    pub key: String,
+    pub descending: bool,
+}
+
+impl SortKey {
+    /// Parse `status,-restarts,name`: column keys compared in turn, each
+    /// ascending unless prefixed with `-`. Ages and times compare as
+    /// timestamps, so `-age` lists the newest first.
+    pub fn parse_list(spec: &str) -> Vec<SortKey> {
+        spec.split(',')
+            .map(str::trim)
+            .filter(|key| !key.is_empty())
+            .map(|key| match key.strip_prefix('-') {
+                Some(key) => SortKey {
+                    key: key.trim().to_lowercase(),
+                    descending: true,
+                },
+                None => SortKey {
+                    key: key.to_lowercase(),
+                    descending: false,
+                },
+            })
+            .collect()
+    }
+}
+
+/// Namespaces and contexts pinned to the top of their views, toggled with `f`
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FavoritesConfig {
+    pub namespaces: Vec<String>,
+    pub contexts: Vec<String>,
+}
+
+/// Which namespaces are listed and searched, for clusters with too many to browse.
+/// Both are optional; when both are set a namespace has to pass both.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct NamespaceFilterConfig {
+    /// Namespace names to list; empty means all
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+    /// Label selector passed to the namespace list, e.g. `team=mine`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label_selector: Option<String>,
+    /// Leave system namespaces out of the Namespaces view and search, toggled with `s`
+    pub hide_system: bool,
+    /// Name prefixes that make a namespace a system one
+    pub system_prefixes: Vec<String>,
+}
+
+impl Default for NamespaceFilterConfig {
+    fn default() -> Self {
+        Self {
+            allow: Vec::new(),
+            label_selector: None,
+            hide_system: false,
+            system_prefixes: vec!["kube-".to_string()],
+        }
+    }
+}
+
+impl NamespaceFilterConfig {
+    pub fn is_active(&self) -> bool {
+        !self.allow.is_empty() || self.label_selector.is_some()
+    }
+
+    /// Whether `namespace` passes the allowlist (the label selector is applied server side)
+    pub fn allows(&self, namespace: &str) -> bool {
+        self.allow.is_empty() || self.allow.iter().any(|ns| ns == namespace)
+    }
+
+    /// Whether `namespace` is hidden as a system namespace right now
+    pub fn hides(&self, namespace: &str) -> bool {
+        self.hide_system
+            && self
+                .system_prefixes
+                .iter()
+                .any(|prefix| namespace.starts_with(prefix.as_str()))
+    }
+}
+
+/// Thresholds for coloring the AGE column
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AgeColorsConfig {
+    /// Resources younger than this many minutes are highlighted as new
+    pub new_minutes: i64,
+    /// Resources older than this many days are dimmed
+    pub old_days: i64,
+}
+
+impl Default for AgeColorsConfig {
+    fn default() -> Self {
+        Self {
+            new_minutes: 5,
+            old_days: 30,
+        }
+    }
+}
+
+impl AgeColorsConfig {
+    /// How a resource created at `created` should stand out, if at all
+    pub fn classify(&self, created: &DateTime<Utc>) -> AgeClass {
+        let age = Utc::now().signed_duration_since(*created);
+        if age < chrono::Duration::minutes(self.new_minutes) {
+            AgeClass::New
+        } else if age > chrono::Duration::days(self.old_days) {
+            AgeClass::Old
+        } else {
+            AgeClass::Normal
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AgeClass {
+    New,
+    Normal,
+    Old,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LogsConfig {
+    /// Highlight lines that arrived in the latest follow-mode refresh
+    pub highlight_new_lines: bool,
+    /// Show the time each line was logged, in the configured time zone
+    pub timestamps: bool,
+    /// How many lines to request from the end of the log
+    pub tail_lines: i64,
+    /// Most lines kept in memory; older ones are dropped first
+    pub max_lines: usize,
+    /// Program `o` pipes the log into; `$PAGER`, else `less`, when unset
+    pub pager: Option<String>,
+}
+
+impl Default for LogsConfig {
+    fn default() -> Self {
+        Self {
+            highlight_new_lines: true,
+            timestamps: false,
+            tail_lines: 100,
+            max_lines: 10_000,
+            pager: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ConnectionLossConfig {
+    /// Consecutive failed requests (timeouts, unreachable API server) before the
+    /// cluster counts as lost and a banner warns that the data is stale
+    pub failures: u32,
+    /// Quit this many seconds after the cluster is lost, unless it comes back
+    /// first; unset to keep running
+    pub exit_after_secs: Option<u64>,
+}
+
+impl Default for ConnectionLossConfig {
+    fn default() -> Self {
+        Self {
+            failures: 3,
+            exit_after_secs: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct TerminalConfig {
+    /// Probe the pod for an installed shell before exec'ing instead of trying
+    /// bash and falling back to sh. Costs one extra exec per image (cached).
+    pub detect_shell: bool,
+}
+
+impl Config {
+    fn get_config_path() -> PathBuf {
+        if let Ok(path) = std::env::var("QUI_CONFIG") {
+            PathBuf::from(path)
+        } else {
+            let mut home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+            home.push(".config");
+            home.push("qui");
+            home.push("config.yaml");
+            home
+        }
+    }
+
+    pub fn load() -> Result<Self> {
+        Self::load_from(Self::get_config_path())
+    }
+
+    /// Read the settings from `path` rather than the default location, and save
+    /// them back there
+    pub fn load_from(path: PathBuf) -> Result<Self> {
+        let content = if path.exists() {
+            fs::read_to_string(&path)?
+        } else {
+            String::new()
+        };
+        let mut config = if content.trim().is_empty() {
+            Self::default()
+        } else {
+            serde_yaml::from_str::<Config>(&content)?
+        };
+        config.activity_log = config.activity_log.map(expand_home);
+        config.token_file = config.token_file.map(expand_home);
+        config.path = Some(path);
+        Ok(config)
+    }
+
+    fn file_path(&self) -> PathBuf {
+        self.path.clone().unwrap_or_else(Self::get_config_path)
+    }
+
+    /// Write the favorites back to the config file, leaving the other settings
+    /// as they are
+    pub fn save_favorites(&self) -> Result<()> {
+        self.save_sections(&[("favorites", serde_yaml::to_value(&self.favorites)?)])
+    }
+
+    /// Write the namespace settings, including whether system ones are hidden,
+    /// back to the config file
+    pub fn save_namespace_filter(&self) -> Result<()> {
+        self.save_sections(&[("namespaces", serde_yaml::to_value(&self.namespaces)?)])
+    }
+
+    /// Write the pod status display choice back to the config file
+    pub fn save_pod_status(&self) -> Result<()> {
+        self.save_sections(&[("pod_status", serde_yaml::to_value(self.pod_status)?)])
+    }
+
+    /// Write the table settings (columns and widths) back to the config file
+    pub fn save_tables(&self) -> Result<()> {
+        self.save_sections(&[
+            ("pods", serde_yaml::to_value(&self.pods)?),
+            ("deployments", serde_yaml::to_value(&self.deployments)?),
+            ("services", serde_yaml::to_value(&self.services)?),
+        ])
+    }
+
+    /// Replace top-level keys in the config file. The file is re-serialized, so
+    /// comments in it are not kept.
+    fn save_sections(&self, sections: &[(&str, serde_yaml::Value)]) -> Result<()> {
+        let config_path = self.file_path();
+        let mut document = match fs::read_to_string(&config_path) {
+            Ok(content) if !content.trim().is_empty() => serde_yaml::from_str(&content)?,
+            _ => serde_yaml::Value::Mapping(Default::default()),
+        };
+
+        let mapping = document
+            .as_mapping_mut()
+            .ok_or_else(|| anyhow!("{} is not a YAML mapping", config_path.display()))?;
+        for (key, value) in sections {
+            mapping.insert((*key).into(), value.clone());
+        }
+
+        if let Some(parent) = config_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&config_path, serde_yaml::to_string(&document)?)?;
+        Ok(())
+    }
+
+    /// Reference to a resource in the configured copy format, kubectl-ready by default
+    pub fn format_reference(&self, namespace: &str, kind: &str, name: &str) -> String {
+        self.copy_format
+            .as_deref()
+            .unwrap_or("-n {namespace} {kind}/{name}")
+            .replace("{namespace}", namespace)
+            .replace("{kind}", kind)
+            .replace("{name}", name)
+    }
+
+    /// Human readable identity being impersonated, if any, for display in the header
+    pub fn impersonation_label(&self) -> Option<String> {
+        match (&self.impersonate, self.impersonate_groups.is_empty()) {
+            (None, true) => None,
+            (Some(user), true) => Some(user.clone()),
+            (user, false) => Some(format!(
+                "{} [{}]",
+                user.as_deref().unwrap_or("(self)"),
+                self.impersonate_groups.join(",")
+            )),
+        }
+    }
+}
+
+/// Expand a leading `~/` so paths in the config file can be written like in a shell
+fn expand_home(path: PathBuf) -> PathBuf {
+    match path.strip_prefix("~") {
+        Ok(rest) => dirs::home_dir().map(|home| home.join(rest)).unwrap_or(path),
+        Err(_) => path,
+    }
+}
@@ -0,0 +1,98 @@
+use thiserror::Error;
+
+/// Categories of client-layer failures the UI reacts to differently: retry a
+/// timeout, explain a permission problem, shrug off something already gone.
+///
+/// `KubeApi` methods still return `anyhow::Result`; `QuiError::classify` finds
+/// the category in an error's chain, whether it started as a `QuiError` or as a
+/// `kube::Error` from the API.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum QuiError {
+    #[error("not found: {0}")]
+    NotFound(String),
+    #[error("forbidden: {0}")]
+    Forbidden(String),
+    #[error("unauthorized: {0}")]
+    Unauthorized(String),
+    #[error("conflict: {0}")]
+    Conflict(String),
+    #[error("timed out: {0}")]
+    Timeout(String),
+    #[error("API server unreachable: {0}")]
+    Unreachable(String),
+    #[error("Not connected to a cluster. Select a context in the Clusters view (4).")]
+    NotConnected,
+    #[error("{0}")]
+    Other(String),
+}
+
+impl QuiError {
+    /// Category of `err`, from the first `QuiError` or `kube::Error` in its chain
+    pub fn classify(err: &anyhow::Error) -> QuiError {
+        for cause in err.chain() {
+            if let Some(err) = cause.downcast_ref::<QuiError>() {
+                return err.clone();
+            }
+            if let Some(err) = cause.downcast_ref::<kube::Error>() {
+                return QuiError::from(err);
+            }
+        }
+        QuiError::Other(err.to_string())
+    }
+
+    /// Worth trying again without changing anything
+    pub fn is_transient(&self) -> bool {
+        matches!(self, QuiError::Timeout(_) | QuiError::Unreachable(_))
+    }
+
+    /// What the user can do about it, where there's something more useful to
+    /// say than the API's message
+    pub fn hint(&self) -> Option<&'static str> {
+        match self {
+            QuiError::Forbidden(_) => {
+                Some("your user lacks RBAC permission for this; ask for access or switch context")
+            }
+            QuiError::Unauthorized(_) => {
+                Some("credentials were rejected; they may have expired, try logging in again")
+            }
+            QuiError::Conflict(_) => Some("it changed meanwhile; refresh and try again"),
+            QuiError::Timeout(_) | QuiError::Unreachable(_) => {
+                Some("the cluster may be slow or down; refresh (r) to retry")
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<&kube::Error> for QuiError {
+    fn from(err: &kube::Error) -> Self {
+        match err {
+            kube::Error::Api(response) => {
+                let message = response.message.clone();
+                match response.code {
+                    404 => QuiError::NotFound(message),
+                    403 => QuiError::Forbidden(message),
+                    401 => QuiError::Unauthorized(message),
+                    409 => QuiError::Conflict(message),
+                    408 | 504 => QuiError::Timeout(message),
+                    _ => QuiError::Other(message),
+                }
+            }
+            kube::Error::HyperError(_) | kube::Error::Service(_) => {
+                let message = err.to_string();
+                if message.contains("timed out") || message.contains("elapsed") {
+                    QuiError::Timeout(message)
+                } else {
+                    QuiError::Unreachable(message)
+                }
+            }
+            _ => QuiError::Other(err.to_string()),
+        }
+    }
+}
+
+impl From<kube::Error> for QuiError {
+    fn from(err: kube::Error) -> Self {
+        QuiError::from(&err)
+    }
+}
@@ -2,6 +2,7 @@ use anyhow::Result;
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
 use std::time::Duration;
 
+#[derive(Default)]
 pub struct EventHandler;
 
 impl EventHandler {
@@ -9,10 +10,13 @@ impl EventHandler {
         Self
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Result<Option<InputEvent>> {
         if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                return Ok(Some(InputEvent::Key(key)));
+            match event::read()? {
+                Event::Key(key) => return Ok(Some(InputEvent::Key(key))),
+                Event::Resize(cols, rows) => return Ok(Some(InputEvent::Resize { cols, rows })),
+                _ => {}
             }
         }
         Ok(None)
@@ -22,18 +26,26 @@ impl EventHandler {
 #[derive(Debug, Clone, Copy)]
 pub enum InputEvent {
     Key(KeyEvent),
+    /// The terminal window changed size; the new one is in columns and rows
+    Resize {
+        cols: u16,
+        rows: u16,
+    },
 }
 
 impl InputEvent {
+    /// The key pressed; `KeyCode::Null` for events that aren't key presses
     pub fn key_code(&self) -> KeyCode {
         match self {
             InputEvent::Key(key) => key.code,
+            InputEvent::Resize { .. } => KeyCode::Null,
         }
     }
 
     pub fn modifiers(&self) -> KeyModifiers {
         match self {
             InputEvent::Key(key) => key.modifiers,
+            InputEvent::Resize { .. } => KeyModifiers::NONE,
         }
     }
 }
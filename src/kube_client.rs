@@ -1,21 +1,37 @@
+use crate::config::NamespaceFilterConfig;
+use crate::error::QuiError;
 use anyhow::Result;
+use async_trait::async_trait;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
 use crossterm::event::{KeyCode, KeyModifiers};
-use futures::TryStreamExt;
-use k8s_openapi::api::apps::v1::Deployment;
-use k8s_openapi::api::core::v1::{Namespace, Pod, Service};
+use futures::{AsyncBufReadExt, AsyncReadExt, TryStreamExt};
+use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet};
+use k8s_openapi::api::batch::v1::Job;
+use k8s_openapi::api::core::v1::{
+    Affinity, ConfigMap, Container, ContainerState, ContainerStatus, Endpoints, Event, Namespace,
+    Node, NodeSelectorTerm, Pod, PodAffinityTerm, Probe, Secret, Service, Toleration, Volume,
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 use kube::{
-    api::{Api, DeleteParams, ListParams, LogParams},
+    api::{
+        Api, ApiResource, DeleteParams, DynamicObject, GroupVersionKind, ListParams, LogParams,
+        ObjectList, PartialObjectMeta, Patch, PatchParams, PropagationPolicy,
+    },
+    config::AuthInfo,
     runtime::{watcher, WatchStreamExt},
-    Client,
+    Client, Config,
 };
 use portable_pty::{CommandBuilder, NativePtySystem, PtySize, PtySystem};
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::io::{Read, Write};
-use std::path::PathBuf;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
 use std::thread;
 use tokio::sync::mpsc as tokio_mpsc;
 use vt100::Parser;
@@ -26,6 +42,8 @@ struct KubeConfig {
     current_context: String,
     contexts: Vec<ContextEntry>,
     clusters: Vec<ClusterEntry>,
+    #[serde(default)]
+    users: Vec<UserEntry>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -39,6 +57,8 @@ struct ContextDetail {
     cluster: String,
     #[serde(default)]
     namespace: String,
+    #[serde(default)]
+    user: String,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -48,8 +68,97 @@ struct ClusterEntry {
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 struct ClusterDetail {
     server: String,
+    #[serde(default)]
+    insecure_skip_tls_verify: bool,
+    certificate_authority: Option<String>,
+    certificate_authority_data: Option<String>,
+    tls_server_name: Option<String>,
+    proxy_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct UserEntry {
+    name: String,
+    #[serde(default)]
+    user: UserDetail,
+}
+
+/// Only which credentials are present matters here; secret values are never displayed
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct UserDetail {
+    token: Option<String>,
+    token_file: Option<String>,
+    client_certificate: Option<String>,
+    client_certificate_data: Option<String>,
+    username: Option<String>,
+    exec: Option<ExecDetail>,
+    auth_provider: Option<AuthProviderDetail>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ExecDetail {
+    command: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct AuthProviderDetail {
+    name: String,
+}
+
+/// The kubeconfig files in play and what they merge into, for diagnosing
+/// contexts that don't show up as expected
+#[derive(Debug, Clone)]
+pub struct EffectiveKubeconfig {
+    /// Each file in merge order, and whether it exists; missing ones are skipped
+    pub sources: Vec<(PathBuf, bool)>,
+    /// The merged kubeconfig as YAML, credentials redacted
+    pub yaml: String,
+}
+
+/// How a context reaches its cluster, for diagnosing TLS and auth failures
+#[derive(Debug, Clone)]
+pub struct ConnectionDetail {
+    pub context: String,
+    pub cluster: String,
+    pub user: String,
+    pub server: String,
+    pub tls_verify: bool,
+    pub ca_source: String,
+    pub tls_server_name: Option<String>,
+    pub proxy_url: Option<String>,
+    pub auth_methods: Vec<String>,
+}
+
+impl UserDetail {
+    /// Describe each configured credential without revealing it
+    fn auth_methods(&self) -> Vec<String> {
+        let mut methods = Vec::new();
+        if let Some(exec) = &self.exec {
+            methods.push(format!("exec plugin ({})", exec.command));
+        }
+        if let Some(provider) = &self.auth_provider {
+            methods.push(format!("auth provider ({})", provider.name));
+        }
+        if self.token.is_some() {
+            methods.push("bearer token (inline, redacted)".to_string());
+        }
+        if let Some(path) = &self.token_file {
+            methods.push(format!("bearer token from {}", path));
+        }
+        if let Some(path) = &self.client_certificate {
+            methods.push(format!("client certificate from {}", path));
+        } else if self.client_certificate_data.is_some() {
+            methods.push("client certificate (inline, redacted)".to_string());
+        }
+        if let Some(username) = &self.username {
+            methods.push(format!("basic auth as {} (password redacted)", username));
+        }
+        methods
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -61,18 +170,565 @@ pub struct ContextInfo {
     pub is_current: bool,
 }
 
+/// Resource kinds the list views show, for actions that work on any of them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResourceKind {
+    Pod,
+    Deployment,
+    Service,
+}
+
+impl ResourceKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResourceKind::Pod => "pod",
+            ResourceKind::Deployment => "deployment",
+            ResourceKind::Service => "service",
+        }
+    }
+}
+
+/// What happens to a deleted object's dependents, e.g. a deployment's pods
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DeletePropagation {
+    /// Delete the object now and its dependents afterwards (the usual default)
+    #[default]
+    Background,
+    /// Wait until the dependents are gone before deleting the object
+    Foreground,
+    /// Leave the dependents running, no longer owned by anything
+    Orphan,
+}
+
+impl DeletePropagation {
+    pub const ALL: [DeletePropagation; 3] = [
+        DeletePropagation::Background,
+        DeletePropagation::Foreground,
+        DeletePropagation::Orphan,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeletePropagation::Background => "Background",
+            DeletePropagation::Foreground => "Foreground",
+            DeletePropagation::Orphan => "Orphan",
+        }
+    }
+
+    fn delete_params(self) -> DeleteParams {
+        let policy = match self {
+            DeletePropagation::Background => PropagationPolicy::Background,
+            DeletePropagation::Foreground => PropagationPolicy::Foreground,
+            DeletePropagation::Orphan => PropagationPolicy::Orphan,
+        };
+        DeleteParams {
+            propagation_policy: Some(policy),
+            ..DeleteParams::default()
+        }
+    }
+}
+
+/// Cluster operations the app performs. `KubeClient` talks to the API server;
+/// tests substitute a canned implementation so the event handling can run
+/// without a cluster.
+#[async_trait]
+pub trait KubeApi: Send + Sync {
+    /// How long the API server takes to answer a trivial request
+    async fn ping(&self) -> Result<std::time::Duration>;
+    async fn list_namespaces(&self) -> Result<Vec<String>>;
+    async fn list_pods(&self, namespace: &str) -> Result<Vec<PodInfo>>;
+    async fn watch_pods(&self, namespace: &str) -> Result<PodWatcher>;
+    async fn describe_pod(&self, namespace: &str, name: &str) -> Result<PodDetail>;
+    /// Current usage per container from metrics-server; None when the metrics
+    /// API isn't served or has no sample for the pod yet
+    async fn pod_metrics(&self, namespace: &str, name: &str)
+        -> Result<Option<Vec<ContainerUsage>>>;
+    async fn describe_node(&self, name: &str) -> Result<NodeDetail>;
+    async fn delete_pod(&self, namespace: &str, name: &str) -> Result<()>;
+    async fn get_pod_logs(&self, namespace: &str, name: &str, query: &LogQuery) -> Result<String>;
+    /// Follow each of `containers` from its last `tail_lines` lines, merged as they
+    /// arrive, like `kubectl logs -f --all-containers --prefix`
+    async fn follow_pod_logs(
+        &self,
+        namespace: &str,
+        name: &str,
+        containers: &[String],
+        tail_lines: i64,
+        timestamps: bool,
+    ) -> Result<PodLogWatcher>;
+    async fn list_deployments(&self, namespace: &str) -> Result<Vec<DeploymentInfo>>;
+    async fn delete_deployment(
+        &self,
+        namespace: &str,
+        name: &str,
+        propagation: DeletePropagation,
+    ) -> Result<()>;
+    async fn scale_deployment(&self, namespace: &str, name: &str, replicas: i32) -> Result<()>;
+    async fn watch_rollout(&self, namespace: &str, name: &str) -> Result<RolloutWatcher>;
+    async fn describe_deployment(&self, namespace: &str, name: &str) -> Result<DeploymentDetail>;
+    /// Revisions kept in the deployment's ReplicaSets, like `kubectl rollout history`
+    async fn deployment_history(&self, namespace: &str, name: &str) -> Result<DeploymentHistory>;
+    /// Put `revision`'s pod template back, like `kubectl rollout undo --to-revision`
+    async fn rollback_deployment(&self, namespace: &str, name: &str, revision: i64) -> Result<()>;
+    async fn set_deployment_env(
+        &self,
+        namespace: &str,
+        name: &str,
+        container: &str,
+        variable: &str,
+        value: &str,
+    ) -> Result<()>;
+    async fn list_services(&self, namespace: &str) -> Result<Vec<ServiceInfo>>;
+    async fn describe_service(&self, namespace: &str, name: &str) -> Result<ServiceDetail>;
+    async fn list_events(&self, namespace: &str) -> Result<Vec<EventInfo>>;
+    async fn list_configmaps(&self, namespace: &str) -> Result<Vec<ConfigMapInfo>>;
+    async fn describe_configmap(&self, namespace: &str, name: &str) -> Result<ConfigMapDetail>;
+    async fn list_secrets(&self, namespace: &str) -> Result<Vec<SecretInfo>>;
+    async fn describe_secret(&self, namespace: &str, name: &str) -> Result<SecretDetail>;
+    /// Stream the namespace's events as they're created or updated
+    async fn watch_events(&self, namespace: &str) -> Result<EventWatcher>;
+    async fn count_recent_warnings(&self, namespace: &str) -> Result<usize>;
+    async fn namespace_summary(&self, namespace: &str) -> Result<NamespaceSummary>;
+    async fn namespace_pod_summary(&self, namespace: &str) -> Result<PodSummary>;
+    async fn get_resource_json(
+        &self,
+        kind: ResourceKind,
+        namespace: &str,
+        name: &str,
+    ) -> Result<serde_json::Value>;
+    async fn search_resources(&self, query: &str) -> Result<Vec<SearchResult>>;
+    /// Output of `kubectl describe <kind> <name>`, for what the native detail
+    /// views don't model yet
+    async fn kubectl_describe(&self, kind: &str, namespace: &str, name: &str) -> Result<String>;
+    /// Listen on `local_port` (0 for any free one) on 127.0.0.1 and tunnel each
+    /// connection to `remote_port` on the pod, like `kubectl port-forward`
+    async fn port_forward(
+        &self,
+        namespace: &str,
+        pod: &str,
+        local_port: u16,
+        remote_port: u16,
+    ) -> Result<PortForwardHandle>;
+    /// Set labels (`Some`) or remove them (`None`) with a strategic-merge patch
+    async fn patch_labels(
+        &self,
+        kind: ResourceKind,
+        namespace: &str,
+        name: &str,
+        labels: &BTreeMap<String, Option<String>>,
+    ) -> Result<()>;
+    /// Mark a node unschedulable, like `kubectl cordon`
+    async fn cordon_node(&self, node: &str) -> Result<()>;
+    /// Names of the Jobs in a namespace that finished successfully
+    async fn list_completed_jobs(&self, namespace: &str) -> Result<Vec<String>>;
+    /// Delete a Job and, in the background, the pods it created
+    async fn delete_job(&self, namespace: &str, name: &str) -> Result<()>;
+    /// Like `patch_labels`, for annotations
+    async fn patch_annotations(
+        &self,
+        kind: ResourceKind,
+        namespace: &str,
+        name: &str,
+        annotations: &BTreeMap<String, Option<String>>,
+    ) -> Result<()>;
+    /// Add an ephemeral debug container running a shell to a pod, sharing the
+    /// process namespace of `target` if given. Returns the new container's name
+    /// without waiting for it to start.
+    async fn add_debug_container(
+        &self,
+        namespace: &str,
+        pod: &str,
+        target: Option<&str>,
+    ) -> Result<String>;
+    /// Whether the ephemeral container runs yet. An error once it has exited or
+    /// can't start, e.g. because its image can't be pulled.
+    async fn debug_container_running(
+        &self,
+        namespace: &str,
+        pod: &str,
+        container: &str,
+    ) -> Result<bool>;
+}
+
+/// Stand-in client while no cluster is connected, e.g. before a context has
+/// been chosen. Every call fails with the same explanation.
+pub struct DisconnectedClient;
+
+fn not_connected<T>() -> Result<T> {
+    Err(QuiError::NotConnected.into())
+}
+
+#[async_trait]
+impl KubeApi for DisconnectedClient {
+    async fn ping(&self) -> Result<std::time::Duration> {
+        not_connected()
+    }
+    async fn list_namespaces(&self) -> Result<Vec<String>> {
+        not_connected()
+    }
+    async fn list_pods(&self, _namespace: &str) -> Result<Vec<PodInfo>> {
+        not_connected()
+    }
+    async fn watch_pods(&self, _namespace: &str) -> Result<PodWatcher> {
+        not_connected()
+    }
+    async fn describe_pod(&self, _namespace: &str, _name: &str) -> Result<PodDetail> {
+        not_connected()
+    }
+    async fn pod_metrics(
+        &self,
+        _namespace: &str,
+        _name: &str,
+    ) -> Result<Option<Vec<ContainerUsage>>> {
+        not_connected()
+    }
+    async fn describe_node(&self, _name: &str) -> Result<NodeDetail> {
+        not_connected()
+    }
+    async fn delete_pod(&self, _namespace: &str, _name: &str) -> Result<()> {
+        not_connected()
+    }
+    async fn get_pod_logs(
+        &self,
+        _namespace: &str,
+        _name: &str,
+        _query: &LogQuery,
+    ) -> Result<String> {
+        not_connected()
+    }
+    async fn follow_pod_logs(
+        &self,
+        _namespace: &str,
+        _name: &str,
+        _containers: &[String],
+        _tail_lines: i64,
+        _timestamps: bool,
+    ) -> Result<PodLogWatcher> {
+        not_connected()
+    }
+    async fn list_deployments(&self, _namespace: &str) -> Result<Vec<DeploymentInfo>> {
+        not_connected()
+    }
+    async fn delete_deployment(
+        &self,
+        _namespace: &str,
+        _name: &str,
+        _propagation: DeletePropagation,
+    ) -> Result<()> {
+        not_connected()
+    }
+    async fn scale_deployment(&self, _namespace: &str, _name: &str, _replicas: i32) -> Result<()> {
+        not_connected()
+    }
+    async fn watch_rollout(&self, _namespace: &str, _name: &str) -> Result<RolloutWatcher> {
+        not_connected()
+    }
+    async fn describe_deployment(&self, _namespace: &str, _name: &str) -> Result<DeploymentDetail> {
+        not_connected()
+    }
+    async fn deployment_history(&self, _namespace: &str, _name: &str) -> Result<DeploymentHistory> {
+        not_connected()
+    }
+    async fn rollback_deployment(
+        &self,
+        _namespace: &str,
+        _name: &str,
+        _revision: i64,
+    ) -> Result<()> {
+        not_connected()
+    }
+    async fn set_deployment_env(
+        &self,
+        _namespace: &str,
+        _name: &str,
+        _container: &str,
+        _variable: &str,
+        _value: &str,
+    ) -> Result<()> {
+        not_connected()
+    }
+    async fn list_services(&self, _namespace: &str) -> Result<Vec<ServiceInfo>> {
+        not_connected()
+    }
+    async fn describe_service(&self, _namespace: &str, _name: &str) -> Result<ServiceDetail> {
+        not_connected()
+    }
+    async fn list_events(&self, _namespace: &str) -> Result<Vec<EventInfo>> {
+        not_connected()
+    }
+    async fn list_configmaps(&self, _namespace: &str) -> Result<Vec<ConfigMapInfo>> {
+        not_connected()
+    }
+    async fn describe_configmap(&self, _namespace: &str, _name: &str) -> Result<ConfigMapDetail> {
+        not_connected()
+    }
+    async fn list_secrets(&self, _namespace: &str) -> Result<Vec<SecretInfo>> {
+        not_connected()
+    }
+    async fn describe_secret(&self, _namespace: &str, _name: &str) -> Result<SecretDetail> {
+        not_connected()
+    }
+    async fn watch_events(&self, _namespace: &str) -> Result<EventWatcher> {
+        not_connected()
+    }
+    async fn count_recent_warnings(&self, _namespace: &str) -> Result<usize> {
+        not_connected()
+    }
+    async fn namespace_summary(&self, _namespace: &str) -> Result<NamespaceSummary> {
+        not_connected()
+    }
+    async fn namespace_pod_summary(&self, _namespace: &str) -> Result<PodSummary> {
+        not_connected()
+    }
+    async fn kubectl_describe(&self, _kind: &str, _namespace: &str, _name: &str) -> Result<String> {
+        not_connected()
+    }
+    async fn port_forward(
+        &self,
+        _namespace: &str,
+        _pod: &str,
+        _local_port: u16,
+        _remote_port: u16,
+    ) -> Result<PortForwardHandle> {
+        not_connected()
+    }
+    async fn get_resource_json(
+        &self,
+        _kind: ResourceKind,
+        _namespace: &str,
+        _name: &str,
+    ) -> Result<serde_json::Value> {
+        not_connected()
+    }
+    async fn search_resources(&self, _query: &str) -> Result<Vec<SearchResult>> {
+        not_connected()
+    }
+    async fn patch_labels(
+        &self,
+        _kind: ResourceKind,
+        _namespace: &str,
+        _name: &str,
+        _labels: &BTreeMap<String, Option<String>>,
+    ) -> Result<()> {
+        not_connected()
+    }
+    async fn cordon_node(&self, _node: &str) -> Result<()> {
+        not_connected()
+    }
+    async fn list_completed_jobs(&self, _namespace: &str) -> Result<Vec<String>> {
+        not_connected()
+    }
+    async fn delete_job(&self, _namespace: &str, _name: &str) -> Result<()> {
+        not_connected()
+    }
+    async fn patch_annotations(
+        &self,
+        _kind: ResourceKind,
+        _namespace: &str,
+        _name: &str,
+        _annotations: &BTreeMap<String, Option<String>>,
+    ) -> Result<()> {
+        not_connected()
+    }
+    async fn add_debug_container(
+        &self,
+        _namespace: &str,
+        _pod: &str,
+        _target: Option<&str>,
+    ) -> Result<String> {
+        not_connected()
+    }
+    async fn debug_container_running(
+        &self,
+        _namespace: &str,
+        _pod: &str,
+        _container: &str,
+    ) -> Result<bool> {
+        not_connected()
+    }
+}
+
+/// Which part of a pod's log to fetch
+#[derive(Debug, Clone)]
+pub struct LogQuery {
+    /// Container to read; required by the API once a pod has more than one
+    pub container: Option<String>,
+    pub previous: bool,
+    pub tail_lines: i64,
+    /// Prefix each line with its RFC 3339 timestamp (always UTC from the API)
+    pub timestamps: bool,
+}
+
+/// Health at a glance for a namespace, shown before switching into it.
+/// `None` means that part couldn't be listed (usually RBAC).
+#[derive(Debug, Clone)]
+pub struct NamespaceSummary {
+    pub namespace: String,
+    /// Pod count per status (phase)
+    pub pod_statuses: Option<BTreeMap<String, usize>>,
+    pub deployments: Option<usize>,
+    pub services: Option<usize>,
+    pub config_maps: Option<usize>,
+    /// Warning events from the last hour, most recent first
+    pub warnings: Option<Vec<EventInfo>>,
+}
+
+/// Pods per phase in a namespace, for headers and badges that need the counts
+/// without listing pods themselves. Pods in any other phase aren't counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PodSummary {
+    pub running: usize,
+    pub pending: usize,
+    pub failed: usize,
+    pub succeeded: usize,
+}
+
+impl PodSummary {
+    pub fn from_pods(pods: &[PodInfo]) -> Self {
+        let mut summary = Self::default();
+        for pod in pods {
+            match pod.status.as_str() {
+                "Running" => summary.running += 1,
+                "Pending" => summary.pending += 1,
+                "Failed" => summary.failed += 1,
+                "Succeeded" => summary.succeeded += 1,
+                _ => {}
+            }
+        }
+        summary
+    }
+}
+
+/// A resource found by the all-namespaces search
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    pub kind: ResourceKind,
+    pub namespace: String,
+    pub name: String,
+}
+
 #[derive(Clone)]
 pub struct KubeClient {
     client: Client,
+    namespace_filter: NamespaceFilterConfig,
+    tls_verification_disabled: bool,
+    // `--as`/`--as-group` for kubectl, so it acts as the same identity as the client
+    kubectl_identity: Vec<String>,
 }
 
 impl KubeClient {
-    pub async fn new() -> Result<Self> {
-        let client = Client::try_default().await?;
-        Ok(Self { client })
+    /// Build a client from the ambient kubeconfig (or the pod's service account
+    /// when running in a cluster), applying the proxy, token file and
+    /// impersonation overrides from the QUI config file
+    pub async fn new(settings: &crate::config::Config) -> Result<Self> {
+        let mut config = Config::infer().await?;
+
+        if let Some(proxy_url) = &settings.proxy_url {
+            config.proxy_url = Some(proxy_url.parse()?);
+        }
+        // The token replaces the user's credentials outright; kube would otherwise
+        // prefer an inline token, client certificate or exec plugin over it
+        if let Some(token_file) = &settings.token_file {
+            config.auth_info = AuthInfo {
+                token_file: Some(token_file.display().to_string()),
+                ..AuthInfo::default()
+            };
+        }
+        if let Some(user) = &settings.impersonate {
+            config.auth_info.impersonate = Some(user.clone());
+        }
+        if !settings.impersonate_groups.is_empty() {
+            config.auth_info.impersonate_groups = Some(settings.impersonate_groups.clone());
+        }
+
+        let mut client = Self::from_config(config)?;
+        client.namespace_filter = settings.namespaces.clone();
+        client.kubectl_identity = kubectl_identity(settings);
+        Ok(client)
+    }
+
+    /// Build a client from an explicit config instead of the ambient kubeconfig,
+    /// e.g. one pointing at a kind cluster or a mock API server in tests
+    pub fn from_config(config: Config) -> Result<Self> {
+        // A cluster's `proxy-url` arrives here too. Only http:// proxies are built in;
+        // kube would silently connect directly for any other scheme
+        if let Some(proxy_url) = &config.proxy_url {
+            if proxy_url.scheme_str() != Some("http") {
+                anyhow::bail!(
+                    "Unsupported proxy URL {}: only http:// proxies are supported",
+                    proxy_url
+                );
+            }
+        }
+        // `insecure-skip-tls-verify` in the kubeconfig arrives here as accept_invalid_certs
+        let tls_verification_disabled = config.accept_invalid_certs;
+        let client = Client::try_from(config)?;
+        Ok(Self {
+            client,
+            namespace_filter: NamespaceFilterConfig::default(),
+            tls_verification_disabled,
+            kubectl_identity: Vec::new(),
+        })
+    }
+
+    /// Whether this client accepts any server certificate, so the UI can warn
+    pub fn tls_verification_disabled(&self) -> bool {
+        self.tls_verification_disabled
+    }
+
+    /// The ReplicaSets a deployment created, one per revision it still keeps
+    async fn owned_replica_sets(
+        &self,
+        namespace: &str,
+        deployment: &Deployment,
+    ) -> Result<Vec<ReplicaSet>> {
+        let api: Api<ReplicaSet> = Api::namespaced(self.client.clone(), namespace);
+        let params = deployment_selector(deployment);
+        let uid = deployment.metadata.uid.as_deref();
+        Ok(api
+            .list(&params)
+            .await?
+            .items
+            .into_iter()
+            .filter(|rs| {
+                rs.metadata
+                    .owner_references
+                    .iter()
+                    .flatten()
+                    .any(|owner| Some(owner.uid.as_str()) == uid)
+            })
+            .collect())
+    }
+
+    /// Strategic-merge `entries` into `metadata.<field>`; a `None` value deletes the key
+    async fn patch_metadata_map(
+        &self,
+        kind: ResourceKind,
+        namespace: &str,
+        name: &str,
+        field: &str,
+        entries: &BTreeMap<String, Option<String>>,
+    ) -> Result<()> {
+        let patch = Patch::Strategic(serde_json::json!({ "metadata": { field: entries } }));
+        let params = PatchParams::default();
+        match kind {
+            ResourceKind::Pod => {
+                let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+                api.patch(name, &params, &patch).await?;
+            }
+            ResourceKind::Deployment => {
+                let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+                api.patch(name, &params, &patch).await?;
+            }
+            ResourceKind::Service => {
+                let api: Api<Service> = Api::namespaced(self.client.clone(), namespace);
+                api.patch(name, &params, &patch).await?;
+            }
+        }
+        Ok(())
     }
 
-    fn get_kubeconfig_path() -> PathBuf {
+    pub fn get_kubeconfig_path() -> PathBuf {
         if let Ok(path) = std::env::var("KUBECONFIG") {
             PathBuf::from(path)
         } else {
@@ -83,6 +739,36 @@ impl KubeClient {
         }
     }
 
+    /// Merge the kubeconfig files like kubectl does: every file in `$KUBECONFIG`
+    /// in order, the first to set a value winning, or else `~/.kube/config`
+    pub fn effective_kubeconfig() -> Result<EffectiveKubeconfig> {
+        let paths: Vec<PathBuf> = match std::env::var_os("KUBECONFIG") {
+            Some(value) => std::env::split_paths(&value)
+                .filter(|p| !p.as_os_str().is_empty())
+                .collect(),
+            None => vec![Self::get_kubeconfig_path()],
+        };
+
+        let mut merged = kube::config::Kubeconfig::default();
+        let mut sources = Vec::new();
+        for path in paths {
+            let exists = path.exists();
+            if exists {
+                let file = kube::config::Kubeconfig::read_from(&path)
+                    .map_err(|e| anyhow::anyhow!("{}: {}", path.display(), e))?;
+                merged = merged.merge(file)?;
+            }
+            sources.push((path, exists));
+        }
+
+        let mut value = serde_json::to_value(&merged)?;
+        redact_credentials(&mut value);
+        Ok(EffectiveKubeconfig {
+            sources,
+            yaml: serde_yaml::to_string(&value)?,
+        })
+    }
+
     pub fn list_contexts() -> Result<Vec<ContextInfo>> {
         let config_path = Self::get_kubeconfig_path();
         let config_content = fs::read_to_string(&config_path)?;
@@ -115,6 +801,73 @@ impl KubeClient {
         Ok(contexts)
     }
 
+    /// Resolve the cluster and user entries a context points at
+    pub fn describe_context(context_name: &str) -> Result<ConnectionDetail> {
+        let config_path = Self::get_kubeconfig_path();
+        let config_content = fs::read_to_string(&config_path)?;
+        let kubeconfig: KubeConfig = serde_yaml::from_str(&config_content)?;
+
+        let context = kubeconfig
+            .contexts
+            .iter()
+            .find(|c| c.name == context_name)
+            .ok_or_else(|| anyhow::anyhow!("Context '{}' not found", context_name))?;
+        let cluster = kubeconfig
+            .clusters
+            .iter()
+            .find(|c| c.name == context.context.cluster)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Cluster '{}' referenced by context '{}' not found",
+                    context.context.cluster,
+                    context_name
+                )
+            })?;
+        let user = kubeconfig
+            .users
+            .iter()
+            .find(|u| u.name == context.context.user);
+
+        let detail = &cluster.cluster;
+        let ca_source = if detail.insecure_skip_tls_verify {
+            "none (verification disabled)".to_string()
+        } else if let Some(path) = &detail.certificate_authority {
+            path.clone()
+        } else if let Some(data) = &detail.certificate_authority_data {
+            format!("inline certificate-authority-data ({} bytes)", data.len())
+        } else {
+            "system trust store".to_string()
+        };
+
+        let auth_methods = match user {
+            Some(user) => user.user.auth_methods(),
+            None if context.context.user.is_empty() => vec![],
+            None => vec![format!(
+                "user '{}' not found in kubeconfig",
+                context.context.user
+            )],
+        };
+
+        Ok(ConnectionDetail {
+            context: context.name.clone(),
+            cluster: cluster.name.clone(),
+            user: context.context.user.clone(),
+            server: detail.server.clone(),
+            tls_verify: !detail.insecure_skip_tls_verify,
+            ca_source,
+            tls_server_name: detail.tls_server_name.clone(),
+            proxy_url: detail.proxy_url.clone(),
+            auth_methods,
+        })
+    }
+
+    /// Whether this runs in a pod, where the client can fall back to the pod's
+    /// service account when there is no kubeconfig
+    pub fn in_cluster() -> bool {
+        std::env::var_os("KUBERNETES_SERVICE_HOST").is_some()
+            && Path::new(SERVICE_ACCOUNT_TOKEN_FILE).exists()
+    }
+
     pub fn get_current_context() -> Result<String> {
         let config_path = Self::get_kubeconfig_path();
         let config_content = fs::read_to_string(&config_path)?;
@@ -137,10 +890,56 @@ impl KubeClient {
         Ok(())
     }
 
-    /// Open a new terminal tab/window with kubectl exec to the specified pod
-    pub fn open_pod_terminal(namespace: &str, pod_name: &str) -> Result<()> {
-        let kubectl_cmd = format!("kubectl exec -it -n {} {} -- env TERM=xterm-256color /bin/bash || kubectl exec -it -n {} {} -- env TERM=xterm-256color /bin/sh",
-            namespace, pod_name, namespace, pod_name);
+    /// Find the first of the usual shells that exists in the pod, with one
+    /// non-interactive exec. Fails if the pod has no `sh` to run the probe.
+    pub fn detect_shell(
+        namespace: &str,
+        pod_name: &str,
+        container: Option<&str>,
+        identity: &[String],
+    ) -> Result<String> {
+        let probe = format!(
+            "for s in {}; do [ -x \"$s\" ] && echo \"$s\" && exit 0; done; exit 1",
+            SHELL_CANDIDATES.join(" ")
+        );
+        let output = Command::new("kubectl")
+            .args(["exec", "-n", namespace, pod_name])
+            .args(container.map(|c| ["-c", c]).into_iter().flatten())
+            .args(identity)
+            .args(["--", "sh", "-c", &probe])
+            .output()?;
+        let shell = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if !output.status.success() || shell.is_empty() {
+            anyhow::bail!("no known shell found in pod {}", pod_name);
+        }
+        Ok(shell)
+    }
+
+    /// Open a new terminal tab/window with kubectl exec to the specified pod,
+    /// using `shell` if known or else trying bash and then sh
+    pub fn open_pod_terminal(
+        namespace: &str,
+        pod_name: &str,
+        container: Option<&str>,
+        shell: Option<&str>,
+        identity: &[String],
+    ) -> Result<()> {
+        let mut target = match container {
+            Some(container) => format!("-n {} {} -c {}", namespace, pod_name, container),
+            None => format!("-n {} {}", namespace, pod_name),
+        };
+        for flag in identity {
+            target.push(' ');
+            target.push_str(flag);
+        }
+        let kubectl_cmd = match shell {
+            Some(shell) => format!(
+                "kubectl exec -it {} -- env TERM=xterm-256color {}",
+                target, shell
+            ),
+            None => format!("kubectl exec -it {} -- env TERM=xterm-256color /bin/bash || kubectl exec -it {} -- env TERM=xterm-256color /bin/sh",
+                target, target),
+        };
 
         // Detect terminal type and open new tab
         #[cfg(target_os = "macos")]
@@ -277,74 +1076,448 @@ end tell"#,
     }
 }
 
+/// A running port forward. The task owns the local listener, so aborting it
+/// (`cancel`, or dropping the handle) frees the port and closes open connections.
+#[derive(Debug)]
+pub struct PortForwardHandle {
+    pub namespace: String,
+    pub pod: String,
+    pub local_addr: SocketAddr,
+    pub remote_port: u16,
+    /// Why the latest connection failed, e.g. nothing listening on the pod's port
+    pub last_error: Arc<Mutex<Option<String>>>,
+    pub task: tokio::task::JoinHandle<()>,
+}
+
+impl PortForwardHandle {
+    pub fn cancel(&self) {
+        self.task.abort();
+    }
+
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error.lock().ok().and_then(|e| e.clone())
+    }
+}
+
+impl Drop for PortForwardHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Accept local connections until aborted, each over its own port-forward stream.
+/// The connections live in a `JoinSet`, so aborting this task aborts them too.
+async fn forward_connections(
+    listener: tokio::net::TcpListener,
+    api: Api<Pod>,
+    pod: String,
+    port: u16,
+    last_error: Arc<Mutex<Option<String>>>,
+) {
+    let mut connections = tokio::task::JoinSet::new();
+    loop {
+        let mut client = match listener.accept().await {
+            Ok((client, _)) => client,
+            Err(e) => {
+                if let Ok(mut last) = last_error.lock() {
+                    *last = Some(format!("accept failed: {}", e));
+                }
+                // e.g. out of file descriptors; don't spin on it
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            }
+        };
+        // Forget connections that have finished
+        while connections.try_join_next().is_some() {}
+
+        let (api, pod, last_error) = (api.clone(), pod.clone(), last_error.clone());
+        connections.spawn(async move {
+            let result = async {
+                let mut forwarder = api.portforward(&pod, &[port]).await?;
+                let mut upstream = forwarder
+                    .take_stream(port)
+                    .ok_or_else(|| anyhow::anyhow!("no stream for port {}", port))?;
+                tokio::io::copy_bidirectional(&mut client, &mut upstream).await?;
+                drop(upstream);
+                forwarder.join().await?;
+                anyhow::Ok(())
+            }
+            .await;
+            if let (Err(e), Ok(mut last)) = (result, last_error.lock()) {
+                *last = Some(e.to_string());
+            }
+        });
+    }
+}
+
 // Pod watcher for realtime updates
 pub struct PodWatcher {
     pub rx: tokio_mpsc::UnboundedReceiver<Vec<PodInfo>>,
 }
 
-impl KubeClient {
-    /// Start watching pods in the given namespace for realtime updates
-    pub async fn watch_pods(&self, namespace: &str) -> Result<PodWatcher> {
-        let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
-        let (tx, rx) = tokio_mpsc::unbounded_channel();
-
-        let watcher_config = watcher::Config::default().timeout(60); // Timeout after 60s of inactivity, will auto-reconnect
+// New and updated events for the Events view's follow mode
+pub struct EventWatcher {
+    pub rx: tokio_mpsc::UnboundedReceiver<EventInfo>,
+}
 
-        // Spawn background task to watch pods
-        tokio::spawn(async move {
-            let stream = watcher(api, watcher_config).applied_objects();
-            let mut stream = Box::pin(stream);
-            let mut pods_cache: HashMap<String, PodInfo> = HashMap::new();
+// Status updates for a single deployment's rollout
+pub struct RolloutWatcher {
+    pub rx: tokio_mpsc::UnboundedReceiver<RolloutStatus>,
+}
 
-            while let Ok(Some(pod)) = stream.try_next().await {
-                let pod_info = PodInfo::from_pod(&pod);
-                let pod_name = pod_info.name.clone();
+/// One line of a followed log and the container that wrote it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerLogLine {
+    pub container: String,
+    pub line: String,
+}
 
-                // Update cache
-                pods_cache.insert(pod_name, pod_info);
+/// Followed logs of several containers, one task per container. A quiet
+/// container would keep its task parked on the stream, so dropping this aborts them.
+pub struct PodLogWatcher {
+    pub rx: tokio_mpsc::UnboundedReceiver<ContainerLogLine>,
+    pub tasks: Vec<tokio::task::JoinHandle<()>>,
+}
 
-                // Send updated pod list
-                let mut pod_list: Vec<PodInfo> = pods_cache.values().cloned().collect();
-                // Sort by name for consistent ordering
-                pod_list.sort_by(|a, b| a.name.cmp(&b.name));
+impl Drop for PodLogWatcher {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
 
-                if tx.send(pod_list).is_err() {
-                    // Receiver dropped, exit watcher
-                    break;
+/// Send one container's followed log line by line until it ends, then say so.
+/// A container that can't be followed (e.g. still waiting to start) reports
+/// why in its place instead of failing the others.
+async fn follow_container_log(
+    api: Api<Pod>,
+    pod: String,
+    params: LogParams,
+    tx: tokio_mpsc::UnboundedSender<ContainerLogLine>,
+) {
+    let container = params.container.clone().unwrap_or_default();
+    let send = |line: String| {
+        tx.send(ContainerLogLine {
+            container: container.clone(),
+            line,
+        })
+        .is_ok()
+    };
+    let mut stream = match api.log_stream(&pod, &params).await {
+        Ok(stream) => Box::pin(stream),
+        Err(e) => {
+            send(format!("--- can't follow this container: {} ---", e));
+            return;
+        }
+    };
+    // Read raw lines, so one bad byte turns into U+FFFD as in `get_pod_logs`
+    let mut bytes = Vec::new();
+    loop {
+        bytes.clear();
+        match stream.read_until(b'\n', &mut bytes).await {
+            Ok(0) => break,
+            Ok(_) => {
+                let line = String::from_utf8_lossy(&bytes);
+                if !send(line.trim_end_matches(['\n', '\r']).to_string()) {
+                    return;
                 }
             }
-        });
-
-        Ok(PodWatcher { rx })
+            Err(e) => {
+                send(format!("--- log stream failed: {} ---", e));
+                return;
+            }
+        }
     }
+    send("--- log stream ended ---".to_string());
 }
 
-pub struct TerminalSession {
-    parser: Parser,
-    writer: Box<dyn Write + Send>,
-    #[allow(dead_code)]
-    child: Box<dyn portable_pty::Child + Send + Sync>,
-    rx: Receiver<Vec<u8>>,
-    _reader_thread: Option<thread::JoinHandle<()>>,
-    rows: u16,
-    #[allow(dead_code)]
-    cols: u16,
-}
+/// Image of debug containers and of the debug pod behind a node shell
+const DEBUG_IMAGE: &str = "busybox:1.36";
 
-impl TerminalSession {
-    #[allow(dead_code)]
-    pub fn new(namespace: &str, pod_name: &str) -> Result<Self> {
-        Self::new_with_shell(namespace, pod_name, None)
-    }
+/// Annotation kubectl reads to pick a multi-container pod's container for
+/// `logs`, `exec` and `attach` when none is given
+pub const DEFAULT_CONTAINER_ANNOTATION: &str = "kubectl.kubernetes.io/default-container";
 
-    pub fn new_with_shell(namespace: &str, pod_name: &str, shell: Option<&str>) -> Result<Self> {
-        let pty_system = NativePtySystem::default();
+/// Kubeconfig keys holding credentials or key material
+const CREDENTIAL_KEYS: &[&str] = &[
+    "token",
+    "password",
+    "client-key-data",
+    "client-certificate-data",
+    "certificate-authority-data",
+    "idp-certificate-authority-data",
+    "id-token",
+    "refresh-token",
+    "access-token",
+    "client-secret",
+];
 
-        // Use larger terminal size for better compatibility
-        let rows = 40;
-        let cols = 120;
+/// Words in an exec plugin flag name that mark its value as a secret
+const SECRET_FLAG_WORDS: &[&str] = &["token", "secret", "password", "key", "credential"];
 
-        let pair = pty_system.openpty(PtySize {
+/// Replace every credential in a kubeconfig with `<redacted>`, at any depth
+/// (auth-provider configs nest them)
+pub fn redact_credentials(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if CREDENTIAL_KEYS.contains(&key.as_str()) && !value.is_null() {
+                    *value = serde_json::Value::String("<redacted>".to_string());
+                } else if key == "exec" {
+                    redact_exec_plugin(value);
+                } else {
+                    redact_credentials(value);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_credentials),
+        _ => {}
+    }
+}
+
+/// Exec plugins get their secrets through `env` (e.g. `AWS_SECRET_ACCESS_KEY`) or
+/// flags in `args` (e.g. `--token`). Every env value is redacted, names kept, and
+/// so is the value of any flag whose name looks secret.
+fn redact_exec_plugin(exec: &mut serde_json::Value) {
+    let redacted = || serde_json::Value::String("<redacted>".to_string());
+    if let Some(env) = exec.get_mut("env").and_then(|e| e.as_array_mut()) {
+        for entry in env {
+            if let Some(value) = entry.get_mut("value").filter(|v| !v.is_null()) {
+                *value = redacted();
+            }
+        }
+    }
+    if let Some(args) = exec.get_mut("args").and_then(|a| a.as_array_mut()) {
+        let secret_flag = |flag: &str| {
+            let flag = flag.to_lowercase();
+            flag.starts_with('-') && SECRET_FLAG_WORDS.iter().any(|w| flag.contains(w))
+        };
+        let mut value_follows = false;
+        for arg in args {
+            let Some(text) = arg.as_str() else {
+                value_follows = false;
+                continue;
+            };
+            if value_follows && !text.starts_with('-') {
+                *arg = redacted();
+                value_follows = false;
+            } else if let Some((flag, _)) = text.split_once('=').filter(|(f, _)| secret_flag(f)) {
+                *arg = serde_json::Value::String(format!("{}=<redacted>", flag));
+                value_follows = false;
+            } else {
+                value_follows = secret_flag(text);
+            }
+        }
+    }
+    // Anything else in there, like a nested key from the list above
+    redact_credentials(exec);
+}
+
+/// Where Kubernetes mounts a pod's service account token
+pub const SERVICE_ACCOUNT_TOKEN_FILE: &str = "/var/run/secrets/kubernetes.io/serviceaccount/token";
+
+/// Who a bearer token file authenticates as: the `sub` claim of the JWT in it,
+/// e.g. `system:serviceaccount:ci:qui`. None for unreadable or opaque tokens.
+pub fn token_file_identity(path: &Path) -> Option<String> {
+    let token = fs::read_to_string(path).ok()?;
+    let payload = token.trim().split('.').nth(1)?;
+    let claims = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+    let claims: serde_json::Value = serde_json::from_slice(&claims).ok()?;
+    claims.get("sub")?.as_str().map(str::to_string)
+}
+
+/// A resource quantity such as `250m`, `1.5` or `128Mi` as a plain number
+/// (cores, bytes). None for anything that isn't a quantity.
+pub fn parse_quantity(quantity: &str) -> Option<f64> {
+    let quantity = quantity.trim();
+    let split = quantity
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(quantity.len());
+    let (number, suffix) = quantity.split_at(split);
+    let number: f64 = number.parse().ok()?;
+    let scale = match suffix {
+        "n" => 1e-9,
+        "u" => 1e-6,
+        "m" => 1e-3,
+        "" => 1.0,
+        "k" => 1e3,
+        "M" => 1e6,
+        "G" => 1e9,
+        "T" => 1e12,
+        "Ki" => 1024.0,
+        "Mi" => 1024.0 * 1024.0,
+        "Gi" => 1024.0 * 1024.0 * 1024.0,
+        "Ti" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        _ => return None,
+    };
+    Some(number * scale)
+}
+
+/// List parameters selecting a deployment's pods and ReplicaSets by its matchLabels
+fn deployment_selector(deployment: &Deployment) -> ListParams {
+    let mut params = ListParams::default();
+    if let Some(labels) = deployment
+        .spec
+        .as_ref()
+        .and_then(|s| s.selector.match_labels.as_ref())
+    {
+        let selector: Vec<String> = labels.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+        params = params.labels(&selector.join(","));
+    }
+    params
+}
+
+/// `debugger`, or `debugger-N` if earlier debug sessions already used the name;
+/// ephemeral containers can't be removed, so names are never freed
+fn debug_container_name(taken: &[String]) -> String {
+    (1..)
+        .map(|n| {
+            if n == 1 {
+                "debugger".to_string()
+            } else {
+                format!("debugger-{}", n)
+            }
+        })
+        .find(|name| !taken.contains(name))
+        .unwrap_or_default()
+}
+
+/// `--as`/`--as-group` for the impersonation set in the QUI config, so kubectl
+/// runs act as the same identity as the API client
+pub fn kubectl_identity(settings: &crate::config::Config) -> Vec<String> {
+    settings
+        .impersonate
+        .iter()
+        .map(|user| format!("--as={}", user))
+        .chain(
+            settings
+                .impersonate_groups
+                .iter()
+                .map(|group| format!("--as-group={}", group)),
+        )
+        .collect()
+}
+
+/// Arguments to `kubectl` for a shell on `node`. The sysadmin profile makes the
+/// debug pod privileged, and the node's root filesystem is mounted at /host.
+fn node_shell_args(namespace: &str, node: &str, identity: &[String]) -> Vec<String> {
+    let mut args: Vec<String> = [
+        "debug",
+        &format!("node/{}", node),
+        "-it",
+        "-n",
+        namespace,
+        "--profile=sysadmin",
+        &format!("--image={}", DEBUG_IMAGE),
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    args.extend(identity.iter().cloned());
+    args.extend(["--", "chroot", "/host", "/bin/sh"].map(String::from));
+    args
+}
+
+/// The node shell as a command line, for running it by hand
+pub fn node_shell_command(namespace: &str, node: &str, identity: &[String]) -> String {
+    format!(
+        "kubectl {}",
+        node_shell_args(namespace, node, identity).join(" ")
+    )
+}
+
+/// Shells probed by `detect_shell`, most capable first
+const SHELL_CANDIDATES: &[&str] = &["/bin/bash", "/usr/bin/bash", "/bin/ash", "/bin/sh"];
+
+/// How long a ping waits for the API server
+const PING_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// How long closing a terminal waits for its reader thread before detaching it
+const READER_JOIN_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(200);
+
+pub struct TerminalSession {
+    pub namespace: String,
+    pub pod_name: String,
+    /// Container attached to rather than exec'd into, see `attach`
+    pub container: Option<String>,
+    /// Container exec'd into when one was picked; kubectl's default otherwise
+    pub exec_container: Option<String>,
+    parser: Parser,
+    // Kept to resize the pty; reads and writes go through their own handles
+    master: Box<dyn portable_pty::MasterPty + Send>,
+    writer: Box<dyn Write + Send>,
+    child: Box<dyn portable_pty::Child + Send + Sync>,
+    rx: Receiver<Vec<u8>>,
+    reader_thread: Option<thread::JoinHandle<()>>,
+    rows: u16,
+    cols: u16,
+}
+
+impl TerminalSession {
+    #[allow(dead_code)]
+    pub fn new(namespace: &str, pod_name: &str) -> Result<Self> {
+        Self::new_with_shell(namespace, pod_name, None, None, &[])
+    }
+
+    pub fn new_with_shell(
+        namespace: &str,
+        pod_name: &str,
+        container: Option<&str>,
+        shell: Option<&str>,
+        identity: &[String],
+    ) -> Result<Self> {
+        let mut args = vec!["exec", "-it", "-n", namespace, pod_name];
+        if let Some(container) = container {
+            args.extend(["-c", container]);
+        }
+        args.extend(identity.iter().map(String::as_str));
+        args.extend([
+            // Set environment variables inside the pod
+            "--",
+            "env",
+            "TERM=xterm-256color",
+            "PS1=$ ", // Simple prompt to avoid issues
+            // Try the specified shell or default to bash (better for Ruby/Rails)
+            shell.unwrap_or("/bin/bash"),
+        ]);
+        let mut session = Self::spawn(namespace, pod_name, &args)?;
+        session.exec_container = container.map(str::to_string);
+        Ok(session)
+    }
+
+    /// A root shell on `node` through a privileged debug pod in `namespace`, like
+    /// `kubectl debug node/<node>`. The session's title is `node/<node>`.
+    pub fn node_shell(namespace: &str, node: &str, identity: &[String]) -> Result<Self> {
+        let args = node_shell_args(namespace, node, identity);
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        Self::spawn(namespace, &format!("node/{}", node), &args)
+    }
+
+    /// Attach to `container` of `pod_name`, e.g. an ephemeral debug container
+    /// whose main process is the shell
+    pub fn attach(
+        namespace: &str,
+        pod_name: &str,
+        container: &str,
+        identity: &[String],
+    ) -> Result<Self> {
+        let mut args = vec!["attach", "-it", "-n", namespace, pod_name, "-c", container];
+        args.extend(identity.iter().map(String::as_str));
+        let mut session = Self::spawn(namespace, pod_name, &args)?;
+        session.container = Some(container.to_string());
+        Ok(session)
+    }
+
+    /// Run `kubectl <args>` in a new pty
+    fn spawn(namespace: &str, pod_name: &str, args: &[&str]) -> Result<Self> {
+        let pty_system = NativePtySystem::default();
+
+        // Until the first frame resizes it to the terminal view
+        let rows = 40;
+        let cols = 120;
+
+        let pair = pty_system.openpty(PtySize {
             rows,
             cols,
             pixel_width: 0,
@@ -352,25 +1525,11 @@ impl TerminalSession {
         })?;
 
         let mut cmd = CommandBuilder::new("kubectl");
-        cmd.arg("exec");
-        cmd.arg("-it");
-        cmd.arg("-n");
-        cmd.arg(namespace);
-        cmd.arg(pod_name);
+        cmd.args(args);
 
         // Set TERM for local kubectl process
         cmd.env("TERM", "xterm-256color");
 
-        // Set environment variables inside the pod
-        cmd.arg("--");
-        cmd.arg("env");
-        cmd.arg("TERM=xterm-256color");
-        cmd.arg("PS1=$ "); // Simple prompt to avoid issues
-
-        // Try the specified shell or default to bash (better for Ruby/Rails)
-        let shell_cmd = shell.unwrap_or("/bin/bash");
-        cmd.arg(shell_cmd);
-
         let child = pair.slave.spawn_command(cmd)?;
 
         let mut reader = pair.master.try_clone_reader()?;
@@ -396,16 +1555,41 @@ impl TerminalSession {
         });
 
         Ok(Self {
+            namespace: namespace.to_string(),
+            pod_name: pod_name.to_string(),
+            container: None,
+            exec_container: None,
             parser: Parser::new(rows, cols, 5000), // Larger scrollback buffer
+            master: pair.master,
             writer,
             child,
             rx,
-            _reader_thread: Some(reader_thread),
+            reader_thread: Some(reader_thread),
             rows,
             cols,
         })
     }
 
+    /// Resize the pty, so the shell (and full-screen programs in it) reflow to
+    /// `rows`x`cols`, and the screen it's parsed into. No-op at the current size.
+    pub fn resize(&mut self, rows: u16, cols: u16) -> Result<()> {
+        // vt100 can't hold an empty grid
+        let (rows, cols) = (rows.max(1), cols.max(1));
+        if (rows, cols) == (self.rows, self.cols) {
+            return Ok(());
+        }
+        self.master.resize(PtySize {
+            rows,
+            cols,
+            pixel_width: 0,
+            pixel_height: 0,
+        })?;
+        self.parser.set_size(rows, cols);
+        self.rows = rows;
+        self.cols = cols;
+        Ok(())
+    }
+
     pub fn send_input(&mut self, event: &crate::events::InputEvent) -> Result<()> {
         let mut buf = Vec::new();
 
@@ -464,14 +1648,12 @@ impl TerminalSession {
 
         let screen = self.parser.screen();
 
-        // Get the entire screen contents including scrollback
-        let contents = screen.contents();
-
-        // Split by lines and preserve all content
-        let lines: Vec<String> = contents.lines().map(|s| s.to_string()).collect();
+        // One string per grid row. `contents()` would join soft-wrapped rows into
+        // one long line, which the widget then re-wraps at a different point once
+        // wide characters (CJK, emoji) are involved, shifting everything below.
+        let mut result: Vec<String> = screen.rows(0, self.cols).collect();
 
         // If we have fewer lines than the terminal height, pad with empty lines
-        let mut result = lines;
         while result.len() < self.rows as usize {
             result.push(String::new());
         }
@@ -479,51 +1661,288 @@ impl TerminalSession {
         result
     }
 
+    /// Whether the kubectl exec process is still running
+    pub fn is_alive(&mut self) -> bool {
+        matches!(self.child.try_wait(), Ok(None))
+    }
+
     pub fn close(&mut self) -> Result<()> {
-        // Send Ctrl+D to close the shell gracefully
-        self.writer.write_all(&[4])?;
-        self.writer.flush()?;
+        // Send Ctrl+D to close the shell gracefully, then make sure it's gone
+        let eof = self
+            .writer
+            .write_all(&[4])
+            .and_then(|_| self.writer.flush());
+        self.shutdown();
+        eof?;
         Ok(())
     }
+
+    /// Kill the kubectl process and reap the reader thread. Safe to call twice.
+    fn shutdown(&mut self) {
+        if self.is_alive() {
+            let _ = self.child.kill();
+        }
+        let _ = self.child.wait();
+
+        // With the child gone the PTY read returns EOF, so the thread normally
+        // finishes right away. If something still holds the PTY open, detach it
+        // rather than block the UI; it exits on its next read once `rx` is dropped.
+        if let Some(handle) = self.reader_thread.take() {
+            let deadline = std::time::Instant::now() + READER_JOIN_TIMEOUT;
+            while !handle.is_finished() && std::time::Instant::now() < deadline {
+                thread::sleep(std::time::Duration::from_millis(5));
+            }
+            if handle.is_finished() {
+                let _ = handle.join();
+            }
+        }
+    }
 }
 
-impl KubeClient {
-    pub async fn list_namespaces(&self) -> Result<Vec<String>> {
+impl Drop for TerminalSession {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+#[async_trait]
+impl KubeApi for KubeClient {
+    /// Time a `/version` request, the cheapest call there is. Gives up after
+    /// `PING_TIMEOUT`, so a dead VPN is reported instead of hanging the UI.
+    async fn ping(&self) -> Result<std::time::Duration> {
+        let started = std::time::Instant::now();
+        tokio::time::timeout(PING_TIMEOUT, self.client.apiserver_version())
+            .await
+            .map_err(|_| {
+                QuiError::Timeout(format!("no answer within {}s", PING_TIMEOUT.as_secs()))
+            })??;
+        Ok(started.elapsed())
+    }
+
+    /// Namespaces passing the configured filter, so a multi-tenant cluster only
+    /// shows the ones that matter
+    async fn list_namespaces(&self) -> Result<Vec<String>> {
         let api: Api<Namespace> = Api::all(self.client.clone());
-        let namespaces = api.list(&ListParams::default()).await?;
+        let mut params = ListParams::default();
+        if let Some(selector) = &self.namespace_filter.label_selector {
+            params = params.labels(selector);
+        }
+        let namespaces = api.list_metadata(&params).await?;
 
         Ok(namespaces
             .items
-            .iter()
-            .filter_map(|ns| ns.metadata.name.clone())
+            .into_iter()
+            .filter_map(|ns| ns.metadata.name)
+            .filter(|name| self.namespace_filter.allows(name))
             .collect())
     }
 
-    pub async fn list_pods(&self, namespace: &str) -> Result<Vec<PodInfo>> {
+    async fn list_pods(&self, namespace: &str) -> Result<Vec<PodInfo>> {
         let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
         let pods = api.list(&ListParams::default()).await?;
 
         Ok(pods.items.iter().map(PodInfo::from_pod).collect())
     }
 
-    pub async fn delete_pod(&self, namespace: &str, name: &str) -> Result<()> {
+    /// Fetch the full object as JSON, for the YAML view
+    async fn get_resource_json(
+        &self,
+        kind: ResourceKind,
+        namespace: &str,
+        name: &str,
+    ) -> Result<serde_json::Value> {
+        let value = match kind {
+            ResourceKind::Pod => {
+                let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+                serde_json::to_value(api.get(name).await?)?
+            }
+            ResourceKind::Deployment => {
+                let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+                serde_json::to_value(api.get(name).await?)?
+            }
+            ResourceKind::Service => {
+                let api: Api<Service> = Api::namespaced(self.client.clone(), namespace);
+                serde_json::to_value(api.get(name).await?)?
+            }
+        };
+        Ok(value)
+    }
+
+    async fn describe_pod(&self, namespace: &str, name: &str) -> Result<PodDetail> {
+        let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+        let pod = api.get(name).await?;
+        Ok(PodDetail::from_pod(&pod))
+    }
+
+    async fn pod_metrics(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Option<Vec<ContainerUsage>>> {
+        let gvk = GroupVersionKind::gvk("metrics.k8s.io", "v1beta1", "PodMetrics");
+        let resource = ApiResource::from_gvk_with_plural(&gvk, "pods");
+        let api: Api<DynamicObject> =
+            Api::namespaced_with(self.client.clone(), namespace, &resource);
+        // A cluster without metrics-server answers 404 for the whole group
+        let Some(metrics) = api.get_opt(name).await? else {
+            return Ok(None);
+        };
+
+        #[derive(Deserialize)]
+        struct ContainerMetrics {
+            name: String,
+            usage: BTreeMap<String, String>,
+        }
+        let containers: Vec<ContainerMetrics> =
+            serde_json::from_value(metrics.data["containers"].clone()).unwrap_or_default();
+        Ok(Some(
+            containers
+                .into_iter()
+                .map(|c| ContainerUsage {
+                    cpu_millis: c
+                        .usage
+                        .get("cpu")
+                        .and_then(|q| parse_quantity(q))
+                        .map_or(0, |cores| (cores * 1000.0).round() as u64),
+                    memory_bytes: c
+                        .usage
+                        .get("memory")
+                        .and_then(|q| parse_quantity(q))
+                        .map_or(0, |bytes| bytes.round() as u64),
+                    container: c.name,
+                })
+                .collect(),
+        ))
+    }
+
+    async fn port_forward(
+        &self,
+        namespace: &str,
+        pod: &str,
+        local_port: u16,
+        remote_port: u16,
+    ) -> Result<PortForwardHandle> {
+        // Bound up front so a port already in use is reported right away
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", local_port)).await?;
+        let local_addr = listener.local_addr()?;
+        let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+        let last_error = Arc::new(Mutex::new(None));
+        let task = tokio::spawn(forward_connections(
+            listener,
+            api,
+            pod.to_string(),
+            remote_port,
+            last_error.clone(),
+        ));
+
+        Ok(PortForwardHandle {
+            namespace: namespace.to_string(),
+            pod: pod.to_string(),
+            local_addr,
+            remote_port,
+            last_error,
+            task,
+        })
+    }
+
+    async fn kubectl_describe(&self, kind: &str, namespace: &str, name: &str) -> Result<String> {
+        let output = tokio::process::Command::new("kubectl")
+            .args(["describe", kind, name, "-n", namespace])
+            .args(&self.kubectl_identity)
+            .output()
+            .await
+            .map_err(|e| match e.kind() {
+                std::io::ErrorKind::NotFound => {
+                    anyhow::anyhow!("kubectl is not installed or not on the PATH")
+                }
+                _ => anyhow::anyhow!("Failed to run kubectl: {}", e),
+            })?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "kubectl describe failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    async fn describe_node(&self, name: &str) -> Result<NodeDetail> {
+        let api: Api<Node> = Api::all(self.client.clone());
+        let node = api.get(name).await?;
+
+        // Counted across all namespaces, which RBAC may not allow; the rest still shows
+        let pods: Api<Pod> = Api::all(self.client.clone());
+        let selector = format!(
+            "spec.nodeName={},status.phase!=Succeeded,status.phase!=Failed",
+            name
+        );
+        let pod_count = pods
+            .list_metadata(&ListParams::default().fields(&selector))
+            .await
+            .ok()
+            .map(|list| list.items.len());
+
+        Ok(NodeDetail::from_node(&node, pod_count))
+    }
+
+    async fn delete_pod(&self, namespace: &str, name: &str) -> Result<()> {
         let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
         api.delete(name, &DeleteParams::default()).await?;
         Ok(())
     }
 
-    pub async fn get_pod_logs(&self, namespace: &str, name: &str) -> Result<String> {
+    /// Fetch the last `tail_lines` log lines. With `previous`, read the instance that ran
+    /// before the latest restart; the kubelet keeps no older instances than that.
+    /// Bytes that aren't valid UTF-8 come back as U+FFFD instead of failing the fetch.
+    async fn get_pod_logs(&self, namespace: &str, name: &str, query: &LogQuery) -> Result<String> {
         let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
         let log_params = LogParams {
-            tail_lines: Some(100),
+            container: query.container.clone(),
+            tail_lines: Some(query.tail_lines),
+            previous: query.previous,
+            timestamps: query.timestamps,
             ..Default::default()
         };
 
-        let logs = api.logs(name, &log_params).await?;
-        Ok(logs)
+        // `Api::logs` rejects the whole body over one bad byte, so read it raw
+        let mut stream = Box::pin(api.log_stream(name, &log_params).await?);
+        let mut bytes = Vec::new();
+        stream.read_to_end(&mut bytes).await?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    async fn follow_pod_logs(
+        &self,
+        namespace: &str,
+        name: &str,
+        containers: &[String],
+        tail_lines: i64,
+        timestamps: bool,
+    ) -> Result<PodLogWatcher> {
+        let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+        let (tx, rx) = tokio_mpsc::unbounded_channel();
+        let tasks = containers
+            .iter()
+            .map(|container| {
+                let params = LogParams {
+                    container: Some(container.clone()),
+                    follow: true,
+                    tail_lines: Some(tail_lines),
+                    timestamps,
+                    ..Default::default()
+                };
+                tokio::spawn(follow_container_log(
+                    api.clone(),
+                    name.to_string(),
+                    params,
+                    tx.clone(),
+                ))
+            })
+            .collect();
+        Ok(PodLogWatcher { rx, tasks })
     }
 
-    pub async fn list_deployments(&self, namespace: &str) -> Result<Vec<DeploymentInfo>> {
+    async fn list_deployments(&self, namespace: &str) -> Result<Vec<DeploymentInfo>> {
         let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
         let deployments = api.list(&ListParams::default()).await?;
 
@@ -534,13 +1953,18 @@ impl KubeClient {
             .collect())
     }
 
-    pub async fn delete_deployment(&self, namespace: &str, name: &str) -> Result<()> {
+    async fn delete_deployment(
+        &self,
+        namespace: &str,
+        name: &str,
+        propagation: DeletePropagation,
+    ) -> Result<()> {
         let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
-        api.delete(name, &DeleteParams::default()).await?;
+        api.delete(name, &propagation.delete_params()).await?;
         Ok(())
     }
 
-    pub async fn scale_deployment(&self, namespace: &str, name: &str, replicas: i32) -> Result<()> {
+    async fn scale_deployment(&self, namespace: &str, name: &str, replicas: i32) -> Result<()> {
         let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
         let mut deployment = api.get(name).await?;
 
@@ -552,7 +1976,99 @@ impl KubeClient {
         Ok(())
     }
 
-    pub async fn list_services(&self, namespace: &str) -> Result<Vec<ServiceInfo>> {
+    async fn describe_deployment(&self, namespace: &str, name: &str) -> Result<DeploymentDetail> {
+        let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+        let deployment = api.get(name).await?;
+        let mut detail = DeploymentDetail::from_deployment(&deployment);
+
+        let pods: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+        let pods = pods.list(&deployment_selector(&deployment)).await?;
+        detail.compare_pods(&pods.items);
+        Ok(detail)
+    }
+
+    async fn deployment_history(&self, namespace: &str, name: &str) -> Result<DeploymentHistory> {
+        let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+        let deployment = api.get(name).await?;
+        let replica_sets = self.owned_replica_sets(namespace, &deployment).await?;
+
+        let mut revisions: Vec<RevisionInfo> = replica_sets
+            .iter()
+            .filter_map(RevisionInfo::from_replica_set)
+            .collect();
+        revisions.sort_by_key(|r| std::cmp::Reverse(r.revision));
+        Ok(DeploymentHistory {
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+            current_revision: revision_of(&deployment.metadata),
+            revisions,
+        })
+    }
+
+    async fn rollback_deployment(&self, namespace: &str, name: &str, revision: i64) -> Result<()> {
+        let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+        let mut deployment = api.get(name).await?;
+        let replica_set = self
+            .owned_replica_sets(namespace, &deployment)
+            .await?
+            .into_iter()
+            .find(|rs| revision_of(&rs.metadata) == Some(revision))
+            .ok_or_else(|| anyhow::anyhow!("Revision {} of {} not found", revision, name))?;
+        let mut template = replica_set
+            .spec
+            .and_then(|s| s.template)
+            .ok_or_else(|| anyhow::anyhow!("Revision {} has no pod template", revision))?;
+        // The hash label belongs to the old ReplicaSet; the controller sets a fresh one
+        if let Some(labels) = template.metadata.as_mut().and_then(|m| m.labels.as_mut()) {
+            labels.remove("pod-template-hash");
+        }
+
+        if let Some(spec) = &mut deployment.spec {
+            spec.template = template;
+        }
+        api.replace(name, &Default::default(), &deployment).await?;
+        Ok(())
+    }
+
+    /// Change a plain-value env var of one container. Variables sourced from a
+    /// secret, configmap or field are refused rather than silently overwritten.
+    async fn set_deployment_env(
+        &self,
+        namespace: &str,
+        name: &str,
+        container: &str,
+        variable: &str,
+        value: &str,
+    ) -> Result<()> {
+        let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+        let mut deployment = api.get(name).await?;
+
+        let env_var = deployment
+            .spec
+            .as_mut()
+            .and_then(|spec| spec.template.spec.as_mut())
+            .and_then(|spec| spec.containers.iter_mut().find(|c| c.name == container))
+            .ok_or_else(|| anyhow::anyhow!("Container '{}' not found", container))?
+            .env
+            .iter_mut()
+            .flatten()
+            .find(|e| e.name == variable)
+            .ok_or_else(|| {
+                anyhow::anyhow!("'{}' is not set in container '{}'", variable, container)
+            })?;
+        if env_var.value_from.is_some() {
+            anyhow::bail!(
+                "'{}' comes from a reference; only plain values can be edited",
+                variable
+            );
+        }
+        env_var.value = Some(value.to_string());
+
+        api.replace(name, &Default::default(), &deployment).await?;
+        Ok(())
+    }
+
+    async fn list_services(&self, namespace: &str) -> Result<Vec<ServiceInfo>> {
         let api: Api<Service> = Api::namespaced(self.client.clone(), namespace);
         let services = api.list(&ListParams::default()).await?;
 
@@ -562,114 +2078,1774 @@ impl KubeClient {
             .map(ServiceInfo::from_service)
             .collect())
     }
-}
 
-#[derive(Debug, Clone)]
-pub struct PodInfo {
-    pub name: String,
-    pub _namespace: String,
-    pub status: String,
-    pub ready: String,
-    pub restarts: i32,
-    pub age: String,
-}
+    /// List events in the namespace, most recent first
+    async fn list_events(&self, namespace: &str) -> Result<Vec<EventInfo>> {
+        let api: Api<Event> = Api::namespaced(self.client.clone(), namespace);
+        let events = api.list(&ListParams::default()).await?;
 
-impl PodInfo {
-    fn from_pod(pod: &Pod) -> Self {
-        let name = pod.metadata.name.clone().unwrap_or_default();
-        let namespace = pod.metadata.namespace.clone().unwrap_or_default();
+        let mut events: Vec<EventInfo> = events.items.iter().map(EventInfo::from_event).collect();
+        events.sort_by_key(|e| std::cmp::Reverse(e.last_seen));
+        Ok(events)
+    }
 
-        let status = pod
-            .status
-            .as_ref()
-            .and_then(|s| s.phase.clone())
-            .unwrap_or_else(|| "Unknown".to_string());
+    async fn list_configmaps(&self, namespace: &str) -> Result<Vec<ConfigMapInfo>> {
+        let api: Api<ConfigMap> = Api::namespaced(self.client.clone(), namespace);
+        let config_maps = api.list(&ListParams::default()).await?;
 
-        let (ready_count, total_count) = pod
-            .status
-            .as_ref()
-            .and_then(|s| s.container_statuses.as_ref())
-            .map(|cs| {
-                let ready = cs.iter().filter(|c| c.ready).count();
-                (ready, cs.len())
-            })
-            .unwrap_or((0, 0));
+        Ok(config_maps
+            .items
+            .iter()
+            .map(ConfigMapInfo::from_config_map)
+            .collect())
+    }
 
-        let ready = format!("{}/{}", ready_count, total_count);
+    async fn describe_configmap(&self, namespace: &str, name: &str) -> Result<ConfigMapDetail> {
+        let api: Api<ConfigMap> = Api::namespaced(self.client.clone(), namespace);
+        let config_map = api.get(name).await?;
+        Ok(ConfigMapDetail::from_config_map(&config_map))
+    }
 
-        let restarts = pod
-            .status
-            .as_ref()
-            .and_then(|s| s.container_statuses.as_ref())
-            .map(|cs| cs.iter().map(|c| c.restart_count).sum())
-            .unwrap_or(0);
+    async fn list_secrets(&self, namespace: &str) -> Result<Vec<SecretInfo>> {
+        let api: Api<Secret> = Api::namespaced(self.client.clone(), namespace);
+        let secrets = api.list(&ListParams::default()).await?;
 
-        let age = pod
-            .metadata
-            .creation_timestamp
-            .as_ref()
-            .map(|t| format_age(&t.0))
-            .unwrap_or_else(|| "Unknown".to_string());
+        Ok(secrets.items.iter().map(SecretInfo::from_secret).collect())
+    }
 
-        Self {
-            name,
-            _namespace: namespace,
-            status,
-            ready,
-            restarts,
-            age,
-        }
+    async fn describe_secret(&self, namespace: &str, name: &str) -> Result<SecretDetail> {
+        let api: Api<Secret> = Api::namespaced(self.client.clone(), namespace);
+        let secret = api.get(name).await?;
+        Ok(SecretDetail::from_secret(&secret))
     }
-}
 
-#[derive(Debug, Clone)]
-pub struct DeploymentInfo {
-    pub name: String,
-    pub _namespace: String,
-    pub ready: String,
-    pub up_to_date: i32,
-    pub available: i32,
-    pub age: String,
-}
+    /// Watch events in the namespace; the first messages replay the existing ones
+    async fn watch_events(&self, namespace: &str) -> Result<EventWatcher> {
+        let api: Api<Event> = Api::namespaced(self.client.clone(), namespace);
+        let (tx, rx) = tokio_mpsc::unbounded_channel();
 
-impl DeploymentInfo {
-    fn from_deployment(dep: &Deployment) -> Self {
-        let name = dep.metadata.name.clone().unwrap_or_default();
-        let namespace = dep.metadata.namespace.clone().unwrap_or_default();
+        tokio::spawn(async move {
+            let stream = watcher(api, watcher::Config::default().timeout(60)).applied_objects();
+            let mut stream = Box::pin(stream);
+            while let Ok(Some(event)) = stream.try_next().await {
+                if tx.send(EventInfo::from_event(&event)).is_err() {
+                    // Follow mode ended
+                    break;
+                }
+            }
+        });
 
-        let desired = dep.spec.as_ref().and_then(|s| s.replicas).unwrap_or(0);
-        let ready = dep
-            .status
-            .as_ref()
-            .and_then(|s| s.ready_replicas)
-            .unwrap_or(0);
-        let ready_str = format!("{}/{}", ready, desired);
+        Ok(EventWatcher { rx })
+    }
 
-        let up_to_date = dep
-            .status
-            .as_ref()
-            .and_then(|s| s.updated_replicas)
-            .unwrap_or(0);
-        let available = dep
-            .status
-            .as_ref()
-            .and_then(|s| s.available_replicas)
-            .unwrap_or(0);
+    /// Count Warning events seen in the last hour, for the tab bar badge
+    async fn count_recent_warnings(&self, namespace: &str) -> Result<usize> {
+        let api: Api<Event> = Api::namespaced(self.client.clone(), namespace);
+        let events = api
+            .list(&ListParams::default().fields("type=Warning"))
+            .await?;
 
-        let age = dep
-            .metadata
-            .creation_timestamp
+        let cutoff = chrono::Utc::now() - chrono::Duration::hours(1);
+        Ok(events
+            .items
+            .iter()
+            .map(EventInfo::from_event)
+            .filter(|e| e.last_seen.is_some_and(|t| t >= cutoff))
+            .count())
+    }
+
+    /// Gather counts for the namespace overview. Each part is best effort so a
+    /// missing list permission blanks one line instead of the whole summary.
+    async fn namespace_summary(&self, namespace: &str) -> Result<NamespaceSummary> {
+        let params = ListParams::default();
+        let pods_api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+        let deployments_api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+        let services_api: Api<Service> = Api::namespaced(self.client.clone(), namespace);
+        let config_maps_api: Api<ConfigMap> = Api::namespaced(self.client.clone(), namespace);
+        let events_api: Api<Event> = Api::namespaced(self.client.clone(), namespace);
+        let warning_params = ListParams::default().fields("type=Warning");
+
+        let (pods, deployments, services, config_maps, warnings) = futures::join!(
+            pods_api.list(&params),
+            deployments_api.list_metadata(&params),
+            services_api.list_metadata(&params),
+            config_maps_api.list_metadata(&params),
+            events_api.list(&warning_params),
+        );
+
+        let pod_statuses = pods.ok().map(|pods| {
+            let mut statuses = BTreeMap::new();
+            for pod in pods.items.iter().map(PodInfo::from_pod) {
+                *statuses.entry(pod.status).or_insert(0) += 1;
+            }
+            statuses
+        });
+
+        let cutoff = chrono::Utc::now() - chrono::Duration::hours(1);
+        let warnings = warnings.ok().map(|events| {
+            let mut warnings: Vec<EventInfo> = events
+                .items
+                .iter()
+                .map(EventInfo::from_event)
+                .filter(|e| e.last_seen.is_some_and(|t| t >= cutoff))
+                .collect();
+            warnings.sort_by_key(|e| std::cmp::Reverse(e.last_seen));
+            warnings
+        });
+
+        Ok(NamespaceSummary {
+            namespace: namespace.to_string(),
+            pod_statuses,
+            deployments: deployments.ok().map(|list| list.items.len()),
+            services: services.ok().map(|list| list.items.len()),
+            config_maps: config_maps.ok().map(|list| list.items.len()),
+            warnings,
+        })
+    }
+
+    async fn namespace_pod_summary(&self, namespace: &str) -> Result<PodSummary> {
+        let pods = self.list_pods(namespace).await?;
+        Ok(PodSummary::from_pods(&pods))
+    }
+
+    async fn describe_service(&self, namespace: &str, name: &str) -> Result<ServiceDetail> {
+        let api: Api<Service> = Api::namespaced(self.client.clone(), namespace);
+        let service = api.get(name).await?;
+
+        // Endpoints share the service name; a missing object just means no endpoints yet
+        let endpoints_api: Api<Endpoints> = Api::namespaced(self.client.clone(), namespace);
+        let (ready_endpoints, not_ready_endpoints) = match endpoints_api.get_opt(name).await? {
+            Some(endpoints) => endpoints.subsets.unwrap_or_default().iter().fold(
+                (0, 0),
+                |(ready, not_ready), subset| {
+                    (
+                        ready + subset.addresses.as_ref().map_or(0, |a| a.len()),
+                        not_ready + subset.not_ready_addresses.as_ref().map_or(0, |a| a.len()),
+                    )
+                },
+            ),
+            None => (0, 0),
+        };
+
+        Ok(ServiceDetail::from_service(
+            &service,
+            ready_endpoints,
+            not_ready_endpoints,
+        ))
+    }
+
+    /// Find pods, deployments and services in any namespace whose name contains
+    /// `query` (case-insensitive). Only metadata is fetched to keep this cheap.
+    async fn search_resources(&self, query: &str) -> Result<Vec<SearchResult>> {
+        let query = query.to_lowercase();
+        let params = ListParams::default();
+
+        let pods = Api::<Pod>::all(self.client.clone())
+            .list_metadata(&params)
+            .await?;
+        let deployments = Api::<Deployment>::all(self.client.clone())
+            .list_metadata(&params)
+            .await?;
+        let services = Api::<Service>::all(self.client.clone())
+            .list_metadata(&params)
+            .await?;
+
+        let mut results = matching_names(ResourceKind::Pod, pods, &query);
+        results.extend(matching_names(
+            ResourceKind::Deployment,
+            deployments,
+            &query,
+        ));
+        results.extend(matching_names(ResourceKind::Service, services, &query));
+
+        if self.namespace_filter.is_active() {
+            let namespaces = self.list_namespaces().await?;
+            results.retain(|r| namespaces.contains(&r.namespace));
+        }
+        Ok(results)
+    }
+
+    async fn patch_labels(
+        &self,
+        kind: ResourceKind,
+        namespace: &str,
+        name: &str,
+        labels: &BTreeMap<String, Option<String>>,
+    ) -> Result<()> {
+        self.patch_metadata_map(kind, namespace, name, "labels", labels)
+            .await
+    }
+
+    async fn patch_annotations(
+        &self,
+        kind: ResourceKind,
+        namespace: &str,
+        name: &str,
+        annotations: &BTreeMap<String, Option<String>>,
+    ) -> Result<()> {
+        self.patch_metadata_map(kind, namespace, name, "annotations", annotations)
+            .await
+    }
+
+    async fn cordon_node(&self, node: &str) -> Result<()> {
+        let api: Api<Node> = Api::all(self.client.clone());
+        let patch = Patch::Strategic(serde_json::json!({ "spec": { "unschedulable": true } }));
+        api.patch(node, &PatchParams::default(), &patch).await?;
+        Ok(())
+    }
+
+    async fn list_completed_jobs(&self, namespace: &str) -> Result<Vec<String>> {
+        let api: Api<Job> = Api::namespaced(self.client.clone(), namespace);
+        let jobs = api.list(&ListParams::default()).await?;
+        Ok(jobs
+            .items
+            .into_iter()
+            .filter(|job| {
+                job.status
+                    .as_ref()
+                    .and_then(|status| status.conditions.as_ref())
+                    .is_some_and(|conditions| {
+                        conditions
+                            .iter()
+                            .any(|c| c.type_ == "Complete" && c.status == "True")
+                    })
+            })
+            .filter_map(|job| job.metadata.name)
+            .collect())
+    }
+
+    async fn delete_job(&self, namespace: &str, name: &str) -> Result<()> {
+        let api: Api<Job> = Api::namespaced(self.client.clone(), namespace);
+        // The API's default for Jobs orphans their pods; background takes them along
+        api.delete(name, &DeletePropagation::Background.delete_params())
+            .await?;
+        Ok(())
+    }
+
+    async fn add_debug_container(
+        &self,
+        namespace: &str,
+        pod: &str,
+        target: Option<&str>,
+    ) -> Result<String> {
+        let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+        // Fetched first so that a 404 from the subresource means the feature is off
+        let existing = api.get(pod).await?;
+        let taken: Vec<String> = existing
+            .spec
+            .and_then(|spec| spec.ephemeral_containers)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|c| c.name)
+            .collect();
+        let name = debug_container_name(&taken);
+
+        let mut container = serde_json::json!({
+            "name": name,
+            "image": DEBUG_IMAGE,
+            "stdin": true,
+            "tty": true,
+        });
+        if let Some(target) = target {
+            container["targetContainerName"] = target.into();
+        }
+        let patch = Patch::Strategic(serde_json::json!({
+            "spec": { "ephemeralContainers": [container] }
+        }));
+        match api
+            .patch_ephemeral_containers(pod, &PatchParams::default(), &patch)
+            .await
+        {
+            Ok(_) => {}
+            Err(kube::Error::Api(response))
+                if response.code == 404
+                    || response.code == 405
+                    || (response.code == 422 && response.message.contains("disabled")) =>
+            {
+                anyhow::bail!(
+                    "ephemeral containers aren't enabled on this cluster (they need Kubernetes 1.23+)"
+                );
+            }
+            Err(e) => return Err(e.into()),
+        }
+        Ok(name)
+    }
+
+    async fn debug_container_running(
+        &self,
+        namespace: &str,
+        pod: &str,
+        container: &str,
+    ) -> Result<bool> {
+        let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+        let state = api
+            .get(pod)
+            .await?
+            .status
+            .and_then(|status| status.ephemeral_container_statuses)
+            .unwrap_or_default()
+            .into_iter()
+            .find(|status| status.name == container)
+            .and_then(|status| status.state);
+        let Some(state) = state else {
+            return Ok(false);
+        };
+        if state.running.is_some() {
+            return Ok(true);
+        }
+        if let Some(terminated) = state.terminated {
+            anyhow::bail!(
+                "debug container {} exited: {}",
+                container,
+                terminated.reason.unwrap_or_default()
+            );
+        }
+        if let Some(reason) = state.waiting.and_then(|waiting| waiting.reason) {
+            if reason.contains("ImagePull") || reason == "InvalidImageName" {
+                anyhow::bail!("debug container {} can't start: {}", container, reason);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Start watching pods in the given namespace for realtime updates
+    async fn watch_pods(&self, namespace: &str) -> Result<PodWatcher> {
+        let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+        let (tx, rx) = tokio_mpsc::unbounded_channel();
+
+        let watcher_config = watcher::Config::default().timeout(60); // Timeout after 60s of inactivity, will auto-reconnect
+
+        // Spawn background task to watch pods
+        tokio::spawn(async move {
+            let stream = watcher(api, watcher_config).applied_objects();
+            let mut stream = Box::pin(stream);
+            let mut pods_cache: HashMap<String, PodInfo> = HashMap::new();
+
+            while let Ok(Some(pod)) = stream.try_next().await {
+                let pod_info = PodInfo::from_pod(&pod);
+                let pod_name = pod_info.name.clone();
+
+                // Update cache
+                pods_cache.insert(pod_name, pod_info);
+
+                // Send updated pod list
+                let mut pod_list: Vec<PodInfo> = pods_cache.values().cloned().collect();
+                // Sort by name for consistent ordering
+                pod_list.sort_by(|a, b| a.name.cmp(&b.name));
+
+                if tx.send(pod_list).is_err() {
+                    // Receiver dropped, exit watcher
+                    break;
+                }
+            }
+        });
+
+        Ok(PodWatcher { rx })
+    }
+
+    /// Follow one deployment until its rollout completes or fails, like `kubectl rollout status`
+    async fn watch_rollout(&self, namespace: &str, name: &str) -> Result<RolloutWatcher> {
+        let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+        let (tx, rx) = tokio_mpsc::unbounded_channel();
+
+        let watcher_config = watcher::Config::default()
+            .fields(&format!("metadata.name={}", name))
+            .timeout(60);
+
+        tokio::spawn(async move {
+            let stream = watcher(api, watcher_config).applied_objects();
+            let mut stream = Box::pin(stream);
+
+            let mut last = None;
+            let reason = loop {
+                match stream.try_next().await {
+                    Ok(Some(dep)) => {
+                        let status = RolloutStatus::from_deployment(&dep);
+                        let finished = status.state != RolloutState::Progressing;
+                        if tx.send(status.clone()).is_err() || finished {
+                            return;
+                        }
+                        last = Some(status);
+                    }
+                    Ok(None) => break "the watch ended".to_string(),
+                    Err(e) => break e.to_string(),
+                }
+            };
+            // Otherwise the overlay would keep showing the last progress line as if live
+            let _ = tx.send(RolloutStatus::watch_stopped(last.as_ref(), &reason));
+        });
+
+        Ok(RolloutWatcher { rx })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PodInfo {
+    pub name: String,
+    pub _namespace: String,
+    pub status: String,
+    pub ready: String,
+    pub restarts: i32,
+    pub age: String,
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    pub node: String,
+    pub ip: String,
+    /// Image of the container `kubectl exec` enters by default
+    pub image: String,
+    /// Containers in spec order with their current state, for picking a log target
+    pub containers: Vec<ContainerSummary>,
+    /// Container named by the `kubectl.kubernetes.io/default-container` annotation,
+    /// if the pod has one by that name
+    pub default_container: Option<String>,
+    pub last_termination: Option<TerminationInfo>,
+    /// Status as `kubectl get pods` shows it; `status` is the bare phase
+    pub computed_status: String,
+    /// Guaranteed, Burstable or BestEffort; empty until the API has assigned it
+    pub qos_class: String,
+    /// Controller that created the pod, e.g. `ReplicaSet/web-5d8f7`
+    pub owner: Option<String>,
+    /// `None` when the pod has no init containers
+    pub init: Option<InitProgress>,
+}
+
+/// How far a pod is through its init containers
+#[derive(Debug, Clone, PartialEq)]
+pub struct InitProgress {
+    pub done: usize,
+    pub total: usize,
+    /// Why the next init container isn't progressing, e.g. CrashLoopBackOff
+    pub blocked: Option<String>,
+}
+
+impl InitProgress {
+    fn from_pod(pod: &Pod) -> Option<Self> {
+        let specs = pod.spec.as_ref()?.init_containers.as_deref()?;
+        if specs.is_empty() {
+            return None;
+        }
+        let statuses = pod
+            .status
+            .as_ref()
+            .and_then(|s| s.init_container_statuses.as_deref())
+            .unwrap_or_default();
+
+        let mut done = 0;
+        let mut blocked = None;
+        for spec in specs {
+            let state = statuses
+                .iter()
+                .find(|s| s.name == spec.name)
+                .and_then(|s| s.state.as_ref());
+            // A sidecar (restartPolicy: Always) has done its part once it runs
+            let sidecar = spec.restart_policy.as_deref() == Some("Always");
+            let finished = state.is_some_and(|s| {
+                s.terminated.as_ref().is_some_and(|t| t.exit_code == 0)
+                    || (sidecar && s.running.is_some())
+            });
+            if finished {
+                done += 1;
+                continue;
+            }
+            blocked = state.and_then(|s| {
+                if let Some(terminated) = &s.terminated {
+                    return Some(
+                        terminated
+                            .reason
+                            .clone()
+                            .unwrap_or_else(|| format!("ExitCode:{}", terminated.exit_code)),
+                    );
+                }
+                s.waiting
+                    .as_ref()
+                    .and_then(|w| w.reason.clone())
+                    .filter(|r| r != "PodInitializing")
+            });
+            break;
+        }
+
+        Some(Self {
+            done,
+            total: specs.len(),
+            blocked,
+        })
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.done == self.total
+    }
+}
+
+impl std::fmt::Display for InitProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.done, self.total)?;
+        if let Some(reason) = &self.blocked {
+            write!(f, " {}", reason)?;
+        }
+        Ok(())
+    }
+}
+
+/// A container's name and how it's doing, as shown next to the log container picker
+#[derive(Debug, Clone)]
+pub struct ContainerSummary {
+    pub name: String,
+    pub image: String,
+    pub health: ContainerHealth,
+    pub last_termination: Option<TerminationInfo>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContainerHealth {
+    Running,
+    Waiting,
+    /// Waiting in `CrashLoopBackOff`: the container keeps dying on start
+    CrashLooping,
+    Terminated,
+    Unknown,
+}
+
+impl ContainerHealth {
+    fn from_state(state: Option<&ContainerState>) -> Self {
+        let Some(state) = state else {
+            return ContainerHealth::Unknown;
+        };
+        if let Some(waiting) = &state.waiting {
+            if waiting.reason.as_deref() == Some("CrashLoopBackOff") {
+                ContainerHealth::CrashLooping
+            } else {
+                ContainerHealth::Waiting
+            }
+        } else if state.terminated.is_some() {
+            ContainerHealth::Terminated
+        } else if state.running.is_some() {
+            ContainerHealth::Running
+        } else {
+            ContainerHealth::Unknown
+        }
+    }
+}
+
+/// The STATUS `kubectl get pods` prints: the pod phase unless deletion, an init
+/// container or a container's waiting/terminated reason says more
+fn computed_pod_status(pod: &Pod, phase: &str) -> String {
+    if pod.metadata.deletion_timestamp.is_some() {
+        return "Terminating".to_string();
+    }
+    let Some(status) = pod.status.as_ref() else {
+        return phase.to_string();
+    };
+    let mut reason = status.reason.clone().unwrap_or_else(|| phase.to_string());
+
+    let init_statuses = status
+        .init_container_statuses
+        .as_deref()
+        .unwrap_or_default();
+    for (i, init) in init_statuses.iter().enumerate() {
+        let state = init.state.as_ref();
+        if let Some(terminated) = state.and_then(|s| s.terminated.as_ref()) {
+            if terminated.exit_code == 0 {
+                continue;
+            }
+            return match &terminated.reason {
+                Some(r) => format!("Init:{}", r),
+                None => format!("Init:ExitCode:{}", terminated.exit_code),
+            };
+        }
+        return match state
+            .and_then(|s| s.waiting.as_ref())
+            .and_then(|w| w.reason.as_deref())
+        {
+            Some(r) if r != "PodInitializing" => format!("Init:{}", r),
+            _ => format!("Init:{}/{}", i, init_statuses.len()),
+        };
+    }
+
+    let mut has_running = false;
+    let statuses = status.container_statuses.as_deref().unwrap_or_default();
+    // Like kubectl, the first container with something to say wins
+    for container in statuses.iter().rev() {
+        let state = container.state.as_ref();
+        if let Some(r) = state
+            .and_then(|s| s.waiting.as_ref())
+            .and_then(|w| w.reason.clone())
+        {
+            reason = r;
+        } else if let Some(terminated) = state.and_then(|s| s.terminated.as_ref()) {
+            reason = terminated
+                .reason
+                .clone()
+                .unwrap_or_else(|| format!("ExitCode:{}", terminated.exit_code));
+        } else if container.ready && state.is_some_and(|s| s.running.is_some()) {
+            has_running = true;
+        }
+    }
+    // A finished sidecar doesn't make a still-serving pod "Completed"
+    if reason == "Completed" && has_running {
+        reason = "Running".to_string();
+    }
+    reason
+}
+
+/// How a container's previous instance ended, from `lastState.terminated`
+#[derive(Debug, Clone)]
+pub struct TerminationInfo {
+    pub container: String,
+    pub reason: String,
+    pub exit_code: i32,
+    pub finished_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// How often this container has restarted, so the ended instance is restart #N
+    pub restart_count: i32,
+}
+
+impl TerminationInfo {
+    fn from_status(status: &ContainerStatus) -> Option<Self> {
+        let terminated = status.last_state.as_ref()?.terminated.as_ref()?;
+        Some(TerminationInfo {
+            container: status.name.clone(),
+            reason: terminated
+                .reason
+                .clone()
+                .unwrap_or_else(|| "Unknown".to_string()),
+            exit_code: terminated.exit_code,
+            finished_at: terminated.finished_at.as_ref().map(|t| t.0),
+            restart_count: status.restart_count,
+        })
+    }
+}
+
+impl PodInfo {
+    pub fn from_pod(pod: &Pod) -> Self {
+        let name = pod.metadata.name.clone().unwrap_or_default();
+        let namespace = pod.metadata.namespace.clone().unwrap_or_default();
+
+        let status = pod
+            .status
+            .as_ref()
+            .and_then(|s| s.phase.clone())
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        let (ready_count, total_count) = pod
+            .status
+            .as_ref()
+            .and_then(|s| s.container_statuses.as_ref())
+            .map(|cs| {
+                let ready = cs.iter().filter(|c| c.ready).count();
+                (ready, cs.len())
+            })
+            .unwrap_or((0, 0));
+
+        let ready = format!("{}/{}", ready_count, total_count);
+
+        let restarts = pod
+            .status
+            .as_ref()
+            .and_then(|s| s.container_statuses.as_ref())
+            .map(|cs| cs.iter().map(|c| c.restart_count).sum())
+            .unwrap_or(0);
+
+        let created = pod.metadata.creation_timestamp.as_ref().map(|t| t.0);
+        let age = created
+            .as_ref()
+            .map(format_age)
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        let last_termination = pod
+            .status
+            .as_ref()
+            .and_then(|s| s.container_statuses.as_ref())
+            .and_then(|cs| cs.iter().find_map(TerminationInfo::from_status));
+
+        let node = pod
+            .spec
+            .as_ref()
+            .and_then(|s| s.node_name.clone())
+            .unwrap_or_else(|| "<none>".to_string());
+
+        let ip = pod
+            .status
+            .as_ref()
+            .and_then(|s| s.pod_ip.clone())
+            .unwrap_or_else(|| "<none>".to_string());
+
+        let spec_containers = pod
+            .spec
+            .as_ref()
+            .map(|s| s.containers.as_slice())
+            .unwrap_or_default();
+        // Like kubectl, an annotation naming no container is ignored
+        let default_container = pod
+            .metadata
+            .annotations
+            .as_ref()
+            .and_then(|a| a.get(DEFAULT_CONTAINER_ANNOTATION))
+            .filter(|name| spec_containers.iter().any(|c| &c.name == *name))
+            .cloned();
+        let image = spec_containers
+            .iter()
+            .find(|c| Some(&c.name) == default_container.as_ref())
+            .or(spec_containers.first())
+            .and_then(|c| c.image.clone())
+            .unwrap_or_default();
+
+        let statuses = pod
+            .status
+            .as_ref()
+            .and_then(|s| s.container_statuses.as_deref())
+            .unwrap_or_default();
+        let containers = spec_containers
+            .iter()
+            .map(|c| {
+                let status = statuses.iter().find(|s| s.name == c.name);
+                ContainerSummary {
+                    name: c.name.clone(),
+                    image: c.image.clone().unwrap_or_default(),
+                    health: ContainerHealth::from_state(status.and_then(|s| s.state.as_ref())),
+                    last_termination: status.and_then(TerminationInfo::from_status),
+                }
+            })
+            .collect();
+
+        let computed_status = computed_pod_status(pod, &status);
+
+        let qos_class = pod
+            .status
+            .as_ref()
+            .and_then(|s| s.qos_class.clone())
+            .unwrap_or_default();
+
+        let owner = pod.metadata.owner_references.as_ref().and_then(|refs| {
+            refs.iter()
+                .find(|r| r.controller == Some(true))
+                .or_else(|| refs.first())
+                .map(|r| format!("{}/{}", r.kind, r.name))
+        });
+
+        Self {
+            name,
+            _namespace: namespace,
+            status,
+            ready,
+            restarts,
+            age,
+            created,
+            node,
+            ip,
+            image,
+            containers,
+            default_container,
+            last_termination,
+            computed_status,
+            qos_class,
+            owner,
+            init: InitProgress::from_pod(pod),
+        }
+    }
+
+    /// Container that logs open on and debug containers target: the annotated
+    /// default, else the first. `None` for single-container pods, where the API
+    /// doesn't need one.
+    pub fn log_container(&self) -> Option<String> {
+        if self.containers.len() < 2 {
+            return None;
+        }
+        self.default_container
+            .clone()
+            .or_else(|| self.containers.first().map(|c| c.name.clone()))
+    }
+
+    /// One line of context for the selected pod, shown under the Pods table
+    pub fn summary_line(&self) -> String {
+        let mut parts = vec![
+            format!("node {}", self.node),
+            format!("ip {}", self.ip),
+            format!(
+                "qos {}",
+                if self.qos_class.is_empty() {
+                    "-"
+                } else {
+                    &self.qos_class
+                }
+            ),
+            format!("owner {}", self.owner.as_deref().unwrap_or("none")),
+        ];
+        if let Some(termination) = &self.last_termination {
+            parts.push(format!(
+                "last restart {} (exit {}) in {}",
+                termination.reason, termination.exit_code, termination.container
+            ));
+        }
+        parts.join("  │  ")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PodCondition {
+    pub type_: String,
+    pub status: String,
+    pub reason: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PodDetail {
+    pub name: String,
+    pub namespace: String,
+    pub phase: String,
+    pub node: Option<String>,
+    pub pod_ip: Option<String>,
+    pub conditions: Vec<PodCondition>,
+    pub node_selector: BTreeMap<String, String>,
+    pub affinity: Vec<String>,
+    pub tolerations: Vec<String>,
+    pub containers: Vec<ContainerRestarts>,
+    pub probes: Vec<ContainerProbes>,
+    pub volumes: Vec<VolumeInfo>,
+    /// Mounts per container, init containers first; containers without any are left out
+    pub mounts: Vec<(String, Vec<MountInfo>)>,
+    /// Config checksums and restart stamps, see `rollout_annotations`
+    pub rollout_annotations: BTreeMap<String, String>,
+}
+
+/// One container's usage as metrics-server last sampled it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerUsage {
+    pub container: String,
+    pub cpu_millis: u64,
+    pub memory_bytes: u64,
+}
+
+/// A pod volume and what backs it, e.g. `configMap app-config`
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeInfo {
+    pub name: String,
+    pub source: String,
+}
+
+impl VolumeInfo {
+    fn from_volume(volume: &Volume) -> Self {
+        let named = |kind: &str, name: Option<&String>| match name {
+            Some(name) => format!("{} {}", kind, name),
+            None => kind.to_string(),
+        };
+        let source = if let Some(cm) = &volume.config_map {
+            named("configMap", Some(&cm.name))
+        } else if let Some(secret) = &volume.secret {
+            named("secret", secret.secret_name.as_ref())
+        } else if let Some(pvc) = &volume.persistent_volume_claim {
+            named("pvc", Some(&pvc.claim_name))
+        } else if let Some(empty_dir) = &volume.empty_dir {
+            match &empty_dir.medium {
+                Some(medium) if !medium.is_empty() => format!("emptyDir ({})", medium),
+                _ => "emptyDir".to_string(),
+            }
+        } else if let Some(host_path) = &volume.host_path {
+            named("hostPath", Some(&host_path.path))
+        } else if volume.projected.is_some() {
+            "projected".to_string()
+        } else if volume.downward_api.is_some() {
+            "downwardAPI".to_string()
+        } else if let Some(csi) = &volume.csi {
+            named("csi", Some(&csi.driver))
+        } else if let Some(nfs) = &volume.nfs {
+            format!("nfs {}:{}", nfs.server, nfs.path)
+        } else if volume.ephemeral.is_some() {
+            "ephemeral".to_string()
+        } else {
+            "other".to_string()
+        };
+        Self {
+            name: volume.name.clone(),
+            source,
+        }
+    }
+}
+
+/// Where a container mounts a volume
+#[derive(Debug, Clone, PartialEq)]
+pub struct MountInfo {
+    pub volume: String,
+    pub path: String,
+    pub sub_path: Option<String>,
+    pub read_only: bool,
+}
+
+/// Configured probes of one container, alongside whether it currently passes
+#[derive(Debug, Clone)]
+pub struct ContainerProbes {
+    pub name: String,
+    pub ready: Option<bool>,
+    pub started: Option<bool>,
+    pub liveness: Option<String>,
+    pub readiness: Option<String>,
+    pub startup: Option<String>,
+}
+
+impl ContainerProbes {
+    fn from_container(container: &Container, status: Option<&ContainerStatus>) -> Self {
+        Self {
+            name: container.name.clone(),
+            ready: status.map(|s| s.ready),
+            started: status.and_then(|s| s.started),
+            liveness: container.liveness_probe.as_ref().map(describe_probe),
+            readiness: container.readiness_probe.as_ref().map(describe_probe),
+            startup: container.startup_probe.as_ref().map(describe_probe),
+        }
+    }
+}
+
+/// One-line probe summary, e.g. `http-get http://:8080/healthz delay=5s period=10s timeout=1s failures=3`
+fn describe_probe(probe: &Probe) -> String {
+    let port = |port: &IntOrString| match port {
+        IntOrString::Int(n) => n.to_string(),
+        IntOrString::String(name) => name.clone(),
+    };
+
+    let action = if let Some(http) = &probe.http_get {
+        // Same shape as `kubectl describe`: http-get http://host:port/path
+        format!(
+            "http-get {}://{}:{}{}",
+            http.scheme.as_deref().unwrap_or("HTTP").to_lowercase(),
+            http.host.as_deref().unwrap_or(""),
+            port(&http.port),
+            http.path.as_deref().unwrap_or("/")
+        )
+    } else if let Some(tcp) = &probe.tcp_socket {
+        format!("tcp-socket :{}", port(&tcp.port))
+    } else if let Some(exec) = &probe.exec {
+        format!(
+            "exec [{}]",
+            exec.command.clone().unwrap_or_default().join(" ")
+        )
+    } else if let Some(grpc) = &probe.grpc {
+        format!("grpc :{}", grpc.port)
+    } else {
+        "unknown".to_string()
+    };
+
+    // Defaults as documented for the Probe API
+    format!(
+        "{} delay={}s period={}s timeout={}s failures={}",
+        action,
+        probe.initial_delay_seconds.unwrap_or(0),
+        probe.period_seconds.unwrap_or(10),
+        probe.timeout_seconds.unwrap_or(1),
+        probe.failure_threshold.unwrap_or(3)
+    )
+}
+
+/// Restart history of one container. The kubelet only keeps the current and
+/// previous state, so `reasons` covers at most two instances of `restart_count`.
+#[derive(Debug, Clone)]
+pub struct ContainerRestarts {
+    pub name: String,
+    pub restart_count: i32,
+    pub state: String,
+    pub last_termination: Option<String>,
+    /// Termination reasons seen, with how often each occurred
+    pub reasons: Vec<(String, usize)>,
+}
+
+impl ContainerRestarts {
+    fn from_status(status: &ContainerStatus) -> Self {
+        let current = status.state.as_ref();
+        let previous = status
+            .last_state
+            .as_ref()
+            .and_then(|s| s.terminated.as_ref());
+
+        let mut reasons: Vec<(String, usize)> = Vec::new();
+        let observed = [
+            current.and_then(|s| s.terminated.as_ref()?.reason.clone()),
+            previous.map(|t| t.reason.clone().unwrap_or_else(|| "Unknown".to_string())),
+        ];
+        for reason in observed.into_iter().flatten() {
+            match reasons.iter_mut().find(|(r, _)| *r == reason) {
+                Some((_, count)) => *count += 1,
+                None => reasons.push((reason, 1)),
+            }
+        }
+        reasons.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+        Self {
+            name: status.name.clone(),
+            restart_count: status.restart_count,
+            state: current
+                .map(describe_container_state)
+                .unwrap_or_else(|| "Unknown".to_string()),
+            last_termination: previous.map(|t| {
+                let mut text = format!(
+                    "{} (exit {})",
+                    t.reason.as_deref().unwrap_or("Unknown"),
+                    t.exit_code
+                );
+                if let Some(finished) = &t.finished_at {
+                    text.push_str(&format!(", {} ago", format_age(&finished.0)));
+                }
+                text
+            }),
+            reasons,
+        }
+    }
+}
+
+fn describe_container_state(state: &ContainerState) -> String {
+    if let Some(waiting) = &state.waiting {
+        format!(
+            "Waiting ({})",
+            waiting.reason.as_deref().unwrap_or("Unknown")
+        )
+    } else if let Some(terminated) = &state.terminated {
+        format!(
+            "Terminated ({}, exit {})",
+            terminated.reason.as_deref().unwrap_or("Unknown"),
+            terminated.exit_code
+        )
+    } else if state.running.is_some() {
+        "Running".to_string()
+    } else {
+        "Unknown".to_string()
+    }
+}
+
+impl PodDetail {
+    pub fn from_pod(pod: &Pod) -> Self {
+        let spec = pod.spec.as_ref();
+        let status = pod.status.as_ref();
+
+        let conditions = status
+            .and_then(|s| s.conditions.as_ref())
+            .map(|conditions| {
+                conditions
+                    .iter()
+                    .map(|c| PodCondition {
+                        type_: c.type_.clone(),
+                        status: c.status.clone(),
+                        reason: c.reason.clone(),
+                        message: c.message.clone(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            name: pod.metadata.name.clone().unwrap_or_default(),
+            namespace: pod.metadata.namespace.clone().unwrap_or_default(),
+            rollout_annotations: rollout_annotations(&pod.metadata),
+            phase: status
+                .and_then(|s| s.phase.clone())
+                .unwrap_or_else(|| "Unknown".to_string()),
+            node: spec.and_then(|s| s.node_name.clone()),
+            pod_ip: status.and_then(|s| s.pod_ip.clone()),
+            conditions,
+            node_selector: spec
+                .and_then(|s| s.node_selector.clone())
+                .unwrap_or_default(),
+            affinity: spec
+                .and_then(|s| s.affinity.as_ref())
+                .map(describe_affinity)
+                .unwrap_or_default(),
+            tolerations: spec
+                .and_then(|s| s.tolerations.as_ref())
+                .map(|t| t.iter().map(describe_toleration).collect())
+                .unwrap_or_default(),
+            containers: status
+                .into_iter()
+                .flat_map(|s| {
+                    s.init_container_statuses
+                        .iter()
+                        .flatten()
+                        .chain(s.container_statuses.iter().flatten())
+                })
+                .map(ContainerRestarts::from_status)
+                .collect(),
+            volumes: spec
+                .and_then(|s| s.volumes.as_ref())
+                .map(|volumes| volumes.iter().map(VolumeInfo::from_volume).collect())
+                .unwrap_or_default(),
+            mounts: spec
+                .into_iter()
+                .flat_map(|s| s.init_containers.iter().flatten().chain(&s.containers))
+                .filter_map(|c| {
+                    let mounts: Vec<MountInfo> = c
+                        .volume_mounts
+                        .iter()
+                        .flatten()
+                        .map(|m| MountInfo {
+                            volume: m.name.clone(),
+                            path: m.mount_path.clone(),
+                            sub_path: m.sub_path.clone().filter(|p| !p.is_empty()),
+                            read_only: m.read_only.unwrap_or(false),
+                        })
+                        .collect();
+                    (!mounts.is_empty()).then(|| (c.name.clone(), mounts))
+                })
+                .collect(),
+            probes: spec
+                .map(|spec| {
+                    let statuses = status.and_then(|s| s.container_statuses.as_ref());
+                    spec.containers
+                        .iter()
+                        .map(|c| {
+                            let status = statuses
+                                .and_then(|statuses| statuses.iter().find(|s| s.name == c.name));
+                            ContainerProbes::from_container(c, status)
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// The scheduler's explanation when the pod couldn't be placed on a node
+    pub fn unschedulable_message(&self) -> Option<&str> {
+        self.conditions
+            .iter()
+            .find(|c| c.type_ == "PodScheduled" && c.status == "False")
+            .and_then(|c| c.message.as_deref())
+    }
+}
+
+/// Search hits among listed object names (`query` is already lowercase)
+fn matching_names<K: Clone>(
+    kind: ResourceKind,
+    list: ObjectList<PartialObjectMeta<K>>,
+    query: &str,
+) -> Vec<SearchResult> {
+    list.items
+        .into_iter()
+        .filter_map(|item| {
+            let name = item.metadata.name?;
+            name.to_lowercase().contains(query).then(|| SearchResult {
+                kind,
+                namespace: item.metadata.namespace.unwrap_or_default(),
+                name,
+            })
+        })
+        .collect()
+}
+
+/// Flatten affinity rules into one readable line per term
+fn describe_affinity(affinity: &Affinity) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    if let Some(node) = &affinity.node_affinity {
+        if let Some(required) = &node.required_during_scheduling_ignored_during_execution {
+            for term in &required.node_selector_terms {
+                lines.push(format!("node required: {}", describe_node_term(term)));
+            }
+        }
+        if let Some(preferred) = &node.preferred_during_scheduling_ignored_during_execution {
+            for term in preferred {
+                lines.push(format!(
+                    "node preferred (weight {}): {}",
+                    term.weight,
+                    describe_node_term(&term.preference)
+                ));
+            }
+        }
+    }
+
+    for (kind, required, preferred) in [
+        (
+            "pod affinity",
+            affinity.pod_affinity.as_ref().and_then(|a| {
+                a.required_during_scheduling_ignored_during_execution
+                    .as_ref()
+            }),
+            affinity.pod_affinity.as_ref().and_then(|a| {
+                a.preferred_during_scheduling_ignored_during_execution
+                    .as_ref()
+            }),
+        ),
+        (
+            "pod anti-affinity",
+            affinity.pod_anti_affinity.as_ref().and_then(|a| {
+                a.required_during_scheduling_ignored_during_execution
+                    .as_ref()
+            }),
+            affinity.pod_anti_affinity.as_ref().and_then(|a| {
+                a.preferred_during_scheduling_ignored_during_execution
+                    .as_ref()
+            }),
+        ),
+    ] {
+        for term in required.into_iter().flatten() {
+            lines.push(format!("{} required: {}", kind, describe_pod_term(term)));
+        }
+        for term in preferred.into_iter().flatten() {
+            lines.push(format!(
+                "{} preferred (weight {}): {}",
+                kind,
+                term.weight,
+                describe_pod_term(&term.pod_affinity_term)
+            ));
+        }
+    }
+
+    lines
+}
+
+fn describe_node_term(term: &NodeSelectorTerm) -> String {
+    let requirements: Vec<String> = term
+        .match_expressions
+        .iter()
+        .chain(term.match_fields.iter())
+        .flatten()
+        .map(|r| describe_requirement(&r.key, &r.operator, r.values.as_deref()))
+        .collect();
+    if requirements.is_empty() {
+        "<any node>".to_string()
+    } else {
+        requirements.join(", ")
+    }
+}
+
+fn describe_pod_term(term: &PodAffinityTerm) -> String {
+    format!(
+        "pods matching {} per {}",
+        term.label_selector
+            .as_ref()
+            .map(describe_label_selector)
+            .unwrap_or_else(|| "<none>".to_string()),
+        term.topology_key
+    )
+}
+
+fn describe_label_selector(selector: &LabelSelector) -> String {
+    let mut parts: Vec<String> = selector
+        .match_labels
+        .iter()
+        .flatten()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect();
+    parts.extend(
+        selector
+            .match_expressions
+            .iter()
+            .flatten()
+            .map(|r| describe_requirement(&r.key, &r.operator, r.values.as_deref())),
+    );
+    if parts.is_empty() {
+        "<everything>".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+fn describe_requirement(key: &str, operator: &str, values: Option<&[String]>) -> String {
+    match values {
+        Some(values) if !values.is_empty() => {
+            format!("{} {} [{}]", key, operator, values.join(","))
+        }
+        _ => format!("{} {}", key, operator),
+    }
+}
+
+/// A node as `kubectl describe node` summarizes it
+#[derive(Debug, Clone)]
+pub struct NodeDetail {
+    pub name: String,
+    /// From the `node-role.kubernetes.io/<role>` labels
+    pub roles: Vec<String>,
+    pub unschedulable: bool,
+    pub conditions: Vec<PodCondition>,
+    pub resources: Vec<NodeResource>,
+    pub taints: Vec<String>,
+    pub labels: BTreeMap<String, String>,
+    pub kubelet_version: String,
+    pub os_image: String,
+    pub kernel_version: String,
+    pub container_runtime: String,
+    /// Pods on the node that haven't finished; `None` if they couldn't be listed
+    pub pod_count: Option<usize>,
+}
+
+/// One resource the node reports, e.g. cpu, in the API's quantity notation
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeResource {
+    pub name: String,
+    pub capacity: String,
+    /// What's left for pods after system reservations
+    pub allocatable: String,
+}
+
+impl NodeDetail {
+    pub fn from_node(node: &Node, pod_count: Option<usize>) -> Self {
+        let labels = node.metadata.labels.clone().unwrap_or_default();
+        let spec = node.spec.as_ref();
+        let status = node.status.as_ref();
+        let info = status.and_then(|s| s.node_info.as_ref());
+
+        let capacity = status.and_then(|s| s.capacity.clone()).unwrap_or_default();
+        let allocatable = status
+            .and_then(|s| s.allocatable.clone())
+            .unwrap_or_default();
+        let resources = capacity
+            .iter()
+            .map(|(name, quantity)| NodeResource {
+                name: name.clone(),
+                capacity: quantity.0.clone(),
+                allocatable: allocatable
+                    .get(name)
+                    .map(|q| q.0.clone())
+                    .unwrap_or_else(|| "-".to_string()),
+            })
+            .collect();
+
+        Self {
+            name: node.metadata.name.clone().unwrap_or_default(),
+            roles: labels
+                .keys()
+                .filter_map(|key| key.strip_prefix("node-role.kubernetes.io/"))
+                .filter(|role| !role.is_empty())
+                .map(|role| role.to_string())
+                .collect(),
+            unschedulable: spec.and_then(|s| s.unschedulable).unwrap_or(false),
+            conditions: status
+                .and_then(|s| s.conditions.as_ref())
+                .map(|conditions| {
+                    conditions
+                        .iter()
+                        .map(|c| PodCondition {
+                            type_: c.type_.clone(),
+                            status: c.status.clone(),
+                            reason: c.reason.clone(),
+                            message: c.message.clone(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            resources,
+            taints: spec
+                .and_then(|s| s.taints.as_ref())
+                .map(|taints| {
+                    taints
+                        .iter()
+                        .map(|t| match &t.value {
+                            Some(value) => format!("{}={}:{}", t.key, value, t.effect),
+                            None => format!("{}:{}", t.key, t.effect),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            labels,
+            kubelet_version: info.map(|i| i.kubelet_version.clone()).unwrap_or_default(),
+            os_image: info.map(|i| i.os_image.clone()).unwrap_or_default(),
+            kernel_version: info.map(|i| i.kernel_version.clone()).unwrap_or_default(),
+            container_runtime: info
+                .map(|i| i.container_runtime_version.clone())
+                .unwrap_or_default(),
+            pod_count,
+        }
+    }
+}
+
+fn describe_toleration(toleration: &Toleration) -> String {
+    let key = toleration.key.as_deref().unwrap_or("<all taints>");
+    let mut text = match (toleration.operator.as_deref(), &toleration.value) {
+        (Some("Exists"), _) => format!("{} exists", key),
+        (_, Some(value)) => format!("{}={}", key, value),
+        _ => key.to_string(),
+    };
+    text.push_str(&format!(
+        " :{}",
+        toleration.effect.as_deref().unwrap_or("<all effects>")
+    ));
+    if let Some(seconds) = toleration.toleration_seconds {
+        text.push_str(&format!(" for {}s", seconds));
+    }
+    text
+}
+
+#[derive(Debug, Clone)]
+pub struct DeploymentInfo {
+    pub name: String,
+    pub _namespace: String,
+    pub ready: String,
+    /// Desired replicas from the spec
+    pub replicas: i32,
+    pub up_to_date: i32,
+    pub available: i32,
+    pub age: String,
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl DeploymentInfo {
+    fn from_deployment(dep: &Deployment) -> Self {
+        let name = dep.metadata.name.clone().unwrap_or_default();
+        let namespace = dep.metadata.namespace.clone().unwrap_or_default();
+
+        let desired = dep.spec.as_ref().and_then(|s| s.replicas).unwrap_or(0);
+        let ready = dep
+            .status
+            .as_ref()
+            .and_then(|s| s.ready_replicas)
+            .unwrap_or(0);
+        let ready_str = format!("{}/{}", ready, desired);
+
+        let up_to_date = dep
+            .status
+            .as_ref()
+            .and_then(|s| s.updated_replicas)
+            .unwrap_or(0);
+        let available = dep
+            .status
             .as_ref()
-            .map(|t| format_age(&t.0))
+            .and_then(|s| s.available_replicas)
+            .unwrap_or(0);
+
+        let created = dep.metadata.creation_timestamp.as_ref().map(|t| t.0);
+        let age = created
+            .as_ref()
+            .map(format_age)
             .unwrap_or_else(|| "Unknown".to_string());
 
         Self {
             name,
             _namespace: namespace,
             ready: ready_str,
+            replicas: desired,
             up_to_date,
             available,
             age,
+            created,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeploymentDetail {
+    pub name: String,
+    pub namespace: String,
+    pub replicas: String,
+    pub strategy: String,
+    pub containers: Vec<ContainerEnv>,
+    /// The pod template's config checksums and restart stamp, see `rollout_annotations`
+    pub rollout_annotations: BTreeMap<String, String>,
+    /// How many of the deployment's pods were compared with the template
+    pub pod_count: usize,
+    /// Pods whose rollout annotations differ from the template's, i.e. that
+    /// haven't picked up the latest config or restart yet
+    pub stale_pods: Vec<String>,
+}
+
+/// A deployment's rollout history, newest revision first
+#[derive(Debug, Clone)]
+pub struct DeploymentHistory {
+    pub name: String,
+    pub namespace: String,
+    /// Revision the deployment's pods currently run
+    pub current_revision: Option<i64>,
+    pub revisions: Vec<RevisionInfo>,
+}
+
+/// One revision, as recorded on the ReplicaSet that runs it
+#[derive(Debug, Clone)]
+pub struct RevisionInfo {
+    pub revision: i64,
+    pub replica_set: String,
+    /// `kubernetes.io/change-cause`, set by `kubectl annotate` or `--record`
+    pub change_cause: Option<String>,
+    pub images: Vec<String>,
+    pub age: String,
+}
+
+impl RevisionInfo {
+    fn from_replica_set(rs: &ReplicaSet) -> Option<Self> {
+        let images = rs
+            .spec
+            .as_ref()
+            .and_then(|s| s.template.as_ref())
+            .and_then(|t| t.spec.as_ref())
+            .map(|s| {
+                s.containers
+                    .iter()
+                    .filter_map(|c| c.image.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Some(Self {
+            revision: revision_of(&rs.metadata)?,
+            replica_set: rs.metadata.name.clone().unwrap_or_default(),
+            change_cause: rs
+                .metadata
+                .annotations
+                .as_ref()
+                .and_then(|a| a.get("kubernetes.io/change-cause").cloned()),
+            images,
+            age: rs
+                .metadata
+                .creation_timestamp
+                .as_ref()
+                .map(|t| format_age(&t.0))
+                .unwrap_or_else(|| "Unknown".to_string()),
+        })
+    }
+}
+
+/// The `deployment.kubernetes.io/revision` annotation the controller stamps
+fn revision_of(meta: &ObjectMeta) -> Option<i64> {
+    meta.annotations
+        .as_ref()?
+        .get("deployment.kubernetes.io/revision")?
+        .parse()
+        .ok()
+}
+
+/// The environment one container is started with, as written in the spec
+#[derive(Debug, Clone)]
+pub struct ContainerEnv {
+    pub name: String,
+    pub image: String,
+    pub env: Vec<EnvVarInfo>,
+    /// Whole secrets/configmaps imported with `envFrom`
+    pub env_from: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EnvVarInfo {
+    pub name: String,
+    pub value: EnvValue,
+}
+
+/// Where an env var's value comes from. References are shown by source name;
+/// secret contents are never fetched.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnvValue {
+    Plain(String),
+    SecretKey { name: String, key: String },
+    ConfigMapKey { name: String, key: String },
+    Field(String),
+    Resource(String),
+}
+
+impl EnvValue {
+    pub fn describe(&self) -> String {
+        match self {
+            EnvValue::Plain(value) => value.clone(),
+            EnvValue::SecretKey { name, key } => format!("<secret {}/{}>", name, key),
+            EnvValue::ConfigMapKey { name, key } => format!("<configmap {}/{}>", name, key),
+            EnvValue::Field(path) => format!("<field {}>", path),
+            EnvValue::Resource(resource) => format!("<resource {}>", resource),
+        }
+    }
+}
+
+impl ContainerEnv {
+    fn from_container(container: &Container) -> Self {
+        let env = container
+            .env
+            .iter()
+            .flatten()
+            .map(|var| {
+                let source = var.value_from.as_ref();
+                let value = if let Some(secret) = source.and_then(|s| s.secret_key_ref.as_ref()) {
+                    EnvValue::SecretKey {
+                        name: secret.name.clone(),
+                        key: secret.key.clone(),
+                    }
+                } else if let Some(cm) = source.and_then(|s| s.config_map_key_ref.as_ref()) {
+                    EnvValue::ConfigMapKey {
+                        name: cm.name.clone(),
+                        key: cm.key.clone(),
+                    }
+                } else if let Some(field) = source.and_then(|s| s.field_ref.as_ref()) {
+                    EnvValue::Field(field.field_path.clone())
+                } else if let Some(resource) = source.and_then(|s| s.resource_field_ref.as_ref()) {
+                    EnvValue::Resource(resource.resource.clone())
+                } else {
+                    EnvValue::Plain(var.value.clone().unwrap_or_default())
+                };
+                EnvVarInfo {
+                    name: var.name.clone(),
+                    value,
+                }
+            })
+            .collect();
+
+        let env_from = container
+            .env_from
+            .iter()
+            .flatten()
+            .filter_map(|source| {
+                let prefix = source
+                    .prefix
+                    .as_ref()
+                    .map(|p| format!(" (prefix {})", p))
+                    .unwrap_or_default();
+                if let Some(secret) = &source.secret_ref {
+                    Some(format!("secret {}{}", secret.name, prefix))
+                } else {
+                    source
+                        .config_map_ref
+                        .as_ref()
+                        .map(|cm| format!("configmap {}{}", cm.name, prefix))
+                }
+            })
+            .collect();
+
+        Self {
+            name: container.name.clone(),
+            image: container.image.clone().unwrap_or_default(),
+            env,
+            env_from,
+        }
+    }
+}
+
+impl DeploymentDetail {
+    pub fn from_deployment(dep: &Deployment) -> Self {
+        let spec = dep.spec.as_ref();
+        let desired = spec.and_then(|s| s.replicas).unwrap_or(1);
+        let ready = dep
+            .status
+            .as_ref()
+            .and_then(|s| s.ready_replicas)
+            .unwrap_or(0);
+
+        Self {
+            name: dep.metadata.name.clone().unwrap_or_default(),
+            namespace: dep.metadata.namespace.clone().unwrap_or_default(),
+            replicas: format!("{}/{}", ready, desired),
+            strategy: spec
+                .and_then(|s| s.strategy.as_ref())
+                .and_then(|s| s.type_.clone())
+                .unwrap_or_else(|| "RollingUpdate".to_string()),
+            containers: spec
+                .and_then(|s| s.template.spec.as_ref())
+                .map(|s| {
+                    s.containers
+                        .iter()
+                        .map(ContainerEnv::from_container)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            rollout_annotations: spec
+                .and_then(|s| s.template.metadata.as_ref())
+                .map(rollout_annotations)
+                .unwrap_or_default(),
+            pod_count: 0,
+            stale_pods: Vec::new(),
+        }
+    }
+
+    /// Compare the deployment's pods' rollout annotations with the template's
+    pub fn compare_pods(&mut self, pods: &[Pod]) {
+        self.pod_count = pods.len();
+        self.stale_pods = pods
+            .iter()
+            .filter(|pod| rollout_annotations(&pod.metadata) != self.rollout_annotations)
+            .filter_map(|pod| pod.metadata.name.clone())
+            .collect();
+    }
+}
+
+/// Annotations that exist to roll pods when something outside the spec
+/// changes: config checksums (e.g. Helm's `checksum/config`) and the
+/// `kubectl.kubernetes.io/restartedAt` stamp of `kubectl rollout restart`
+fn rollout_annotations(metadata: &ObjectMeta) -> BTreeMap<String, String> {
+    metadata
+        .annotations
+        .iter()
+        .flatten()
+        .filter(|(key, _)| key.contains("checksum") || key.ends_with("/restartedAt"))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RolloutState {
+    Progressing,
+    Complete,
+    Failed,
+    /// The watch ended before the rollout did; the counts are the last ones seen
+    WatchStopped,
+}
+
+/// Where a deployment's rollout stands, worded like `kubectl rollout status`
+#[derive(Debug, Clone)]
+pub struct RolloutStatus {
+    pub state: RolloutState,
+    pub message: String,
+    pub desired: i32,
+    pub updated: i32,
+    pub available: i32,
+}
+
+impl RolloutStatus {
+    /// The watch gave up for `reason`, keeping the counts of the `last` update
+    pub fn watch_stopped(last: Option<&RolloutStatus>, reason: &str) -> Self {
+        Self {
+            state: RolloutState::WatchStopped,
+            message: format!("Stopped watching the rollout: {}", reason),
+            desired: last.map_or(0, |s| s.desired),
+            updated: last.map_or(0, |s| s.updated),
+            available: last.map_or(0, |s| s.available),
+        }
+    }
+
+    fn from_deployment(dep: &Deployment) -> Self {
+        let name = dep.metadata.name.clone().unwrap_or_default();
+        let desired = dep.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+        let status = dep.status.clone().unwrap_or_default();
+        let replicas = status.replicas.unwrap_or(0);
+        let updated = status.updated_replicas.unwrap_or(0);
+        let available = status.available_replicas.unwrap_or(0);
+
+        let observed =
+            dep.metadata.generation.unwrap_or(0) <= status.observed_generation.unwrap_or(0);
+        let deadline_exceeded = status.conditions.iter().flatten().any(|c| {
+            c.type_ == "Progressing" && c.reason.as_deref() == Some("ProgressDeadlineExceeded")
+        });
+
+        let (state, message) = if !observed {
+            (
+                RolloutState::Progressing,
+                "Waiting for deployment spec update to be observed...".to_string(),
+            )
+        } else if deadline_exceeded {
+            (
+                RolloutState::Failed,
+                format!("deployment \"{}\" exceeded its progress deadline", name),
+            )
+        } else if updated < desired {
+            (
+                RolloutState::Progressing,
+                format!(
+                    "Waiting for deployment \"{}\" rollout to finish: {} out of {} new replicas have been updated...",
+                    name, updated, desired
+                ),
+            )
+        } else if replicas > updated {
+            (
+                RolloutState::Progressing,
+                format!(
+                    "Waiting for deployment \"{}\" rollout to finish: {} old replicas are pending termination...",
+                    name,
+                    replicas - updated
+                ),
+            )
+        } else if available < updated {
+            (
+                RolloutState::Progressing,
+                format!(
+                    "Waiting for deployment \"{}\" rollout to finish: {} of {} updated replicas are available...",
+                    name, available, updated
+                ),
+            )
+        } else {
+            (
+                RolloutState::Complete,
+                format!("deployment \"{}\" successfully rolled out", name),
+            )
+        };
+
+        Self {
+            state,
+            message,
+            desired,
+            updated,
+            available,
         }
     }
 }
@@ -682,6 +3858,7 @@ pub struct ServiceInfo {
     pub cluster_ip: String,
     pub ports: String,
     pub age: String,
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 impl ServiceInfo {
@@ -720,11 +3897,10 @@ impl ServiceInfo {
             })
             .unwrap_or_else(|| "None".to_string());
 
-        let age = svc
-            .metadata
-            .creation_timestamp
+        let created = svc.metadata.creation_timestamp.as_ref().map(|t| t.0);
+        let age = created
             .as_ref()
-            .map(|t| format_age(&t.0))
+            .map(format_age)
             .unwrap_or_else(|| "Unknown".to_string());
 
         Self {
@@ -734,6 +3910,228 @@ impl ServiceInfo {
             cluster_ip,
             ports,
             age,
+            created,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfigMapInfo {
+    pub name: String,
+    /// Keys in `data` and `binaryData` together
+    pub data_keys: usize,
+    pub age: String,
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ConfigMapInfo {
+    fn from_config_map(config_map: &ConfigMap) -> Self {
+        let created = config_map.metadata.creation_timestamp.as_ref().map(|t| t.0);
+        Self {
+            name: config_map.metadata.name.clone().unwrap_or_default(),
+            data_keys: config_map.data.as_ref().map_or(0, |d| d.len())
+                + config_map.binary_data.as_ref().map_or(0, |d| d.len()),
+            age: created
+                .as_ref()
+                .map(format_age)
+                .unwrap_or_else(|| "Unknown".to_string()),
+            created,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfigMapDetail {
+    pub name: String,
+    pub namespace: String,
+    pub data: BTreeMap<String, String>,
+    /// Size in bytes of each `binaryData` value, which isn't worth previewing
+    pub binary_data: BTreeMap<String, usize>,
+}
+
+impl ConfigMapDetail {
+    fn from_config_map(config_map: &ConfigMap) -> Self {
+        Self {
+            name: config_map.metadata.name.clone().unwrap_or_default(),
+            namespace: config_map.metadata.namespace.clone().unwrap_or_default(),
+            data: config_map.data.clone().unwrap_or_default(),
+            binary_data: config_map
+                .binary_data
+                .as_ref()
+                .map(|data| {
+                    data.iter()
+                        .map(|(key, value)| (key.clone(), value.0.len()))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SecretInfo {
+    pub name: String,
+    pub type_: String,
+    pub key_count: usize,
+    pub age: String,
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl SecretInfo {
+    fn from_secret(secret: &Secret) -> Self {
+        let created = secret.metadata.creation_timestamp.as_ref().map(|t| t.0);
+        Self {
+            name: secret.metadata.name.clone().unwrap_or_default(),
+            type_: secret.type_.clone().unwrap_or_else(|| "Opaque".to_string()),
+            key_count: secret.data.as_ref().map_or(0, |d| d.len()),
+            age: created
+                .as_ref()
+                .map(format_age)
+                .unwrap_or_else(|| "Unknown".to_string()),
+            created,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SecretDetail {
+    pub name: String,
+    pub namespace: String,
+    pub type_: String,
+    /// Values already base64-decoded, as the API serves them encoded
+    pub data: BTreeMap<String, Vec<u8>>,
+}
+
+impl SecretDetail {
+    fn from_secret(secret: &Secret) -> Self {
+        Self {
+            name: secret.metadata.name.clone().unwrap_or_default(),
+            namespace: secret.metadata.namespace.clone().unwrap_or_default(),
+            type_: secret.type_.clone().unwrap_or_else(|| "Opaque".to_string()),
+            data: secret
+                .data
+                .as_ref()
+                .map(|data| {
+                    data.iter()
+                        .map(|(key, value)| (key.clone(), value.0.clone()))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ServiceDetail {
+    pub name: String,
+    pub namespace: String,
+    pub service_type: String,
+    pub cluster_ip: String,
+    pub ports: Vec<String>,
+    pub selector: BTreeMap<String, String>,
+    pub session_affinity: String,
+    pub external_traffic_policy: Option<String>,
+    pub ready_endpoints: usize,
+    pub not_ready_endpoints: usize,
+}
+
+impl ServiceDetail {
+    fn from_service(svc: &Service, ready_endpoints: usize, not_ready_endpoints: usize) -> Self {
+        let info = ServiceInfo::from_service(svc);
+        let spec = svc.spec.as_ref();
+
+        let ports = spec
+            .and_then(|s| s.ports.as_ref())
+            .map(|ports| {
+                ports
+                    .iter()
+                    .map(|p| {
+                        let target = p
+                            .target_port
+                            .as_ref()
+                            .map(|t| match t {
+                                IntOrString::Int(port) => port.to_string(),
+                                IntOrString::String(name) => name.clone(),
+                            })
+                            .unwrap_or_else(|| p.port.to_string());
+                        let mut port = format!(
+                            "{} {}/{} -> {}",
+                            p.name.as_deref().unwrap_or("<unnamed>"),
+                            p.port,
+                            p.protocol.as_deref().unwrap_or("TCP"),
+                            target
+                        );
+                        if let Some(node_port) = p.node_port {
+                            port.push_str(&format!(" (node port {})", node_port));
+                        }
+                        port
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            name: info.name,
+            namespace: svc.metadata.namespace.clone().unwrap_or_default(),
+            service_type: info.service_type,
+            cluster_ip: info.cluster_ip,
+            ports,
+            selector: spec.and_then(|s| s.selector.clone()).unwrap_or_default(),
+            session_affinity: spec
+                .and_then(|s| s.session_affinity.clone())
+                .unwrap_or_else(|| "None".to_string()),
+            external_traffic_policy: spec.and_then(|s| s.external_traffic_policy.clone()),
+            ready_endpoints,
+            not_ready_endpoints,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EventInfo {
+    /// The Event object's own name; an update to an event keeps it
+    pub name: String,
+    pub type_: String,
+    pub reason: String,
+    pub object: String,
+    pub message: String,
+    pub count: i32,
+    pub last_seen: Option<chrono::DateTime<chrono::Utc>>,
+    pub age: String,
+}
+
+impl EventInfo {
+    fn from_event(event: &Event) -> Self {
+        // Newer clients only fill eventTime/series, older ones lastTimestamp
+        let last_seen = event
+            .last_timestamp
+            .as_ref()
+            .map(|t| t.0)
+            .or_else(|| event.event_time.as_ref().map(|t| t.0))
+            .or_else(|| event.first_timestamp.as_ref().map(|t| t.0))
+            .or_else(|| event.metadata.creation_timestamp.as_ref().map(|t| t.0));
+
+        Self {
+            name: event.metadata.name.clone().unwrap_or_default(),
+            type_: event.type_.clone().unwrap_or_else(|| "Normal".to_string()),
+            reason: event.reason.clone().unwrap_or_default(),
+            object: format!(
+                "{}/{}",
+                event
+                    .involved_object
+                    .kind
+                    .as_deref()
+                    .unwrap_or("Unknown")
+                    .to_lowercase(),
+                event.involved_object.name.as_deref().unwrap_or("")
+            ),
+            message: event.message.clone().unwrap_or_default().trim().to_string(),
+            count: event.count.unwrap_or(1),
+            last_seen,
+            age: last_seen
+                .as_ref()
+                .map(format_age)
+                .unwrap_or_else(|| "Unknown".to_string()),
         }
     }
 }
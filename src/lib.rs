@@ -0,0 +1,19 @@
+//! Library side of `qui`, a terminal UI for Kubernetes.
+//!
+//! The binary in `main.rs` only sets up the terminal and runs the event loop;
+//! the application state, Kubernetes access and rendering live here so they can
+//! be embedded elsewhere or exercised from tests.
+
+pub mod app;
+pub mod clipboard;
+pub mod columns;
+pub mod config;
+pub mod error;
+pub mod events;
+pub mod kube_client;
+pub mod ui;
+
+pub use app::App;
+pub use config::Config;
+pub use error::QuiError;
+pub use kube_client::{ContextInfo, DeploymentInfo, EventInfo, KubeClient, PodInfo, ServiceInfo};
@@ -1,11 +1,6 @@
-mod app;
-mod events;
-mod kube_client;
-mod ui;
-
 use anyhow::Result;
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -13,12 +8,14 @@ use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
 use std::time::{Duration, Instant};
 
-use app::App;
-use events::EventHandler;
-use ui::ui;
+use qui::app::{self, App};
+use qui::events::EventHandler;
+use qui::ui::{render_splash, ui};
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    install_panic_hook();
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -27,35 +24,89 @@ async fn main() -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // Create app and run
-    let app = App::new().await?;
-    let res = run_app(&mut terminal, app).await;
+    let res = match connect(&mut terminal).await {
+        Ok(Some(app)) => run_app(&mut terminal, app).await,
+        Ok(None) => Ok(None),
+        Err(e) => Err(e),
+    };
 
     // Restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
+    restore_terminal()?;
     terminal.show_cursor()?;
 
-    if let Err(err) = res {
-        eprintln!("Error: {:?}", err);
+    match res {
+        Ok(Some(location)) => println!("{}", location),
+        Ok(None) => {}
+        Err(err) => eprintln!("Error: {:?}", err),
     }
 
     Ok(())
 }
 
+fn restore_terminal() -> Result<()> {
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+    Ok(())
+}
+
+/// Restore the terminal before the default panic handler prints, so a crash
+/// leaves the user's shell usable instead of stuck in raw mode
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = restore_terminal();
+        let _ = execute!(io::stdout(), crossterm::cursor::Show);
+        default_hook(info);
+    }));
+}
+
+/// Create the app, showing which context it's connecting to until it's done
+/// rather than a blank screen. None if the user quit while waiting.
+async fn connect<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>) -> Result<Option<App>> {
+    let context = App::startup_context();
+    let started = Instant::now();
+    let app = App::new();
+    tokio::pin!(app);
+
+    loop {
+        terminal.draw(|f| render_splash(f, &context, started.elapsed()))?;
+
+        tokio::select! {
+            app = &mut app => return app.map(Some),
+            _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+        }
+
+        // Raw mode swallows the usual Ctrl+C, so a hung connection needs a way out
+        while event::poll(Duration::ZERO)? {
+            if let Event::Key(key) = event::read()? {
+                let ctrl_c =
+                    key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+                if ctrl_c || key.code == KeyCode::Char('q') {
+                    return Ok(None);
+                }
+            }
+        }
+    }
+}
+
 async fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     mut app: App,
-) -> Result<()> {
+) -> Result<Option<String>> {
     let mut event_handler = EventHandler::new();
     let mut last_log_refresh = Instant::now();
     let log_refresh_interval = Duration::from_secs(2); // Refresh logs every 2 seconds
     let mut last_terminal_refresh = Instant::now();
     let terminal_refresh_interval = Duration::from_millis(50); // Refresh terminal every 50ms for smooth updates
 
+    let mut last_connection_check = Instant::now();
+    let connection_check_interval = Duration::from_secs(10); // Notice a dropped connection while idle
+
+    let mut last_usage_sample = Instant::now();
+
+    let mut last_warning_refresh = Instant::now();
+    let warning_refresh_interval = Duration::from_secs(30); // Poll the warning events badge every 30 seconds
+
     // New: Track last pod update check
     let mut last_pod_update = Instant::now();
     let pod_update_interval = Duration::from_millis(100); // Check for pod updates every 100ms
@@ -64,17 +115,54 @@ async fn run_app<B: ratatui::backend::Backend>(
         // Update pods from watcher if available (non-blocking)
         if last_pod_update.elapsed() >= pod_update_interval {
             app.try_update_pods();
+            app.try_update_rollout();
+            app.try_update_events();
+            app.try_update_log_stream();
             last_pod_update = Instant::now();
         }
 
+        // Swap the log preview over to a newly selected pod
+        app.sync_log_preview().await;
+
         terminal.draw(|f| ui(f, &mut app))?;
 
+        // One context switch step per frame, so the footer shows which one is slow
+        if app.context_switch.is_some() {
+            app.advance_context_switch().await?;
+            continue;
+        }
+
         // Refresh logs if in follow mode and enough time has passed
         if last_log_refresh.elapsed() >= log_refresh_interval {
             app.refresh_logs().await?;
             last_log_refresh = Instant::now();
         }
 
+        // Fire a destructive action once its countdown runs out
+        app.run_due_action().await?;
+
+        // Attach to a new debug container once it runs
+        app.advance_debug_container().await;
+
+        if last_connection_check.elapsed() >= connection_check_interval {
+            app.check_connection().await;
+            last_connection_check = Instant::now();
+        }
+        // Optionally quit rather than keep showing data from a cluster that's gone
+        if let Some(reason) = app.connection_exit_reason() {
+            anyhow::bail!(reason);
+        }
+
+        if last_usage_sample.elapsed() >= app::USAGE_SAMPLE_INTERVAL {
+            app.sample_pod_usage().await;
+            last_usage_sample = Instant::now();
+        }
+
+        if last_warning_refresh.elapsed() >= warning_refresh_interval {
+            app.refresh_warning_count().await;
+            last_warning_refresh = Instant::now();
+        }
+
         // Refresh terminal more frequently for smooth interactive commands
         if matches!(app.current_view, app::View::Terminal)
             && last_terminal_refresh.elapsed() >= terminal_refresh_interval
@@ -83,9 +171,21 @@ async fn run_app<B: ratatui::backend::Backend>(
             last_terminal_refresh = Instant::now();
         }
 
+        // Hand the terminal to an external program (e.g. $EDITOR) and take it back after
+        if let Some(command) = app.pending_command.take() {
+            restore_terminal()?;
+            terminal.show_cursor()?;
+            let result = command.run();
+            enable_raw_mode()?;
+            execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+            terminal.clear()?;
+            app.finish_external_command(command, result).await;
+            continue;
+        }
+
         if let Some(event) = event_handler.next()? {
             if !app.handle_event(event).await? {
-                return Ok(());
+                return Ok(app.exit_location());
             }
         }
     }
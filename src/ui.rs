@@ -1,19 +1,47 @@
+use chrono::{DateTime, Utc};
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Sparkline, Table, Wrap},
     Frame,
 };
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::time::Duration;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-use crate::app::{App, InputMode, View};
+use crate::app::{
+    deployment_value, pod_value, service_value, App, ContainerAction, Detail, InputMode,
+    UsageHistory, View, LOG_GREP_TAIL_LINES, USAGE_SAMPLE_INTERVAL,
+};
+use crate::columns::{
+    column_width, visible_columns, ColumnSpec, DEFAULT_DEPLOYMENT_COLUMNS, DEFAULT_POD_COLUMNS,
+    DEFAULT_SERVICE_COLUMNS, DEPLOYMENT_COLUMNS, POD_COLUMNS, SERVICE_COLUMNS,
+};
+use crate::config::{
+    AgeClass, AgeColorsConfig, Config, PodStatusDisplay, TableConfig, ThemePreset,
+};
+use crate::kube_client::{
+    ConfigMapDetail, ConnectionDetail, ContainerHealth, ContainerSummary, DeletePropagation,
+    DeploymentDetail, DeploymentHistory, DeploymentInfo, EffectiveKubeconfig, EnvValue,
+    NamespaceSummary, NodeDetail, PodDetail, PodInfo, RolloutState, SecretDetail, ServiceDetail,
+    ServiceInfo,
+};
 
 pub fn ui(f: &mut Frame, app: &mut App) {
+    // A lost connection takes a line above the content until it comes back
+    let banner_height = if app.connection_lost_at.is_some() {
+        1
+    } else {
+        0
+    };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),
             Constraint::Length(3),
+            Constraint::Length(banner_height),
             Constraint::Min(0),
             Constraint::Length(3),
         ])
@@ -21,100 +49,1989 @@ pub fn ui(f: &mut Frame, app: &mut App) {
 
     render_header(f, app, chunks[0]);
     render_tabs(f, app, chunks[1]);
-    render_main_content(f, app, chunks[2]);
-    render_footer(f, app, chunks[3]);
+    if app.connection_lost_at.is_some() {
+        render_connection_lost_banner(f, app, chunks[2]);
+    }
+    render_main_content(f, app, chunks[3]);
+    render_footer(f, app, chunks[4]);
+
+    if app.input_mode == InputMode::NamespacePicker {
+        render_namespace_picker(f, app);
+    }
+    if app.input_mode == InputMode::GlobalSearch {
+        render_global_search(f, app);
+    }
+    if app.input_mode == InputMode::Rollout {
+        render_rollout(f, app);
+    }
+    if app.input_mode == InputMode::EnvEdit {
+        render_env_edit(f, app);
+    }
+    if app.input_mode == InputMode::RowPopup {
+        render_row_popup(f, app);
+    }
+    if app.input_mode == InputMode::ConfirmContextSwitch {
+        render_confirm_context_switch(f, app);
+    }
+    if app.input_mode == InputMode::ViewPicker {
+        render_view_picker(f, app);
+    }
+    if app.input_mode == InputMode::MetadataEdit {
+        render_metadata_edit(f, app);
+    }
+    if app.input_mode == InputMode::LogGrep {
+        render_log_grep(f, app);
+    }
+    if app.input_mode == InputMode::PortForward {
+        render_port_forward_input(f, app);
+    }
+    if app.input_mode == InputMode::ContainerPicker {
+        render_container_picker(f, app);
+    }
+    if matches!(
+        app.input_mode,
+        InputMode::LogInclude | InputMode::LogExclude
+    ) {
+        render_log_filter(f, app);
+    }
+    if app.input_mode == InputMode::ConfirmCordon {
+        render_confirm_cordon(f, app);
+    }
+    if app.input_mode == InputMode::ConfirmRollback {
+        render_confirm_rollback(f, app);
+    }
+    if app.input_mode == InputMode::ConfirmDelete {
+        render_confirm_delete(f, app);
+    }
+    if app.input_mode == InputMode::ConfirmJobCleanup {
+        render_confirm_job_cleanup(f, app);
+    }
+    if app.input_mode == InputMode::ConfirmScaleToZero {
+        render_confirm_scale_to_zero(f, app);
+    }
+    if app.input_mode == InputMode::ConfirmNodeShell {
+        render_confirm_node_shell(f, app);
+    }
+    if app.input_mode == InputMode::ConfirmDebugContainer {
+        render_confirm_debug_container(f, app);
+    }
+
+    apply_theme(app.theme, f.buffer_mut());
+}
+
+/// Recolor the finished frame for the active preset. Everything is drawn with the
+/// default palette, so a preset is a mapping over the few colors it uses.
+fn apply_theme(theme: ThemePreset, buffer: &mut Buffer) {
+    if theme == ThemePreset::Default {
+        return;
+    }
+    for cell in buffer.content.iter_mut() {
+        let (fg, bg) = (cell.fg, cell.bg);
+        match theme {
+            ThemePreset::Default => {}
+            ThemePreset::Light => {
+                cell.set_fg(match fg {
+                    Color::Reset | Color::White => Color::Black,
+                    Color::Yellow => Color::Rgb(150, 100, 0),
+                    Color::Cyan => Color::Blue,
+                    Color::Green => Color::Rgb(0, 120, 0),
+                    other => other,
+                });
+                cell.set_bg(match bg {
+                    Color::Reset => Color::White,
+                    Color::DarkGray => Color::Gray,
+                    Color::Cyan => Color::LightCyan,
+                    other => other,
+                });
+            }
+            ThemePreset::HighContrast => {
+                cell.set_fg(match fg {
+                    Color::Reset | Color::Gray => Color::White,
+                    Color::DarkGray => Color::Gray,
+                    Color::Red => Color::LightRed,
+                    Color::Green => Color::LightGreen,
+                    Color::Yellow => Color::LightYellow,
+                    Color::Cyan | Color::Blue => Color::LightCyan,
+                    Color::Magenta => Color::LightMagenta,
+                    other => other,
+                });
+                cell.set_bg(match bg {
+                    Color::Reset => Color::Black,
+                    Color::DarkGray => Color::Blue,
+                    other => other,
+                });
+            }
+            ThemePreset::Monochrome => {
+                // Highlights keep standing out as reversed text, errors as bold
+                if bg != Color::Reset {
+                    cell.modifier.insert(Modifier::REVERSED);
+                }
+                if matches!(fg, Color::Red | Color::LightRed) {
+                    cell.modifier.insert(Modifier::BOLD);
+                }
+                cell.set_fg(Color::Reset);
+                cell.set_bg(Color::Reset);
+            }
+        }
+    }
+}
+
+/// Cut `text` to at most `width` terminal columns. Counts display width, not
+/// chars, so a wide character (CJK, most emoji) is never split across the edge.
+fn truncate_to_width(text: &str, width: usize) -> String {
+    let mut used = 0;
+    text.chars()
+        .take_while(|c| {
+            used += c.width().unwrap_or(0);
+            used <= width
+        })
+        .collect()
+}
+
+/// Left-align `text` in a field `width` columns wide, measured by display width
+/// (`format!("{:<N}")` pads by chars and misaligns columns after wide characters)
+fn pad_to_width(text: &str, width: usize) -> String {
+    format!("{}{}", text, " ".repeat(width.saturating_sub(text.width())))
+}
+
+/// Centered rectangle taking the given percentage of `area`
+fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}
+
+fn render_namespace_picker(f: &mut Frame, app: &App) {
+    let area = centered_rect(50, 60, f.area());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    let input = Paragraph::new(format!("{}▏", app.input_buffer))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Switch Namespace (type to filter, Enter to switch, Esc to cancel)"),
+        )
+        .style(Style::default().fg(Color::Yellow));
+
+    let matches = app.namespace_picker_matches();
+    let visible_height = chunks[1].height.saturating_sub(2) as usize;
+    // Keep the selection in view when the list is longer than the popup
+    let offset = (app.namespace_picker_index + 1).saturating_sub(visible_height);
+
+    let lines: Vec<Line> = if matches.is_empty() {
+        vec![Line::styled(
+            "No matching namespaces",
+            Style::default().fg(Color::DarkGray),
+        )]
+    } else {
+        matches
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(visible_height)
+            .map(|(i, ns)| {
+                let marker = if *ns == app.current_namespace {
+                    "▶ "
+                } else {
+                    "  "
+                };
+                let style = if i == app.namespace_picker_index {
+                    Style::default()
+                        .bg(Color::DarkGray)
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD)
+                } else if *ns == app.current_namespace {
+                    Style::default().fg(Color::Yellow)
+                } else {
+                    Style::default()
+                };
+                Line::styled(format!("{}{}", marker, ns), style)
+            })
+            .collect()
+    };
+
+    let list = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(format!(
+        "{} of {}",
+        matches.len(),
+        app.namespaces.len()
+    )));
+
+    f.render_widget(Clear, area);
+    f.render_widget(input, chunks[0]);
+    f.render_widget(list, chunks[1]);
+}
+
+fn render_view_picker(f: &mut Frame, app: &App) {
+    let area = centered_rect(40, 50, f.area());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    let input = Paragraph::new(format!(":{}▏", app.input_buffer))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Go to View (Tab completes, Enter to go, Esc to cancel)"),
+        )
+        .style(Style::default().fg(Color::Yellow));
+
+    let matches = app.view_picker_matches();
+    let lines: Vec<Line> = if matches.is_empty() {
+        vec![Line::styled(
+            "No matching views",
+            Style::default().fg(Color::DarkGray),
+        )]
+    } else {
+        matches
+            .iter()
+            .enumerate()
+            .map(|(i, (name, view))| {
+                let marker = if *view == app.current_view {
+                    "▶ "
+                } else {
+                    "  "
+                };
+                let style = if i == app.view_picker_index {
+                    Style::default()
+                        .bg(Color::DarkGray)
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                Line::styled(format!("{}{}", marker, name), style)
+            })
+            .collect()
+    };
+
+    let list = Paragraph::new(lines).block(Block::default().borders(Borders::ALL));
+
+    f.render_widget(Clear, area);
+    f.render_widget(input, chunks[0]);
+    f.render_widget(list, chunks[1]);
+}
+
+fn render_env_edit(f: &mut Frame, app: &App) {
+    let area = centered_rect(60, 20, f.area());
+    let area = Rect {
+        height: area.height.min(3),
+        ..area
+    };
+
+    let input = Paragraph::new(format!("{}▏", app.input_buffer))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Set env var: [container:]NAME=value (Enter to apply, Esc to cancel)"),
+        )
+        .style(Style::default().fg(Color::Yellow));
+
+    f.render_widget(Clear, area);
+    f.render_widget(input, area);
+}
+
+fn render_metadata_edit(f: &mut Frame, app: &App) {
+    let area = centered_rect(60, 20, f.area());
+    let area = Rect {
+        height: area.height.min(3),
+        ..area
+    };
+    let target = app
+        .metadata_target
+        .as_ref()
+        .map(|(kind, name)| format!("{}/{}", kind.as_str(), name))
+        .unwrap_or_default();
+
+    let input = Paragraph::new(format!("{}▏", app.input_buffer))
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            "Label {}: key=value, key- removes, @key=value annotates (Enter/Esc)",
+            target
+        )))
+        .style(Style::default().fg(Color::Yellow));
+
+    f.render_widget(Clear, area);
+    f.render_widget(input, area);
+}
+
+fn render_log_grep(f: &mut Frame, app: &App) {
+    let area = centered_rect(60, 20, f.area());
+    let area = Rect {
+        height: area.height.min(3),
+        ..area
+    };
+
+    let input = Paragraph::new(format!("{}▏", app.input_buffer))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Grep logs: show lines containing (empty clears, Enter/Esc)"),
+        )
+        .style(Style::default().fg(Color::Yellow));
+
+    f.render_widget(Clear, area);
+    f.render_widget(input, area);
+}
+
+fn render_log_filter(f: &mut Frame, app: &App) {
+    let area = centered_rect(60, 20, f.area());
+    let area = Rect {
+        height: area.height.min(3),
+        ..area
+    };
+
+    let title = if app.input_mode == InputMode::LogExclude {
+        "Exclude log lines matching regex (empty clears, Enter/Esc)"
+    } else {
+        "Only show log lines matching regex (empty clears, Enter/Esc)"
+    };
+    let input = Paragraph::new(format!("{}▏", app.input_buffer))
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .style(Style::default().fg(Color::Yellow));
+
+    f.render_widget(Clear, area);
+    f.render_widget(input, area);
+}
+
+/// The containers of the selected pod, numbered like the terminal type menu
+fn render_container_picker(f: &mut Frame, app: &App) {
+    let Some(pod) = app.selected_pod() else {
+        return;
+    };
+    let area = centered_rect(60, 40, f.area());
+    let area = Rect {
+        height: area.height.min(pod.containers.len() as u16 + 4),
+        ..area
+    };
+
+    let mut lines: Vec<Line> = pod
+        .containers
+        .iter()
+        .enumerate()
+        .map(|(i, container)| {
+            let marker = if i == app.container_picker_index {
+                "▶"
+            } else {
+                " "
+            };
+            let number = if i < 9 {
+                format!("[{}]", i + 1)
+            } else {
+                "   ".to_string()
+            };
+            let default = if pod.default_container.as_deref() == Some(container.name.as_str()) {
+                " (default)"
+            } else {
+                ""
+            };
+            Line::raw(format!(
+                "{} {} {}{}",
+                marker, number, container.name, default
+            ))
+        })
+        .collect();
+    lines.push(Line::raw(""));
+    lines.push(Line::raw(
+        "Use ↑/↓ or 1-9 to choose, Enter to confirm, Esc to cancel",
+    ));
+
+    let title = match app.container_picker {
+        Some(ContainerAction::Logs { .. }) => format!("Logs of which container in {}?", pod.name),
+        _ => format!("Shell in which container of {}?", pod.name),
+    };
+    let menu = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .style(Style::default().fg(Color::Cyan));
+
+    f.render_widget(Clear, area);
+    f.render_widget(menu, area);
+}
+
+fn render_port_forward_input(f: &mut Frame, app: &App) {
+    let area = centered_rect(60, 20, f.area());
+    let area = Rect {
+        height: area.height.min(3),
+        ..area
+    };
+
+    let input = Paragraph::new(format!("{}▏", app.input_buffer))
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            "Forward to {}: PORT, LOCAL:REMOTE or :REMOTE (Enter/Esc)",
+            app.port_forward_pod.as_deref().unwrap_or_default()
+        )))
+        .style(Style::default().fg(Color::Yellow));
+
+    f.render_widget(Clear, area);
+    f.render_widget(input, area);
+}
+
+fn render_rollout(f: &mut Frame, app: &App) {
+    let area = centered_rect(70, 30, f.area());
+    let name = app.rollout_deployment.as_deref().unwrap_or_default();
+
+    let mut lines = vec![Line::from("")];
+    match &app.rollout_status {
+        None => lines.push(Line::styled(
+            "Waiting for the first status update...",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Some(status) => {
+            let color = match status.state {
+                RolloutState::Progressing => Color::Yellow,
+                RolloutState::Complete => Color::Green,
+                RolloutState::Failed => Color::Red,
+                RolloutState::WatchStopped => Color::Red,
+            };
+            lines.push(Line::styled(
+                status.message.clone(),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ));
+            lines.push(Line::from(""));
+            lines.push(Line::from(format!(
+                "Updated: {}/{}   Available: {}/{}",
+                status.updated, status.desired, status.available, status.desired
+            )));
+        }
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        "Esc to close",
+        Style::default().fg(Color::DarkGray),
+    ));
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Rollout: {}", name)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+fn render_confirm_context_switch(f: &mut Frame, app: &App) {
+    let area = centered_rect(50, 20, f.area());
+    let name = app
+        .contexts
+        .get(app.context_index)
+        .map(|c| c.name.as_str())
+        .unwrap_or_default();
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Switch to "),
+            Span::styled(
+                name,
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("?"),
+        ]),
+        Line::from(""),
+        Line::styled("(y/n)", Style::default().fg(Color::DarkGray)),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Switch Context"),
+        );
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+fn render_confirm_cordon(f: &mut Frame, app: &App) {
+    let area = centered_rect(50, 25, f.area());
+    let node = app.cordon_node.as_deref().unwrap_or_default();
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Cordon node "),
+            Span::styled(
+                node,
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("?"),
+        ]),
+        Line::styled(
+            "No new pods from any namespace will be scheduled there.",
+            Style::default().fg(Color::DarkGray),
+        ),
+        Line::from(""),
+        Line::styled("(y/n)", Style::default().fg(Color::DarkGray)),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).title("Cordon Node"));
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+fn render_confirm_rollback(f: &mut Frame, app: &App) {
+    let area = centered_rect(50, 25, f.area());
+    let name = match &app.detail {
+        Some(Detail::History(history)) => history.name.as_str(),
+        _ => "",
+    };
+    let revision = app.rollback_revision.unwrap_or_default();
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Roll "),
+            Span::styled(
+                name.to_string(),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(" back to revision {}?", revision)),
+        ]),
+        Line::styled(
+            "Its pods are replaced with that revision's template.",
+            Style::default().fg(Color::DarkGray),
+        ),
+        Line::from(""),
+        Line::styled("(y/n)", Style::default().fg(Color::DarkGray)),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).title("Roll Back"));
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+fn render_confirm_delete(f: &mut Frame, app: &App) {
+    let area = centered_rect(60, 35, f.area());
+    let name = app.delete_target.as_deref().unwrap_or_default();
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Delete deployment "),
+            Span::styled(
+                name,
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("?"),
+        ]),
+        Line::from(""),
+    ];
+    for propagation in DeletePropagation::ALL {
+        let (key, effect) = match propagation {
+            DeletePropagation::Background => ("b", "delete the pods afterwards"),
+            DeletePropagation::Foreground => ("f", "delete the pods first, then the deployment"),
+            DeletePropagation::Orphan => ("o", "keep the pods running"),
+        };
+        let style = if propagation == app.delete_propagation {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::styled(
+            format!("[{}] {}: {}", key, propagation.as_str(), effect),
+            style,
+        ));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        "(y/Enter delete, Tab change, n/Esc cancel)",
+        Style::default().fg(Color::DarkGray),
+    ));
+
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Delete Deployment"),
+        );
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+fn render_confirm_scale_to_zero(f: &mut Frame, app: &App) {
+    let area = centered_rect(50, 40, f.area());
+    let targets = &app.scale_to_zero_targets;
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Scale "),
+            Span::styled(
+                format!("{} deployments", targets.len()),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(" in {} to zero?", app.current_namespace)),
+        ]),
+        Line::styled(
+            "Every pod of these is stopped:",
+            Style::default().fg(Color::DarkGray),
+        ),
+        Line::from(""),
+    ];
+    for name in targets {
+        lines.push(Line::styled(
+            name.clone(),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::styled("(y/n)", Style::default().fg(Color::DarkGray)));
+
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Scale to Zero"),
+        );
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+fn render_confirm_job_cleanup(f: &mut Frame, app: &App) {
+    let area = centered_rect(50, 25, f.area());
+    let count = app.completed_jobs.len();
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Delete "),
+            Span::styled(
+                format!(
+                    "{} completed job{}",
+                    count,
+                    if count == 1 { "" } else { "s" }
+                ),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(" in {}?", app.current_namespace)),
+        ]),
+        Line::styled(
+            "Their pods are deleted along with them.",
+            Style::default().fg(Color::DarkGray),
+        ),
+        Line::from(""),
+        Line::styled("(y/n)", Style::default().fg(Color::DarkGray)),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Clean Up Jobs"),
+        );
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+fn render_confirm_node_shell(f: &mut Frame, app: &App) {
+    let area = centered_rect(60, 30, f.area());
+    let node = app.node_shell_target.as_deref().unwrap_or("");
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Open a root shell on node "),
+            Span::styled(
+                node,
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("?"),
+        ]),
+        Line::styled(
+            format!(
+                "Creates a privileged debug pod in {} that stays until you delete it.",
+                app.current_namespace
+            ),
+            Style::default().fg(Color::DarkGray),
+        ),
+        Line::styled(
+            "Needs RBAC to create privileged pods and kubectl 1.27+.",
+            Style::default().fg(Color::DarkGray),
+        ),
+        Line::from(""),
+        Line::styled("(y/n)", Style::default().fg(Color::DarkGray)),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).title("Node Shell"));
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+fn render_confirm_debug_container(f: &mut Frame, app: &App) {
+    let area = centered_rect(60, 30, f.area());
+    let (pod, target) = match &app.debug_target {
+        Some((pod, target)) => (pod.as_str(), target.as_deref()),
+        None => ("", None),
+    };
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Add a busybox debug container to "),
+            Span::styled(
+                pod,
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("?"),
+        ]),
+    ];
+    if let Some(target) = target {
+        lines.push(Line::styled(
+            format!("It shares {}'s processes, visible under /proc.", target),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    lines.push(Line::styled(
+        "Ephemeral containers can't be removed; it stays in the pod until the pod is deleted.",
+        Style::default().fg(Color::DarkGray),
+    ));
+    lines.push(Line::from(""));
+    lines.push(Line::styled("(y/n)", Style::default().fg(Color::DarkGray)));
+
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Debug Container"),
+        );
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
 }
 
-fn render_header(f: &mut Frame, app: &App, area: Rect) {
-    let mut title = vec![Span::styled(
-        "QUI - Kubernetes TUI",
-        Style::default()
-            .fg(Color::Cyan)
-            .add_modifier(Modifier::BOLD),
-    )];
+fn render_global_search(f: &mut Frame, app: &App) {
+    let area = centered_rect(70, 60, f.area());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    let input =
+        Paragraph::new(format!("{}▏", app.input_buffer))
+            .block(Block::default().borders(Borders::ALL).title(
+                "Search All Namespaces (Enter to search, Enter again to jump, Esc to cancel)",
+            ))
+            .style(Style::default().fg(Color::Yellow));
+
+    let visible_height = chunks[1].height.saturating_sub(2) as usize;
+    let offset = (app.search_index + 1).saturating_sub(visible_height);
+
+    let lines: Vec<Line> = match &app.search_query {
+        None => vec![Line::styled(
+            "Type part of a pod, deployment or service name and press Enter",
+            Style::default().fg(Color::DarkGray),
+        )],
+        Some(_) if app.search_results.is_empty() => vec![Line::styled(
+            "No matching resources",
+            Style::default().fg(Color::DarkGray),
+        )],
+        Some(_) => app
+            .search_results
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(visible_height)
+            .map(|(i, result)| {
+                let style = if i == app.search_index {
+                    Style::default()
+                        .bg(Color::DarkGray)
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                Line::styled(
+                    format!(
+                        "{}{}{}",
+                        pad_to_width(result.kind.as_str(), 12),
+                        pad_to_width(&result.namespace, 24),
+                        result.name
+                    ),
+                    style,
+                )
+            })
+            .collect(),
+    };
+
+    let title = match &app.search_query {
+        Some(query) => format!("{} match(es) for '{}'", app.search_results.len(), query),
+        None => "Results".to_string(),
+    };
+    let list = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(Clear, area);
+    f.render_widget(input, chunks[0]);
+    f.render_widget(list, chunks[1]);
+}
+
+/// The frame shown while the app connects, before there's an `App` to draw.
+/// `context` is empty when the kubeconfig names none.
+pub fn render_splash(f: &mut Frame, context: &str, elapsed: Duration) {
+    let area = centered_rect(60, 20, f.area());
+    let area = Rect {
+        height: area.height.min(5),
+        ..area
+    };
+
+    let target = if context.is_empty() {
+        "Reading kubeconfig...".to_string()
+    } else {
+        format!("Connecting to {}...", context)
+    };
+    // A second in, say how long it's been so a slow API server isn't mistaken for a hang
+    let waited = if elapsed.as_secs() >= 1 {
+        format!("{}s, q to quit", elapsed.as_secs())
+    } else {
+        "q to quit".to_string()
+    };
+    let splash = Paragraph::new(vec![
+        Line::from(Span::styled(
+            target,
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(waited, Style::default().fg(Color::DarkGray))),
+    ])
+    .alignment(Alignment::Center)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("QUI - Kubernetes TUI")
+            .style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(splash, area);
+}
+
+fn render_connection_lost_banner(f: &mut Frame, app: &App, area: Rect) {
+    let mut text = format!(
+        " ⚠ Lost connection to {}: the last {} requests failed. What's shown may be stale.",
+        app.current_context, app.consecutive_failures
+    );
+    if let Some(exit_in) = app.connection_exit_in() {
+        text.push_str(&format!(" Exiting in {}s.", exit_in.as_secs()));
+    }
+    let banner = Paragraph::new(text).style(
+        Style::default()
+            .fg(Color::White)
+            .bg(Color::Red)
+            .add_modifier(Modifier::BOLD),
+    );
+    f.render_widget(banner, area);
+}
+
+fn render_header(f: &mut Frame, app: &App, area: Rect) {
+    let mut title = vec![Span::styled(
+        "QUI - Kubernetes TUI",
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    )];
+
+    if !app.current_context.is_empty() {
+        title.push(Span::raw(" │ "));
+        title.push(Span::styled(
+            format!("Context: {}", app.current_context),
+            Style::default().fg(if app.connected {
+                Color::Green
+            } else {
+                Color::Red
+            }),
+        ));
+    }
+    if !app.connected {
+        title.push(Span::raw(" │ "));
+        title.push(Span::styled(
+            "Not connected",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ));
+    }
+    if app.tls_insecure {
+        title.push(Span::raw(" │ "));
+        title.push(Span::styled(
+            "⚠ TLS verification disabled",
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Red)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    title.push(Span::raw(" │ "));
+    title.push(Span::styled(
+        format!("Namespace: {}", app.current_namespace),
+        Style::default().fg(Color::Yellow),
+    ));
+
+    if let Some(identity) = &app.token_identity {
+        title.push(Span::raw(" │ "));
+        title.push(Span::styled(
+            format!("Token: {}", identity),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    if let Some(identity) = app.config.impersonation_label() {
+        title.push(Span::raw(" │ "));
+        title.push(Span::styled(
+            format!("As: {}", identity),
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    let header = Paragraph::new(Line::from(title)).block(Block::default().borders(Borders::ALL));
+
+    f.render_widget(header, area);
+}
+
+fn render_tabs(f: &mut Frame, app: &App, area: Rect) {
+    let tabs = [
+        ("1", "Pods", View::Pods),
+        ("2", "Deployments", View::Deployments),
+        ("3", "Services", View::Services),
+        ("4", "Clusters", View::Clusters),
+        ("5", "Namespaces", View::Namespaces),
+        ("6", "Events", View::Events),
+        ("7", "ConfigMaps", View::ConfigMaps),
+        ("8", "Secrets", View::Secrets),
+        ("?", "Help", View::Help),
+    ];
+
+    let mut tab_spans = Vec::new();
+
+    for (i, (key, label, view)) in tabs.iter().enumerate() {
+        if i > 0 {
+            tab_spans.push(Span::raw(" "));
+        }
+
+        let is_active = *view == app.current_view;
+
+        let style = if is_active {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::DIM)
+        };
+
+        let tab_text = format!(" {} {} ", key, label);
+        tab_spans.push(Span::styled(tab_text, style));
+
+        if *view == View::Events && app.warning_count > 0 {
+            tab_spans.push(Span::styled(
+                format!("⚠ {}", app.warning_count),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+        }
+    }
+
+    tab_spans.push(Span::raw("  "));
+    tab_spans.push(Span::styled(
+        "← → Navigate",
+        Style::default()
+            .fg(Color::DarkGray)
+            .add_modifier(Modifier::ITALIC),
+    ));
+
+    let tabs_paragraph =
+        Paragraph::new(Line::from(tab_spans)).block(Block::default().borders(Borders::ALL));
+
+    f.render_widget(tabs_paragraph, area);
+}
+
+fn render_main_content(f: &mut Frame, app: &mut App, area: Rect) {
+    if !app.connected && app.current_view.needs_cluster() {
+        render_not_connected(f, area);
+        return;
+    }
+
+    match app.current_view {
+        View::Pods => render_pods_view(f, app, area),
+        View::Deployments => render_deployments_view(f, app, area),
+        View::Services => render_services_view(f, app, area),
+        View::Events => render_events_view(f, app, area),
+        View::ConfigMaps => render_configmaps_view(f, app, area),
+        View::Secrets => render_secrets_view(f, app, area),
+        View::Logs => render_logs_view(f, app, area),
+        View::Clusters => render_clusters_view(f, app, area),
+        View::Namespaces => render_namespaces_view(f, app, area),
+        View::Help => render_help_view(f, app, area),
+        View::Terminal => render_terminal_view(f, app, area),
+        View::Activity => render_activity_view(f, app, area),
+        View::PortForwards => render_port_forwards_view(f, app, area),
+        View::Detail => render_detail_view(f, app, area),
+    }
+}
+
+fn render_not_connected(f: &mut Frame, area: Rect) {
+    let text = vec![
+        Line::from(""),
+        Line::styled(
+            "Not connected — switch context (4)",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Line::from(""),
+        Line::styled(
+            "Pick a context in the Clusters view and press Enter to connect.",
+            Style::default().fg(Color::DarkGray),
+        ),
+    ];
+
+    let paragraph = Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+
+    f.render_widget(paragraph, area);
+}
+
+/// Header row; the column focused for resizing is shown reversed
+fn column_header(columns: &[&ColumnSpec], focus: Option<usize>) -> Row<'static> {
+    let header_cells = columns.iter().enumerate().map(|(i, c)| {
+        let style = Style::default().fg(Color::Yellow);
+        let style = if focus.map(|f| f.min(columns.len() - 1)) == Some(i) {
+            style.add_modifier(Modifier::REVERSED)
+        } else {
+            style
+        };
+        Cell::from(c.1).style(style)
+    });
+
+    Row::new(header_cells)
+        .style(Style::default())
+        .height(1)
+        .bottom_margin(1)
+}
+
+/// Scale the widths so the visible columns fill the table
+fn column_widths(columns: &[&ColumnSpec], table: &TableConfig) -> Vec<Constraint> {
+    let widths: Vec<u16> = columns.iter().map(|c| column_width(c, table)).collect();
+    let total: u16 = widths.iter().sum::<u16>().max(1);
+    widths
+        .iter()
+        .map(|w| Constraint::Percentage(w * 100 / total))
+        .collect()
+}
+
+fn row_style(selected: bool) -> Style {
+    if selected {
+        Style::default()
+            .bg(Color::DarkGray)
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    }
+}
+
+/// AGE cell colored by the configured thresholds: new resources stand out, old ones fade
+fn age_cell(age: &str, created: Option<&DateTime<Utc>>, ages: &AgeColorsConfig) -> Cell<'static> {
+    let style = match created.map(|t| ages.classify(t)) {
+        Some(AgeClass::New) => Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+        // Dim rather than a dark color, which would vanish on the selected row
+        Some(AgeClass::Old) => Style::default().add_modifier(Modifier::DIM),
+        _ => Style::default(),
+    };
+    Cell::from(age.to_string()).style(style)
+}
+
+fn pod_cell(pod: &PodInfo, key: &str, config: &Config) -> Cell<'static> {
+    match key {
+        "age" => age_cell(&pod.age, pod.created.as_ref(), &config.age_colors),
+        "init" => {
+            // Only pods still initializing need attention; stuck ones most of all
+            let style = match &pod.init {
+                Some(init) if init.blocked.is_some() => Style::default().fg(Color::Red),
+                Some(init) if !init.is_complete() => Style::default().fg(Color::Yellow),
+                _ => Style::default(),
+            };
+            Cell::from(pod_value(pod, key, config.pod_status)).style(style)
+        }
+        _ => Cell::from(pod_value(pod, key, config.pod_status)),
+    }
+}
+
+fn deployment_cell(dep: &DeploymentInfo, key: &str, ages: &AgeColorsConfig) -> Cell<'static> {
+    match key {
+        "age" => age_cell(&dep.age, dep.created.as_ref(), ages),
+        _ => Cell::from(deployment_value(dep, key)),
+    }
+}
+
+fn service_cell(svc: &ServiceInfo, key: &str, ages: &AgeColorsConfig) -> Cell<'static> {
+    match key {
+        "age" => age_cell(&svc.age, svc.created.as_ref(), ages),
+        _ => Cell::from(service_value(svc, key)),
+    }
+}
+
+/// Every column of the selected row, untruncated, for the `v` popup. Includes
+/// columns the config hides, since those can't be seen any other way.
+fn selected_row_fields(app: &App) -> Vec<(&'static str, String)> {
+    fn all_columns<T>(
+        columns: &[ColumnSpec],
+        item: Option<&T>,
+        value: impl Fn(&T, &str) -> String,
+    ) -> Vec<(&'static str, String)> {
+        item.map(|item| {
+            columns
+                .iter()
+                .map(|(key, header, _)| (*header, value(item, key)))
+                .collect()
+        })
+        .unwrap_or_default()
+    }
+
+    match app.current_view {
+        View::Pods => all_columns(POD_COLUMNS, app.selected_pod(), |pod, key| {
+            pod_value(pod, key, app.config.pod_status)
+        }),
+        View::Deployments => all_columns(
+            DEPLOYMENT_COLUMNS,
+            app.selected_deployment(),
+            deployment_value,
+        ),
+        View::Services => all_columns(SERVICE_COLUMNS, app.selected_service(), service_value),
+        View::Events => app
+            .events
+            .get(app.event_index)
+            .map(|event| {
+                vec![
+                    ("LAST SEEN", event.age.clone()),
+                    ("TYPE", event.type_.clone()),
+                    ("REASON", event.reason.clone()),
+                    ("OBJECT", event.object.clone()),
+                    ("COUNT", event.count.to_string()),
+                    ("MESSAGE", event.message.clone()),
+                ]
+            })
+            .unwrap_or_default(),
+        View::Clusters => app
+            .contexts
+            .get(app.context_index)
+            .map(|ctx| {
+                vec![
+                    ("CONTEXT", ctx.name.clone()),
+                    ("CLUSTER", ctx.cluster.clone()),
+                    ("SERVER", ctx.server.clone()),
+                    ("NAMESPACE", ctx.namespace.clone()),
+                ]
+            })
+            .unwrap_or_default(),
+        _ => vec![],
+    }
+}
+
+fn render_row_popup(f: &mut Frame, app: &App) {
+    let area = centered_rect(70, 50, f.area());
+
+    let lines: Vec<Line> = selected_row_fields(app)
+        .into_iter()
+        .map(|(header, value)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<12}", header),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw(value),
+            ])
+        })
+        .collect();
+
+    let popup = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Selected Row (Esc to close)"),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
+
+fn render_pods_view(f: &mut Frame, app: &App, area: Rect) {
+    let area = if app.show_log_preview {
+        let halves = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+        render_log_preview(f, app, halves[1]);
+        halves[0]
+    } else {
+        area
+    };
+
+    let columns = visible_columns(
+        POD_COLUMNS,
+        app.config.pods.columns.as_ref(),
+        DEFAULT_POD_COLUMNS,
+    );
+
+    let mut rows = Vec::with_capacity(app.pods.len());
+    // Pins that aren't listed stay on top, so losing the pod is noticed
+    for pin in app.missing_pins() {
+        rows.push(
+            Row::new(vec![Cell::from(format!(
+                "📌 {} - not in this namespace",
+                pin
+            ))])
+            .style(Style::default().fg(Color::DarkGray))
+            .height(1),
+        );
+    }
+    let mut last_node = None;
+    for (i, pod) in app
+        .pods
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| app.shown_in_list(View::Pods, &p.name))
+    {
+        // Pods arrive ordered by node when grouped, so a new node starts a group
+        if app.group_pods_by_node && last_node != Some(&pod.node) {
+            rows.push(node_group_row(app, &pod.node));
+        }
+        last_node = Some(&pod.node);
+        let pinned = app.pinned_pods.contains(&pod.name);
+        let cells: Vec<Cell> = columns
+            .iter()
+            .map(|c| match c.0 {
+                "name" if pinned => Cell::from(format!("📌 {}", pod.name)),
+                key => pod_cell(pod, key, &app.config),
+            })
+            .collect();
+        let style = if pinned && i != app.pod_index {
+            Style::default().fg(Color::Cyan)
+        } else {
+            row_style(i == app.pod_index)
+        };
+        rows.push(Row::new(cells).style(style).height(1));
+    }
+
+    let status = match app.config.pod_status {
+        PodStatusDisplay::Phase => "status: phase, S for computed",
+        PodStatusDisplay::Computed => "status: computed, S for phase",
+    };
+    let title = if app.group_pods_by_node {
+        format!("Pods by node ({}, G to ungroup)", status)
+    } else {
+        format!("Pods ({})", status)
+    } + &filter_title(app, View::Pods);
+    let table = Table::new(rows, column_widths(&columns, &app.config.pods))
+        .header(column_header(&columns, app.column_focus))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .style(Style::default()),
+        );
+
+    // The selected pod's node, IP, QoS and owner, without opening its detail
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+    f.render_widget(table, chunks[0]);
+    if let Some(pod) = app.selected_pod() {
+        let summary = Paragraph::new(format!(" {}", pod.summary_line()))
+            .style(Style::default().fg(Color::DarkGray));
+        f.render_widget(summary, chunks[1]);
+    }
+}
+
+/// The selected pod's latest log lines under the Pods list, kept at the bottom
+fn render_log_preview(f: &mut Frame, app: &App, area: Rect) {
+    let (title, logs) = match &app.log_preview {
+        Some(preview) => (
+            format!("Logs: {} (following, p to close, l to open)", preview.pod),
+            preview.logs.as_str(),
+        ),
+        None => ("Logs (no pod selected)".to_string(), ""),
+    };
+    // No wrapping, so the last lines are exactly the ones that fit
+    let visible_height = area.height.saturating_sub(2) as usize;
+    let total = logs.lines().count();
+    let lines: Vec<Line> = logs
+        .lines()
+        .skip(total.saturating_sub(visible_height))
+        .map(Line::raw)
+        .collect();
+
+    let preview = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(Style::default()),
+    );
+    f.render_widget(preview, area);
+}
+
+/// Header row of a node's group: how many of the listed pods run there, as a
+/// count and a share, so a node carrying too much stands out
+fn node_group_row(app: &App, node: &str) -> Row<'static> {
+    let count = app.pods.iter().filter(|p| p.node == node).count();
+    let share = count * 100 / app.pods.len().max(1);
+    let name = if node == "<none>" {
+        "<unscheduled>"
+    } else {
+        node
+    };
+    Row::new(vec![Cell::from(format!(
+        "▾ {} - {} pod{} ({}%)",
+        name,
+        count,
+        if count == 1 { "" } else { "s" },
+        share
+    ))])
+    .style(
+        Style::default()
+            .fg(Color::Magenta)
+            .add_modifier(Modifier::BOLD),
+    )
+    .height(1)
+}
+
+fn render_deployments_view(f: &mut Frame, app: &App, area: Rect) {
+    let columns = visible_columns(
+        DEPLOYMENT_COLUMNS,
+        app.config.deployments.columns.as_ref(),
+        DEFAULT_DEPLOYMENT_COLUMNS,
+    );
+
+    let rows = app
+        .deployments
+        .iter()
+        .enumerate()
+        .filter(|(_, d)| app.shown_in_list(View::Deployments, &d.name))
+        .map(|(i, dep)| {
+            let marked = app.marked_deployments.contains(&dep.name);
+            let cells: Vec<Cell> = columns
+                .iter()
+                .map(|c| match c.0 {
+                    "name" if marked => Cell::from(format!("✓ {}", dep.name)),
+                    key => deployment_cell(dep, key, &app.config.age_colors),
+                })
+                .collect();
+            let style = if marked && i != app.deployment_index {
+                Style::default().fg(Color::Magenta)
+            } else {
+                row_style(i == app.deployment_index)
+            };
+            Row::new(cells).style(style).height(1)
+        });
+
+    let title = match app.marked_deployments.len() {
+        0 => "Deployments".to_string(),
+        n => format!("Deployments - {} marked, s scales them all", n),
+    } + &filter_title(app, View::Deployments);
+    let table = Table::new(rows, column_widths(&columns, &app.config.deployments))
+        .header(column_header(&columns, app.column_focus))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .style(Style::default()),
+        );
+
+    f.render_widget(table, area);
+}
+
+fn render_services_view(f: &mut Frame, app: &App, area: Rect) {
+    let columns = visible_columns(
+        SERVICE_COLUMNS,
+        app.config.services.columns.as_ref(),
+        DEFAULT_SERVICE_COLUMNS,
+    );
+
+    let rows = app
+        .services
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| app.shown_in_list(View::Services, &s.name))
+        .map(|(i, svc)| {
+            let cells: Vec<Cell> = columns
+                .iter()
+                .map(|c| service_cell(svc, c.0, &app.config.age_colors))
+                .collect();
+            Row::new(cells)
+                .style(row_style(i == app.service_index))
+                .height(1)
+        });
+
+    let table = Table::new(rows, column_widths(&columns, &app.config.services))
+        .header(column_header(&columns, app.column_focus))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Services{}", filter_title(app, View::Services)))
+                .style(Style::default()),
+        );
+
+    f.render_widget(table, area);
+}
+
+/// The name filter a list view applies, for its title
+fn filter_title(app: &App, view: View) -> String {
+    app.list_filter(view)
+        .map(|q| format!(" [filter: {}]", q))
+        .unwrap_or_default()
+}
+
+fn render_configmaps_view(f: &mut Frame, app: &App, area: Rect) {
+    let header_cells = ["NAME", "DATA", "AGE"]
+        .iter()
+        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
+
+    let header = Row::new(header_cells)
+        .style(Style::default())
+        .height(1)
+        .bottom_margin(1);
+
+    let rows = app.configmaps.iter().enumerate().map(|(i, configmap)| {
+        let cells = vec![
+            Cell::from(configmap.name.clone()),
+            Cell::from(configmap.data_keys.to_string()),
+            Cell::from(configmap.age.clone()),
+        ];
+
+        Row::new(cells)
+            .style(row_style(i == app.configmap_index))
+            .height(1)
+    });
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(70),
+            Constraint::Percentage(15),
+            Constraint::Percentage(15),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("ConfigMaps - Enter to preview the data")
+            .style(Style::default()),
+    );
+
+    f.render_widget(table, area);
+}
+
+fn render_secrets_view(f: &mut Frame, app: &App, area: Rect) {
+    let header_cells = ["NAME", "TYPE", "DATA", "AGE"]
+        .iter()
+        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
+
+    let header = Row::new(header_cells)
+        .style(Style::default())
+        .height(1)
+        .bottom_margin(1);
+
+    let rows = app.secrets.iter().enumerate().map(|(i, secret)| {
+        let cells = vec![
+            Cell::from(secret.name.clone()),
+            Cell::from(secret.type_.clone()),
+            Cell::from(secret.key_count.to_string()),
+            Cell::from(secret.age.clone()),
+        ];
+
+        Row::new(cells)
+            .style(row_style(i == app.secret_index))
+            .height(1)
+    });
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(45),
+            Constraint::Percentage(35),
+            Constraint::Percentage(10),
+            Constraint::Percentage(10),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Secrets - Enter to list the keys")
+            .style(Style::default()),
+    );
+
+    f.render_widget(table, area);
+}
+
+fn render_events_view(f: &mut Frame, app: &App, area: Rect) {
+    let header_cells = ["LAST SEEN", "TYPE", "REASON", "OBJECT", "COUNT", "MESSAGE"]
+        .iter()
+        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
+
+    let header = Row::new(header_cells)
+        .style(Style::default())
+        .height(1)
+        .bottom_margin(1);
+
+    let rows = app.events.iter().enumerate().map(|(i, event)| {
+        let type_style = if event.type_ == "Warning" {
+            Style::default().fg(Color::Red)
+        } else {
+            Style::default()
+        };
+
+        let cells = vec![
+            Cell::from(event.age.clone()),
+            Cell::from(event.type_.clone()).style(type_style),
+            Cell::from(event.reason.clone()),
+            Cell::from(event.object.clone()),
+            Cell::from(event.count.to_string()),
+            Cell::from(event.message.clone()),
+        ];
+
+        Row::new(cells)
+            .style(row_style(i == app.event_index))
+            .height(1)
+    });
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(8),
+            Constraint::Percentage(8),
+            Constraint::Percentage(14),
+            Constraint::Percentage(20),
+            Constraint::Percentage(6),
+            Constraint::Percentage(44),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(if app.events_follow {
+                "Events [LIVE] - newest first, 'f' to stop"
+            } else {
+                "Events - 'f' to follow live"
+            })
+            .style(Style::default()),
+    );
+
+    f.render_widget(table, area);
+}
+
+fn render_logs_view(f: &mut Frame, app: &App, area: Rect) {
+    let total_lines = app.shown_log_line_count();
+    let follow_indicator = if app.logs_follow && !app.logs_paused {
+        " [FOLLOW]"
+    } else {
+        ""
+    };
+    let instance = if app.logs_previous {
+        format!(" [PREVIOUS: {}]", previous_instance_label(app))
+    } else {
+        String::new()
+    };
+    let scope = match &app.logs_grep {
+        _ if app.log_watcher.is_some() => format!(
+            "All containers, streaming from the last {} lines each",
+            app.config.logs.tail_lines
+        ),
+        Some(pattern) => format!(
+            "Lines matching \"{}\" in the last {}",
+            pattern,
+            app.config.logs.tail_lines.max(LOG_GREP_TAIL_LINES)
+        ),
+        None => format!("Last {} lines", app.config.logs.tail_lines),
+    };
+    let title = format!(
+        "Pod Logs ({}){} - Line {}/{}{} - Press 'f' to toggle follow, 'p' for previous, 'g' to grep, 'i'/'x' to filter, 'w' to wrap",
+        scope,
+        instance,
+        app.logs_scroll + 1,
+        total_lines.max(1),
+        follow_indicator
+    );
+    // A follow whose refreshes keep failing would otherwise look like a quiet log
+    let mut title = vec![Span::raw(title)];
+    let containers = app.logs_pod_containers();
+    let merged = app.log_watcher.is_some();
+    if merged {
+        // Named in the colors their lines are prefixed with
+        title.push(Span::raw(" -"));
+        for (i, container) in containers.iter().enumerate() {
+            title.push(Span::styled(
+                format!(" [{}]", container.name),
+                Style::default().fg(container_color(i)),
+            ));
+        }
+    } else if containers.len() > 1 {
+        title.push(Span::raw(" - Tab: container "));
+        for container in containers {
+            let dot = match container.health {
+                ContainerHealth::Running => Color::Green,
+                ContainerHealth::Waiting => Color::Yellow,
+                ContainerHealth::CrashLooping => Color::Red,
+                ContainerHealth::Terminated | ContainerHealth::Unknown => Color::DarkGray,
+            };
+            let name_style = if app.logs_container.as_deref() == Some(container.name.as_str()) {
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .add_modifier(Modifier::UNDERLINED)
+            } else {
+                Style::default()
+            };
+            title.push(Span::styled(" ● ", Style::default().fg(dot)));
+            title.push(Span::styled(container.name.clone(), name_style));
+        }
+    }
+    // Why the container last restarted, next to the logs that led up to it
+    if let Some(termination) = app.logs_last_termination() {
+        let color = if termination.exit_code == 0 {
+            Color::Yellow
+        } else {
+            Color::Red
+        };
+        title.push(Span::styled(
+            format!(
+                " [prev exit {} {}]",
+                termination.exit_code, termination.reason
+            ),
+            Style::default().fg(color),
+        ));
+    }
+    if app.logs_paused {
+        title.push(Span::styled(
+            " [PAUSED - scrolled up]",
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    if !app.logs_wrap {
+        title.push(Span::raw(" [NOWRAP]"));
+    }
+    if let Some(include) = &app.logs_include {
+        title.push(Span::styled(
+            format!(" [include /{}/]", include.as_str()),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+    if let Some(exclude) = &app.logs_exclude {
+        title.push(Span::styled(
+            format!(" [exclude /{}/]", exclude.as_str()),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    if let Some(stalled) = app.logs_stalled_for() {
+        title.push(Span::styled(
+            format!(" [STALLED {}s]", stalled.as_secs()),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    // Only build the lines that can be on screen, so long buffers don't slow
+    // down wrapping and rendering; the scroll offset is applied by skipping.
+    // Filtering comes first so the scroll counts shown lines, while the index
+    // stays that of the raw line for the new-lines highlight
+    let visible_height = area.height.saturating_sub(2) as usize;
+    let new_line_style = Style::default().bg(Color::DarkGray).fg(Color::White);
+    let lines: Vec<Line> = app
+        .logs
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| app.log_line_shown(line))
+        .skip(app.logs_scroll)
+        .take(visible_height)
+        .map(|(i, line)| {
+            let line = if merged {
+                prefixed_log_line(line, containers)
+            } else {
+                Line::raw(line)
+            };
+            match app.logs_new_from {
+                Some(start) if i >= start => line.style(new_line_style),
+                _ => line,
+            }
+        })
+        .collect();
+
+    let mut logs = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(Line::from(title))
+            .style(Style::default()),
+    );
+    if app.logs_wrap {
+        logs = logs.wrap(Wrap { trim: false });
+    }
+
+    f.render_widget(logs, area);
+}
+
+/// Colors telling containers apart in a merged log, by their spec order
+const CONTAINER_COLORS: [Color; 6] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::LightRed,
+];
+
+fn container_color(index: usize) -> Color {
+    CONTAINER_COLORS[index % CONTAINER_COLORS.len()]
+}
+
+/// Color the `[container] ` prefix of a merged log line after its container
+fn prefixed_log_line<'a>(line: &'a str, containers: &[ContainerSummary]) -> Line<'a> {
+    let index = line
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once("] "))
+        .and_then(|(name, _)| containers.iter().position(|c| c.name == name));
+    match index {
+        Some(index) => {
+            let (prefix, rest) = line.split_at(containers[index].name.len() + 2);
+            Line::from(vec![
+                Span::styled(prefix, Style::default().fg(container_color(index))),
+                Span::raw(rest),
+            ])
+        }
+        None => Line::raw(line),
+    }
+}
+
+/// Describe which instance `previous` logs come from: the one before the latest restart
+fn previous_instance_label(app: &App) -> String {
+    match app.logs_last_termination() {
+        Some(termination) => {
+            let finished = termination
+                .finished_at
+                .map(|t| app.config.timezone.format(&t, "%Y-%m-%d %H:%M:%S %Z"))
+                .unwrap_or_else(|| "unknown time".to_string());
+            format!(
+                "{} before restart #{}, {} (exit {}) at {}",
+                termination.container,
+                termination.restart_count,
+                termination.reason,
+                termination.exit_code,
+                finished
+            )
+        }
+        None => "instance before the last restart".to_string(),
+    }
+}
+
+fn render_clusters_view(f: &mut Frame, app: &App, area: Rect) {
+    let header_cells = ["CONTEXT", "CLUSTER", "SERVER", "NAMESPACE"]
+        .iter()
+        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
+
+    let header = Row::new(header_cells)
+        .style(Style::default())
+        .height(1)
+        .bottom_margin(1);
+
+    // Contexts sharing a cluster (other users or namespaces) come in a run and
+    // get a header, so they don't read as near-duplicate rows
+    let mut per_cluster: HashMap<&str, usize> = HashMap::new();
+    for ctx in &app.contexts {
+        *per_cluster.entry(ctx.cluster.as_str()).or_default() += 1;
+    }
+
+    let mut rows = Vec::with_capacity(app.contexts.len());
+    for (i, ctx) in app.contexts.iter().enumerate() {
+        let shared = per_cluster[ctx.cluster.as_str()];
+        let grouped = shared > 1;
+        if grouped && (i == 0 || app.contexts[i - 1].cluster != ctx.cluster) {
+            rows.push(
+                Row::new(vec![Cell::from(format!(
+                    "▾ {} - {} contexts",
+                    ctx.cluster, shared
+                ))])
+                .style(
+                    Style::default()
+                        .fg(Color::Magenta)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .height(1),
+            );
+        }
+
+        let mut name = ctx.name.clone();
+
+        // Add a visual indicator for the current context
+        if ctx.is_current {
+            name = format!("▶ {}", name);
+        }
+        if grouped {
+            name = format!("  {}", name);
+        }
+        if app.config.favorites.contexts.contains(&ctx.name) {
+            name = format!("{} ★", name);
+        }
+
+        let cells = vec![
+            Cell::from(name),
+            Cell::from(ctx.cluster.clone()),
+            Cell::from(ctx.server.clone()),
+            Cell::from(ctx.namespace.clone()),
+        ];
+
+        let style = if i == app.context_index {
+            Style::default()
+                .bg(Color::DarkGray)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD)
+        } else if ctx.is_current {
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
 
-    if !app.current_context.is_empty() {
-        title.push(Span::raw(" │ "));
-        title.push(Span::styled(
-            format!("Context: {}", app.current_context),
-            Style::default().fg(Color::Green),
-        ));
+        rows.push(Row::new(cells).style(style).height(1));
     }
 
-    title.push(Span::raw(" │ "));
-    title.push(Span::styled(
-        format!("Namespace: {}", app.current_namespace),
-        Style::default().fg(Color::Yellow),
-    ));
-
-    let header = Paragraph::new(Line::from(title)).block(Block::default().borders(Borders::ALL));
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(25),
+            Constraint::Percentage(25),
+            Constraint::Percentage(35),
+            Constraint::Percentage(15),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Clusters / Contexts")
+            .style(Style::default()),
+    );
 
-    f.render_widget(header, area);
+    f.render_widget(table, area);
 }
 
-fn render_tabs(f: &mut Frame, app: &App, area: Rect) {
-    let tabs = [
-        ("1", "Pods", View::Pods),
-        ("2", "Deployments", View::Deployments),
-        ("3", "Services", View::Services),
-        ("4", "Clusters", View::Clusters),
-        ("5", "Namespaces", View::Namespaces),
-        ("?", "Help", View::Help),
-    ];
+fn render_namespaces_view(f: &mut Frame, app: &App, area: Rect) {
+    let header_cells = ["NAMESPACE"]
+        .iter()
+        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
 
-    let mut tab_spans = Vec::new();
+    let header = Row::new(header_cells)
+        .style(Style::default())
+        .height(1)
+        .bottom_margin(1);
 
-    for (i, (key, label, view)) in tabs.iter().enumerate() {
-        if i > 0 {
-            tab_spans.push(Span::raw(" "));
+    let rows = app.namespaces.iter().enumerate().map(|(i, ns)| {
+        let mut name = ns.clone();
+
+        // Add indicator for current namespace
+        if ns == &app.current_namespace {
+            name = format!("▶ {}", ns);
+        }
+        if app.config.favorites.namespaces.contains(ns) {
+            name = format!("{} ★", name);
         }
 
-        let is_active = *view == app.current_view;
+        let cells = vec![Cell::from(name)];
 
-        let style = if is_active {
+        let style = if i == app.namespace_index {
             Style::default()
-                .fg(Color::Black)
-                .bg(Color::Cyan)
+                .bg(Color::DarkGray)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD)
+        } else if ns == &app.current_namespace {
+            Style::default()
+                .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD)
         } else {
             Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::DIM)
         };
 
-        let tab_text = format!(" {} {} ", key, label);
-        tab_spans.push(Span::styled(tab_text, style));
-    }
-
-    tab_spans.push(Span::raw("  "));
-    tab_spans.push(Span::styled(
-        "← → Navigate",
-        Style::default()
-            .fg(Color::DarkGray)
-            .add_modifier(Modifier::ITALIC),
-    ));
-
-    let tabs_paragraph =
-        Paragraph::new(Line::from(tab_spans)).block(Block::default().borders(Borders::ALL));
+        Row::new(cells).style(style).height(1)
+    });
 
-    f.render_widget(tabs_paragraph, area);
-}
+    let table = Table::new(rows, [Constraint::Percentage(100)])
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(if app.config.namespaces.hide_system {
+                    "Namespaces (system hidden, s to show)"
+                } else {
+                    "Namespaces (s to hide system)"
+                })
+                .style(Style::default()),
+        );
 
-fn render_main_content(f: &mut Frame, app: &App, area: Rect) {
-    match app.current_view {
-        View::Pods => render_pods_view(f, app, area),
-        View::Deployments => render_deployments_view(f, app, area),
-        View::Services => render_services_view(f, app, area),
-        View::Logs => render_logs_view(f, app, area),
-        View::Clusters => render_clusters_view(f, app, area),
-        View::Namespaces => render_namespaces_view(f, app, area),
-        View::Help => render_help_view(f, app, area),
-        View::Terminal => render_terminal_view(f, app, area),
-    }
+    f.render_widget(table, area);
 }
 
-fn render_pods_view(f: &mut Frame, app: &App, area: Rect) {
-    let header_cells = ["NAME", "READY", "STATUS", "RESTARTS", "AGE"]
+fn render_activity_view(f: &mut Frame, app: &App, area: Rect) {
+    let header_cells = ["TIME", "CONTEXT", "NAMESPACE", "ACTION"]
         .iter()
         .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
 
@@ -123,16 +2040,19 @@ fn render_pods_view(f: &mut Frame, app: &App, area: Rect) {
         .height(1)
         .bottom_margin(1);
 
-    let rows = app.pods.iter().enumerate().map(|(i, pod)| {
+    let rows = app.activity.iter().enumerate().map(|(i, entry)| {
         let cells = vec![
-            Cell::from(pod.name.clone()),
-            Cell::from(pod.ready.clone()),
-            Cell::from(pod.status.clone()),
-            Cell::from(pod.restarts.to_string()),
-            Cell::from(pod.age.clone()),
+            Cell::from(
+                app.config
+                    .timezone
+                    .format(&entry.timestamp, "%Y-%m-%d %H:%M:%S"),
+            ),
+            Cell::from(entry.context.clone()),
+            Cell::from(entry.namespace.clone()),
+            Cell::from(entry.action.clone()),
         ];
 
-        let style = if i == app.pod_index {
+        let style = if i == app.activity_index {
             Style::default()
                 .bg(Color::DarkGray)
                 .fg(Color::White)
@@ -144,29 +2064,33 @@ fn render_pods_view(f: &mut Frame, app: &App, area: Rect) {
         Row::new(cells).style(style).height(1)
     });
 
+    let title = match &app.config.activity_log {
+        Some(path) => format!("Activity (also logged to {})", path.display()),
+        None => "Activity".to_string(),
+    };
+
     let table = Table::new(
         rows,
         [
-            Constraint::Percentage(30),
-            Constraint::Percentage(15),
             Constraint::Percentage(20),
-            Constraint::Percentage(15),
             Constraint::Percentage(20),
+            Constraint::Percentage(15),
+            Constraint::Percentage(45),
         ],
     )
     .header(header)
     .block(
         Block::default()
             .borders(Borders::ALL)
-            .title("Pods")
+            .title(title)
             .style(Style::default()),
     );
 
     f.render_widget(table, area);
 }
 
-fn render_deployments_view(f: &mut Frame, app: &App, area: Rect) {
-    let header_cells = ["NAME", "READY", "UP-TO-DATE", "AVAILABLE", "AGE"]
+fn render_port_forwards_view(f: &mut Frame, app: &App, area: Rect) {
+    let header_cells = ["LOCAL", "POD", "PORT", "LAST ERROR"]
         .iter()
         .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
 
@@ -175,228 +2099,1031 @@ fn render_deployments_view(f: &mut Frame, app: &App, area: Rect) {
         .height(1)
         .bottom_margin(1);
 
-    let rows = app.deployments.iter().enumerate().map(|(i, dep)| {
+    let rows = app.port_forwards.iter().enumerate().map(|(i, forward)| {
         let cells = vec![
-            Cell::from(dep.name.clone()),
-            Cell::from(dep.ready.clone()),
-            Cell::from(dep.up_to_date.to_string()),
-            Cell::from(dep.available.to_string()),
-            Cell::from(dep.age.clone()),
+            Cell::from(forward.local_addr.to_string()),
+            Cell::from(format!("{}/{}", forward.namespace, forward.pod)),
+            Cell::from(forward.remote_port.to_string()),
+            Cell::from(forward.last_error().unwrap_or_default())
+                .style(Style::default().fg(Color::Red)),
         ];
 
-        let style = if i == app.deployment_index {
-            Style::default()
-                .bg(Color::DarkGray)
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD)
-        } else {
-            Style::default()
-        };
-
-        Row::new(cells).style(style).height(1)
+        Row::new(cells)
+            .style(row_style(i == app.port_forward_index))
+            .height(1)
     });
 
+    let title = if app.port_forwards.is_empty() {
+        "Port Forwards - none running, press F on a pod to start one"
+    } else {
+        "Port Forwards - 'd' to stop"
+    };
+
     let table = Table::new(
         rows,
         [
-            Constraint::Percentage(30),
-            Constraint::Percentage(20),
             Constraint::Percentage(20),
-            Constraint::Percentage(15),
-            Constraint::Percentage(15),
+            Constraint::Percentage(30),
+            Constraint::Percentage(10),
+            Constraint::Percentage(40),
         ],
     )
     .header(header)
     .block(
         Block::default()
             .borders(Borders::ALL)
-            .title("Deployments")
+            .title(title)
             .style(Style::default()),
     );
 
-    f.render_widget(table, area);
+    f.render_widget(table, area);
+}
+
+fn render_detail_view(f: &mut Frame, app: &mut App, area: Rect) {
+    // A pod's recent usage goes above its detail, once metrics-server has sampled it
+    let area = match (&app.detail, &app.pod_usage) {
+        (Some(Detail::Pod(detail)), Some(usage))
+            if usage.namespace == detail.namespace
+                && usage.pod == detail.name
+                && !usage.containers.is_empty() =>
+        {
+            let height = (usage.containers.len() as u16 * 2 + 2).min(area.height / 2);
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(height), Constraint::Min(0)])
+                .split(area);
+            render_usage_sparklines(f, usage, chunks[0]);
+            chunks[1]
+        }
+        _ => area,
+    };
+
+    let (title, lines) = match &app.detail {
+        Some(Detail::Pod(detail)) => (
+            format!("Pod: {}/{}", detail.namespace, detail.name),
+            pod_detail_lines(detail),
+        ),
+        Some(Detail::Deployment(detail)) => (
+            format!(
+                "Deployment: {}/{} - 'e' to edit a plain env var",
+                detail.namespace, detail.name
+            ),
+            deployment_detail_lines(detail),
+        ),
+        Some(Detail::Node(detail)) => (format!("Node: {}", detail.name), node_detail_lines(detail)),
+        Some(Detail::Service(detail)) => (
+            format!("Service: {}/{}", detail.namespace, detail.name),
+            service_detail_lines(detail),
+        ),
+        Some(Detail::ConfigMap(detail)) => (
+            format!("ConfigMap: {}/{}", detail.namespace, detail.name),
+            configmap_detail_lines(detail),
+        ),
+        Some(Detail::Secret(detail)) => (
+            format!(
+                "Secret: {}/{} - values {} ('x')",
+                detail.namespace,
+                detail.name,
+                if app.secret_revealed {
+                    "revealed"
+                } else {
+                    "masked"
+                }
+            ),
+            secret_detail_lines(detail, app.secret_revealed),
+        ),
+        Some(Detail::Namespace(summary)) => (
+            format!(
+                "Namespace: {} - Enter to switch, Esc to go back",
+                summary.namespace
+            ),
+            namespace_summary_lines(summary),
+        ),
+        Some(Detail::Connection(detail)) => (
+            format!("Context: {}", detail.context),
+            connection_detail_lines(detail),
+        ),
+        Some(Detail::Yaml(doc)) => (
+            format!(
+                "YAML: {}/{} - managedFields {} ('m'), status {} ('s')",
+                doc.kind.as_str(),
+                doc.name,
+                if doc.show_managed_fields {
+                    "shown"
+                } else {
+                    "hidden"
+                },
+                if doc.show_status { "shown" } else { "hidden" },
+            ),
+            doc.to_yaml()
+                .lines()
+                .map(|l| Line::raw(l.to_string()))
+                .collect(),
+        ),
+        Some(Detail::History(history)) => (
+            format!(
+                "Rollout History: {}/{} - 'u' to roll back to the selected revision",
+                history.namespace, history.name
+            ),
+            history_lines(history, app.history_index),
+        ),
+        Some(Detail::Kubeconfig(kubeconfig)) => (
+            "Effective kubeconfig - credentials redacted".to_string(),
+            kubeconfig_lines(kubeconfig),
+        ),
+        Some(Detail::Describe(output)) => (
+            format!("kubectl describe {} {}", output.kind, output.name),
+            output
+                .text
+                .lines()
+                .map(|l| Line::raw(l.to_string()))
+                .collect(),
+        ),
+        None => ("Detail".to_string(), vec![Line::from("Nothing selected")]),
+    };
+
+    // Keep the scroll offset within the content so scrolling back up responds immediately
+    let visible_height = area.height.saturating_sub(2) as usize;
+    let max_scroll = lines.len().saturating_sub(visible_height);
+    app.detail_scroll = app.detail_scroll.min(max_scroll);
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .style(Style::default()),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((app.detail_scroll as u16, 0));
+
+    f.render_widget(paragraph, area);
+}
+
+/// CPU and memory per container, a label with the latest value beside each sparkline
+fn render_usage_sparklines(f: &mut Frame, usage: &UsageHistory, area: Rect) {
+    let block = Block::default().borders(Borders::ALL).title(format!(
+        "Usage - sampled every {}s",
+        USAGE_SAMPLE_INTERVAL.as_secs()
+    ));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let name_width = usage
+        .containers
+        .iter()
+        .map(|c| c.container.width())
+        .max()
+        .unwrap_or(0)
+        .min(30);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(1); usage.containers.len() * 2])
+        .split(inner);
+
+    let mut rows = rows.iter();
+    for container in &usage.containers {
+        let series = [
+            (
+                container.container.as_str(),
+                "CPU",
+                &container.cpu_millis,
+                format_millicores(container.cpu_millis.back().copied().unwrap_or(0)),
+                Color::Cyan,
+            ),
+            (
+                "",
+                "MEM",
+                &container.memory_bytes,
+                format_bytes(container.memory_bytes.back().copied().unwrap_or(0)),
+                Color::Magenta,
+            ),
+        ];
+        for (name, metric, samples, latest, color) in series {
+            let Some(row) = rows.next() else {
+                return;
+            };
+            let label = format!(
+                " {} {} {:>7} ",
+                pad_to_width(&truncate_to_width(name, name_width), name_width),
+                metric,
+                latest
+            );
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(label.width() as u16), Constraint::Min(0)])
+                .split(*row);
+            f.render_widget(Paragraph::new(label), columns[0]);
+            f.render_widget(
+                Sparkline::default()
+                    .data(newest_samples(samples, columns[1].width as usize))
+                    .style(Style::default().fg(color)),
+                columns[1],
+            );
+        }
+    }
+}
+
+/// The newest samples that fit in `width` columns; a sparkline draws from the left
+fn newest_samples(samples: &VecDeque<u64>, width: usize) -> Vec<u64> {
+    samples
+        .iter()
+        .skip(samples.len().saturating_sub(width))
+        .copied()
+        .collect()
+}
+
+/// CPU as kubectl top prints it, e.g. `250m`
+fn format_millicores(millis: u64) -> String {
+    format!("{}m", millis)
+}
+
+/// Memory in binary units as kubectl top prints it, e.g. `128Mi`
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["Ki", "Mi", "Gi", "Ti"];
+    let mut value = bytes as f64;
+    let mut unit = "";
+    for next in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+    if unit.is_empty() || value >= 10.0 {
+        format!("{:.0}{}", value, unit)
+    } else {
+        format!("{:.1}{}", value, unit)
+    }
+}
+
+fn detail_section(title: &str) -> Line<'static> {
+    Line::from(vec![Span::styled(
+        title.to_string(),
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )])
+}
+
+fn detail_field(label: &str, value: impl Into<String>) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("  {:<24}", label), Style::default().fg(Color::Cyan)),
+        Span::raw(value.into()),
+    ])
+}
+
+fn pod_detail_lines(detail: &PodDetail) -> Vec<Line<'static>> {
+    let none = || {
+        Line::from(Span::styled(
+            "  <none>",
+            Style::default().fg(Color::DarkGray),
+        ))
+    };
+
+    let mut lines = vec![
+        detail_section("Pod"),
+        detail_field("Phase", detail.phase.clone()),
+        detail_field(
+            "Node",
+            detail
+                .node
+                .clone()
+                .unwrap_or_else(|| "<not scheduled>".to_string()),
+        ),
+        detail_field(
+            "Pod IP",
+            detail.pod_ip.clone().unwrap_or_else(|| "-".to_string()),
+        ),
+        Line::from(""),
+        detail_section("Conditions"),
+    ];
+
+    if detail.conditions.is_empty() {
+        lines.push(none());
+    }
+    for condition in &detail.conditions {
+        let style = if condition.status == "True" {
+            Style::default().fg(Color::Green)
+        } else {
+            Style::default().fg(Color::Red)
+        };
+        let mut spans = vec![
+            Span::styled(
+                format!("  {}", pad_to_width(&condition.type_, 24)),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(condition.status.clone(), style),
+        ];
+        if let Some(reason) = &condition.reason {
+            spans.push(Span::raw(format!(" ({})", reason)));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(detail_section("Scheduling"));
+    if let Some(message) = detail.unschedulable_message() {
+        lines.push(Line::from(Span::styled(
+            format!("  Unschedulable: {}", message),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    lines.push(Line::from(Span::styled(
+        "  Node Selector",
+        Style::default().fg(Color::Cyan),
+    )));
+    if detail.node_selector.is_empty() {
+        lines.push(none());
+    }
+    for (key, value) in &detail.node_selector {
+        lines.push(Line::from(format!("    {}={}", key, value)));
+    }
+    lines.push(Line::from(Span::styled(
+        "  Affinity",
+        Style::default().fg(Color::Cyan),
+    )));
+    if detail.affinity.is_empty() {
+        lines.push(none());
+    }
+    for rule in &detail.affinity {
+        lines.push(Line::from(format!("    {}", rule)));
+    }
+    lines.push(Line::from(Span::styled(
+        "  Tolerations",
+        Style::default().fg(Color::Cyan),
+    )));
+    if detail.tolerations.is_empty() {
+        lines.push(none());
+    }
+    for toleration in &detail.tolerations {
+        lines.push(Line::from(format!("    {}", toleration)));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(detail_section("Probes"));
+    if detail.probes.is_empty() {
+        lines.push(none());
+    }
+    for container in &detail.probes {
+        let (state, style) = match container.ready {
+            Some(true) => ("ready", Style::default().fg(Color::Green)),
+            Some(false) => ("not ready", Style::default().fg(Color::Red)),
+            None => ("no status", Style::default().fg(Color::DarkGray)),
+        };
+        let mut spans = vec![
+            Span::styled(
+                format!("  {}", pad_to_width(&container.name, 24)),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(state, style),
+        ];
+        if container.started == Some(false) {
+            spans.push(Span::styled(
+                " (startup probe not yet passed)",
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        lines.push(Line::from(spans));
+
+        let probes = [
+            ("Liveness", &container.liveness),
+            ("Readiness", &container.readiness),
+            ("Startup", &container.startup),
+        ];
+        if probes.iter().all(|(_, probe)| probe.is_none()) {
+            lines.push(Line::from(Span::styled(
+                "    <no probes configured>",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        for (label, probe) in probes {
+            if let Some(probe) = probe {
+                lines.push(Line::from(format!("    {:<10} {}", label, probe)));
+            }
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(detail_section("Volumes"));
+    if detail.volumes.is_empty() {
+        lines.push(none());
+    }
+    for volume in &detail.volumes {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {}", pad_to_width(&volume.name, 24)),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::raw(volume.source.clone()),
+        ]));
+    }
+
+    if !detail.rollout_annotations.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(detail_section("Rollout Annotations"));
+        lines.extend(rollout_annotation_lines(&detail.rollout_annotations));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(detail_section("Mounts"));
+    if detail.mounts.is_empty() {
+        lines.push(none());
+    }
+    for (container, mounts) in &detail.mounts {
+        lines.push(Line::from(Span::styled(
+            format!("  {}", container),
+            Style::default().fg(Color::Cyan),
+        )));
+        for mount in mounts {
+            let mut text = format!("    {} ← {}", mount.path, mount.volume);
+            if let Some(sub_path) = &mount.sub_path {
+                text.push_str(&format!(" (subPath {})", sub_path));
+            }
+            // A mount naming no volume of the pod keeps the pod from starting
+            let known = detail.volumes.iter().any(|v| v.name == mount.volume);
+            let mut spans = vec![Span::raw(text)];
+            if mount.read_only {
+                spans.push(Span::styled(" ro", Style::default().fg(Color::DarkGray)));
+            }
+            if !known {
+                spans.push(Span::styled(
+                    " (no such volume)",
+                    Style::default().fg(Color::Red),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(detail_section("Restarts"));
+    if detail.containers.is_empty() {
+        lines.push(none());
+    }
+    for container in &detail.containers {
+        let restarts_style = if container.restart_count > 0 {
+            Style::default().fg(Color::Red)
+        } else {
+            Style::default().fg(Color::Green)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {}", pad_to_width(&container.name, 24)),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(
+                format!("{} restarts", container.restart_count),
+                restarts_style,
+            ),
+        ]));
+        lines.push(Line::from(format!("    Current:   {}", container.state)));
+        if let Some(last) = &container.last_termination {
+            lines.push(Line::from(format!("    Last exit: {}", last)));
+        }
+        if !container.reasons.is_empty() {
+            let summary = container
+                .reasons
+                .iter()
+                .map(|(reason, count)| format!("{} × {}", reason, count))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let seen: usize = container.reasons.iter().map(|(_, count)| count).sum();
+            let unseen = (container.restart_count.max(0) as usize).saturating_sub(seen);
+            let mut spans = vec![Span::styled(
+                format!("    Reasons:   {}", summary),
+                Style::default().fg(Color::Yellow),
+            )];
+            if unseen > 0 {
+                spans.push(Span::styled(
+                    format!(" (+{} earlier not retained)", unseen),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+    }
+
+    lines
 }
 
-fn render_services_view(f: &mut Frame, app: &App, area: Rect) {
-    let header_cells = ["NAME", "TYPE", "CLUSTER-IP", "PORTS", "AGE"]
-        .iter()
-        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
-
-    let header = Row::new(header_cells)
-        .style(Style::default())
-        .height(1)
-        .bottom_margin(1);
+fn node_detail_lines(detail: &NodeDetail) -> Vec<Line<'static>> {
+    let none = || {
+        Line::from(Span::styled(
+            "  <none>",
+            Style::default().fg(Color::DarkGray),
+        ))
+    };
 
-    let rows = app.services.iter().enumerate().map(|(i, svc)| {
-        let cells = vec![
-            Cell::from(svc.name.clone()),
-            Cell::from(svc.service_type.clone()),
-            Cell::from(svc.cluster_ip.clone()),
-            Cell::from(svc.ports.clone()),
-            Cell::from(svc.age.clone()),
-        ];
+    let roles = if detail.roles.is_empty() {
+        "<none>".to_string()
+    } else {
+        detail.roles.join(",")
+    };
+    let mut lines = vec![
+        detail_section("Node"),
+        detail_field("Roles", roles),
+        detail_field(
+            "Pods",
+            detail
+                .pod_count
+                .map(|n| n.to_string())
+                .unwrap_or_else(|| "- (not permitted)".to_string()),
+        ),
+    ];
+    if detail.unschedulable {
+        lines.push(Line::from(Span::styled(
+            format!("  {:<24}SchedulingDisabled (cordoned)", "Scheduling"),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
 
-        let style = if i == app.service_index {
-            Style::default()
-                .bg(Color::DarkGray)
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD)
+    lines.push(Line::from(""));
+    lines.push(detail_section("Conditions"));
+    if detail.conditions.is_empty() {
+        lines.push(none());
+    }
+    for condition in &detail.conditions {
+        // Ready should be True; the pressure conditions should be False
+        let healthy = (condition.type_ == "Ready") == (condition.status == "True");
+        let style = if healthy {
+            Style::default().fg(Color::Green)
         } else {
-            Style::default()
+            Style::default().fg(Color::Red)
         };
+        let mut spans = vec![
+            Span::styled(
+                format!("  {}", pad_to_width(&condition.type_, 24)),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(condition.status.clone(), style),
+        ];
+        if let Some(reason) = &condition.reason {
+            spans.push(Span::raw(format!(" ({})", reason)));
+        }
+        lines.push(Line::from(spans));
+    }
 
-        Row::new(cells).style(style).height(1)
-    });
+    lines.push(Line::from(""));
+    lines.push(detail_section("Capacity / Allocatable"));
+    if detail.resources.is_empty() {
+        lines.push(none());
+    }
+    for resource in &detail.resources {
+        lines.push(detail_field(
+            &resource.name,
+            format!("{} / {}", resource.capacity, resource.allocatable),
+        ));
+    }
 
-    let table = Table::new(
-        rows,
-        [
-            Constraint::Percentage(25),
-            Constraint::Percentage(15),
-            Constraint::Percentage(20),
-            Constraint::Percentage(25),
-            Constraint::Percentage(15),
-        ],
-    )
-    .header(header)
-    .block(
-        Block::default()
-            .borders(Borders::ALL)
-            .title("Services")
-            .style(Style::default()),
-    );
+    lines.push(Line::from(""));
+    lines.push(detail_section("Taints"));
+    if detail.taints.is_empty() {
+        lines.push(none());
+    }
+    for taint in &detail.taints {
+        lines.push(Line::from(format!("  {}", taint)));
+    }
 
-    f.render_widget(table, area);
+    lines.push(Line::from(""));
+    lines.push(detail_section("System Info"));
+    lines.push(detail_field("Kubelet", detail.kubelet_version.clone()));
+    lines.push(detail_field(
+        "Container Runtime",
+        detail.container_runtime.clone(),
+    ));
+    lines.push(detail_field("OS Image", detail.os_image.clone()));
+    lines.push(detail_field("Kernel", detail.kernel_version.clone()));
+
+    lines.push(Line::from(""));
+    lines.push(detail_section("Labels"));
+    if detail.labels.is_empty() {
+        lines.push(none());
+    }
+    for (key, value) in &detail.labels {
+        lines.push(Line::from(format!("  {}={}", key, value)));
+    }
+
+    lines
 }
 
-fn render_logs_view(f: &mut Frame, app: &App, area: Rect) {
-    let total_lines = app.logs.lines().count();
-    let follow_indicator = if app.logs_follow { " [FOLLOW]" } else { "" };
-    let title = format!(
-        "Pod Logs (Last 100 lines) - Line {}/{}{} - Press 'f' to toggle follow",
-        app.logs_scroll + 1,
-        total_lines.max(1),
-        follow_indicator
-    );
+fn namespace_summary_lines(summary: &NamespaceSummary) -> Vec<Line<'static>> {
+    let count = |value: Option<usize>| {
+        value
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| "- (not permitted)".to_string())
+    };
+    let mut lines = vec![detail_section("Pods")];
+
+    match &summary.pod_statuses {
+        None => lines.push(detail_field("Total", count(None))),
+        Some(statuses) => {
+            lines.push(detail_field(
+                "Total",
+                statuses.values().sum::<usize>().to_string(),
+            ));
+            for (status, n) in statuses {
+                let color = match status.as_str() {
+                    "Running" | "Succeeded" => Color::Green,
+                    "Pending" => Color::Yellow,
+                    "Failed" | "Unknown" => Color::Red,
+                    _ => Color::White,
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {:<24}", status), Style::default().fg(color)),
+                    Span::raw(n.to_string()),
+                ]));
+            }
+        }
+    }
 
-    let logs = Paragraph::new(app.logs.clone())
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(title)
-                .style(Style::default()),
-        )
-        .wrap(Wrap { trim: false })
-        .scroll((app.logs_scroll as u16, 0));
+    lines.push(Line::from(""));
+    lines.push(detail_section("Resources"));
+    lines.push(detail_field("Deployments", count(summary.deployments)));
+    lines.push(detail_field("Services", count(summary.services)));
+    lines.push(detail_field("ConfigMaps", count(summary.config_maps)));
+
+    lines.push(Line::from(""));
+    lines.push(detail_section("Warning Events (last hour)"));
+    match &summary.warnings {
+        None => lines.push(Line::from(Span::styled(
+            "  - (not permitted)",
+            Style::default().fg(Color::DarkGray),
+        ))),
+        Some(warnings) if warnings.is_empty() => lines.push(Line::from(Span::styled(
+            "  <none>",
+            Style::default().fg(Color::Green),
+        ))),
+        Some(warnings) => {
+            for event in warnings {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("  {:<8}", event.age),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(
+                        format!("{} ", event.reason),
+                        Style::default().fg(Color::Red),
+                    ),
+                    Span::raw(format!("{}: {}", event.object, event.message)),
+                ]));
+            }
+        }
+    }
 
-    f.render_widget(logs, area);
+    lines
 }
 
-fn render_clusters_view(f: &mut Frame, app: &App, area: Rect) {
-    let header_cells = ["CONTEXT", "CLUSTER", "SERVER", "NAMESPACE"]
-        .iter()
-        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
+fn connection_detail_lines(detail: &ConnectionDetail) -> Vec<Line<'static>> {
+    let or_dash = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+    let mut lines = vec![
+        detail_section("Cluster"),
+        detail_field("Name", detail.cluster.clone()),
+        detail_field("Server", detail.server.clone()),
+        detail_field("Proxy", or_dash(&detail.proxy_url)),
+        Line::from(""),
+        detail_section("TLS"),
+        Line::from(vec![
+            Span::styled(
+                format!("  {:<24}", "Verification"),
+                Style::default().fg(Color::Cyan),
+            ),
+            if detail.tls_verify {
+                Span::styled("on", Style::default().fg(Color::Green))
+            } else {
+                Span::styled(
+                    "off (insecure-skip-tls-verify)",
+                    Style::default().fg(Color::Red),
+                )
+            },
+        ]),
+        detail_field("CA", detail.ca_source.clone()),
+        detail_field("Server Name", or_dash(&detail.tls_server_name)),
+        Line::from(""),
+        detail_section("Authentication"),
+        detail_field(
+            "User",
+            if detail.user.is_empty() {
+                "-".to_string()
+            } else {
+                detail.user.clone()
+            },
+        ),
+    ];
 
-    let header = Row::new(header_cells)
-        .style(Style::default())
-        .height(1)
-        .bottom_margin(1);
+    if detail.auth_methods.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  <none> (anonymous)",
+            Style::default().fg(Color::DarkGray),
+        )));
+    } else {
+        for method in &detail.auth_methods {
+            lines.push(Line::from(format!("  {}", method)));
+        }
+    }
 
-    let rows = app.contexts.iter().enumerate().map(|(i, ctx)| {
-        let mut cells = vec![
-            Cell::from(ctx.name.clone()),
-            Cell::from(ctx.cluster.clone()),
-            Cell::from(ctx.server.clone()),
-            Cell::from(ctx.namespace.clone()),
-        ];
+    lines
+}
 
-        // Add a visual indicator for the current context
-        if ctx.is_current {
-            cells[0] = Cell::from(format!("▶ {}", ctx.name));
-        }
+fn history_lines(history: &DeploymentHistory, selected: usize) -> Vec<Line<'static>> {
+    if history.revisions.is_empty() {
+        return vec![Line::from("No revisions recorded (no ReplicaSets left)")];
+    }
+    let mut lines = Vec::new();
+    for (i, revision) in history.revisions.iter().enumerate() {
+        let current = history.current_revision == Some(revision.revision);
+        lines.push(Line::styled(
+            format!(
+                "{} {:>4}  {:<32} {:>5}  {}",
+                if current { "▶" } else { " " },
+                revision.revision,
+                revision.replica_set,
+                revision.age,
+                revision.images.join(", ")
+            ),
+            row_style(i == selected),
+        ));
+        lines.push(Line::styled(
+            format!(
+                "        {}",
+                revision
+                    .change_cause
+                    .as_deref()
+                    .unwrap_or("(no change-cause recorded)")
+            ),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    lines
+}
 
-        let style = if i == app.context_index {
-            Style::default()
-                .bg(Color::DarkGray)
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD)
-        } else if ctx.is_current {
-            Style::default()
-                .fg(Color::Green)
-                .add_modifier(Modifier::BOLD)
+fn deployment_detail_lines(detail: &DeploymentDetail) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        detail_section("Deployment"),
+        detail_field("Replicas (ready)", detail.replicas.clone()),
+        detail_field("Strategy", detail.strategy.clone()),
+    ];
+
+    if !detail.rollout_annotations.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(detail_section("Rollout Annotations"));
+        lines.extend(rollout_annotation_lines(&detail.rollout_annotations));
+        if detail.stale_pods.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!("  all {} pods match the template", detail.pod_count),
+                Style::default().fg(Color::Green),
+            )));
         } else {
-            Style::default()
-        };
+            // These pods still run with the previous config or from before the restart
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  {} of {} pods differ from the template:",
+                    detail.stale_pods.len(),
+                    detail.pod_count
+                ),
+                Style::default().fg(Color::Yellow),
+            )));
+            for pod in &detail.stale_pods {
+                lines.push(Line::from(Span::styled(
+                    format!("    {}", pod),
+                    Style::default().fg(Color::Yellow),
+                )));
+            }
+        }
+    }
 
-        Row::new(cells).style(style).height(1)
-    });
+    for container in &detail.containers {
+        lines.push(Line::from(""));
+        lines.push(detail_section(&format!(
+            "Container {} ({})",
+            container.name, container.image
+        )));
+
+        if container.env.is_empty() && container.env_from.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  <no environment>",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        for var in &container.env {
+            let value_style = match var.value {
+                EnvValue::Plain(_) => Style::default(),
+                _ => Style::default().fg(Color::Magenta),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {}", pad_to_width(&var.name, 24)),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(var.value.describe(), value_style),
+            ]));
+        }
+        for source in &container.env_from {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {}", pad_to_width("(all keys from)", 24)),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(source.clone(), Style::default().fg(Color::Magenta)),
+            ]));
+        }
+    }
 
-    let table = Table::new(
-        rows,
-        [
-            Constraint::Percentage(25),
-            Constraint::Percentage(25),
-            Constraint::Percentage(35),
-            Constraint::Percentage(15),
-        ],
-    )
-    .header(header)
-    .block(
-        Block::default()
-            .borders(Borders::ALL)
-            .title("Clusters / Contexts")
-            .style(Style::default()),
-    );
+    lines
+}
 
-    f.render_widget(table, area);
+fn kubeconfig_lines(kubeconfig: &EffectiveKubeconfig) -> Vec<Line<'static>> {
+    let mut lines = vec![detail_section(
+        "Files (merged in order, the first to set a value wins)",
+    )];
+    for (path, exists) in &kubeconfig.sources {
+        if *exists {
+            lines.push(Line::from(format!("  {}", path.display())));
+        } else {
+            lines.push(Line::from(vec![
+                Span::raw(format!("  {}", path.display())),
+                Span::styled(" (missing, skipped)", Style::default().fg(Color::Red)),
+            ]));
+        }
+    }
+    lines.push(Line::from(""));
+    lines.push(detail_section("Merged"));
+    lines.extend(kubeconfig.yaml.lines().map(|l| Line::raw(l.to_string())));
+    lines
 }
 
-fn render_namespaces_view(f: &mut Frame, app: &App, area: Rect) {
-    let header_cells = ["NAMESPACE"]
+/// Config checksum and restartedAt annotations, one per line
+fn rollout_annotation_lines(annotations: &BTreeMap<String, String>) -> Vec<Line<'static>> {
+    annotations
         .iter()
-        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
+        .map(|(key, value)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("  {}", pad_to_width(key, 40)),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw(value.clone()),
+            ])
+        })
+        .collect()
+}
 
-    let header = Row::new(header_cells)
-        .style(Style::default())
-        .height(1)
-        .bottom_margin(1);
+fn service_detail_lines(detail: &ServiceDetail) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        detail_section("Service"),
+        detail_field("Type", detail.service_type.clone()),
+        detail_field("Cluster IP", detail.cluster_ip.clone()),
+        detail_field("Session Affinity", detail.session_affinity.clone()),
+        detail_field(
+            "External Traffic Policy",
+            detail
+                .external_traffic_policy
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+        Line::from(""),
+        detail_section("Selector"),
+    ];
 
-    let rows = app.namespaces.iter().enumerate().map(|(i, ns)| {
-        let mut name = ns.clone();
+    if detail.selector.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  <none> (endpoints are managed manually)",
+            Style::default().fg(Color::DarkGray),
+        )));
+    } else {
+        for (key, value) in &detail.selector {
+            lines.push(Line::from(format!("  {}={}", key, value)));
+        }
+    }
 
-        // Add indicator for current namespace
-        if ns == &app.current_namespace {
-            name = format!("▶ {}", ns);
+    lines.push(Line::from(""));
+    lines.push(detail_section("Ports"));
+    if detail.ports.is_empty() {
+        lines.push(Line::from("  <none>"));
+    } else {
+        for port in &detail.ports {
+            lines.push(Line::from(format!("  {}", port)));
         }
+    }
 
-        let cells = vec![Cell::from(name)];
+    lines.push(Line::from(""));
+    lines.push(detail_section("Endpoints"));
+    let endpoint_style = if detail.ready_endpoints == 0 {
+        Style::default().fg(Color::Red)
+    } else {
+        Style::default().fg(Color::Green)
+    };
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!("  {:<24}", "Ready"),
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::styled(detail.ready_endpoints.to_string(), endpoint_style),
+    ]));
+    lines.push(detail_field(
+        "Not Ready",
+        detail.not_ready_endpoints.to_string(),
+    ));
 
-        let style = if i == app.namespace_index {
-            Style::default()
-                .bg(Color::DarkGray)
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD)
-        } else if ns == &app.current_namespace {
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD)
-        } else {
-            Style::default()
-        };
+    lines
+}
 
-        Row::new(cells).style(style).height(1)
-    });
+/// Longest value preview in the ConfigMap detail, in terminal columns
+const CONFIGMAP_PREVIEW_WIDTH: usize = 60;
+
+fn configmap_detail_lines(detail: &ConfigMapDetail) -> Vec<Line<'static>> {
+    let key_width = detail
+        .data
+        .keys()
+        .chain(detail.binary_data.keys())
+        .map(|key| key.width())
+        .max()
+        .unwrap_or(0)
+        .min(40);
+
+    let mut lines = vec![detail_section("Data")];
+    if detail.data.is_empty() && detail.binary_data.is_empty() {
+        lines.push(Line::from("  <none>"));
+    }
+    for (key, value) in &detail.data {
+        // Only the first line is previewed, with a marker when anything was cut
+        let first_line = value.lines().next().unwrap_or("");
+        let mut preview = truncate_to_width(first_line, CONFIGMAP_PREVIEW_WIDTH);
+        if preview.len() < value.trim_end().len() {
+            preview.push('…');
+        }
+        let mut spans = vec![
+            Span::styled(
+                format!("  {}  ", pad_to_width(key, key_width)),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::raw(preview),
+        ];
+        let line_count = value.lines().count();
+        if line_count > 1 {
+            spans.push(Span::styled(
+                format!("  ({} lines)", line_count),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    for (key, size) in &detail.binary_data {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {}  ", pad_to_width(key, key_width)),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(
+                format!("<binary, {} bytes>", size),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+    lines
+}
 
-    let table = Table::new(rows, [Constraint::Percentage(100)])
-        .header(header)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("Namespaces")
-                .style(Style::default()),
+fn secret_detail_lines(detail: &SecretDetail, revealed: bool) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        detail_section("Secret"),
+        detail_field("Type", detail.type_.clone()),
+        Line::from(""),
+        detail_section("Data"),
+    ];
+    if detail.data.is_empty() {
+        lines.push(Line::from("  <none>"));
+    }
+    let key_width = detail
+        .data
+        .keys()
+        .map(|key| key.width())
+        .max()
+        .unwrap_or(0)
+        .min(40);
+    for (key, value) in &detail.data {
+        let key = Span::styled(
+            format!("  {}  ", pad_to_width(key, key_width)),
+            Style::default().fg(Color::Cyan),
         );
-
-    f.render_widget(table, area);
+        let dim = Style::default().fg(Color::DarkGray);
+        if !revealed {
+            lines.push(Line::from(vec![key, Span::styled("••••", dim)]));
+            continue;
+        }
+        match std::str::from_utf8(value) {
+            // Certificates and the like span lines; those go underneath the key
+            Ok(text) if text.trim_end().contains('\n') => {
+                lines.push(Line::from(key));
+                lines.extend(text.lines().map(|l| Line::raw(format!("    {}", l))));
+            }
+            Ok(text) => lines.push(Line::from(vec![key, Span::raw(text.to_string())])),
+            Err(_) => lines.push(Line::from(vec![
+                key,
+                Span::styled(format!("<{} bytes binary>", value.len()), dim),
+            ])),
+        }
+    }
+    lines
 }
 
 fn render_help_view(f: &mut Frame, _app: &App, area: Rect) {
@@ -420,7 +3147,19 @@ fn render_help_view(f: &mut Frame, _app: &App, area: Rect) {
         Line::from("  3 - Services View      │  List all services"),
         Line::from("  4 - Clusters View      │  List all contexts/clusters"),
         Line::from("  5/n - Namespaces View  │  List all namespaces"),
+        Line::from("  6 - Events View        │  Namespace events (⚠ badge counts recent warnings)"),
+        Line::from("  f - Follow Events      │  In Events, stream new events live at the top"),
+        Line::from("  7 - ConfigMaps View    │  Enter previews each key's value"),
+        Line::from("  8 - Secrets View       │  Enter lists the keys, x reveals the values"),
+        Line::from("  Ctrl+N - Quick Switch  │  Pick a namespace from any view"),
+        Line::from("  Ctrl+F - Global Search │  Find a resource by name in all namespaces"),
+        Line::from("  Ctrl+P - Ping Cluster  │  Show how fast the API server answers"),
+        Line::from("  : - Go to View         │  Type part of a view name (e.g. dep), Enter"),
+        Line::from("  / - Filter             │  Narrow pods, deployments or services by name; Esc clears"),
         Line::from("  ?/h - Help View        │  This help screen"),
+        Line::from(
+            "  v - Full Row           │  Show every column of the selected row, untruncated",
+        ),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Pod Operations:",
@@ -428,9 +3167,32 @@ fn render_help_view(f: &mut Frame, _app: &App, area: Rect) {
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         )]),
-        Line::from("  l - View Logs          │  Show last 100 lines of pod logs"),
-        Line::from("  e - Exec into Pod      │  Open interactive shell in pod"),
+        Line::from("  Enter - Describe       │  Conditions and scheduling constraints"),
+        Line::from(
+            "  y - YAML               │  Show the resource as YAML (pods, deployments, services)",
+        ),
+        Line::from("  K - kubectl describe   │  Full 'kubectl describe' output (also from a detail)"),
+        Line::from("  c - Copy Reference     │  Copy e.g. '-n ns pod/name' to the clipboard"),
+        Line::from("  Y - Copy Error         │  Copy the full text of the last error"),
+        Line::from("  = - Label/Annotate     │  key=value, key- removes, @key=value annotates"),
+        Line::from("  l - View Logs          │  Show the last lines of pod logs"),
+        Line::from("  L - Follow Logs        │  Open the logs already following at the bottom"),
+        Line::from("  A - Follow All         │  Follow every container's log merged, prefixed by container"),
+        Line::from("  p - Log Preview        │  Split the view to follow the selected pod's logs below"),
+        Line::from("  e - Exec into Pod      │  Open interactive shell in pod (pick the container)"),
+        Line::from(
+            "  t - Terminals          │  Return to open embedded terminals (Alt+←/→ switches)",
+        ),
         Line::from("  d - Delete Pod         │  Delete selected pod"),
+        Line::from("  C - Cordon Node        │  Make the pod's node unschedulable (y/n)"),
+        Line::from("  S - Status Display     │  Phase or kubectl-style status (CrashLoopBackOff, ...)"),
+        Line::from("  J - Clean Up Jobs      │  Delete the namespace's completed Jobs and their pods (y/n)"),
+        Line::from("  G - Group by Node      │  Toggle grouping pods under a header per node, with counts"),
+        Line::from("  P - Pin                │  Keep the pod on top and highlighted; flagged if it goes missing"),
+        Line::from("  F - Port Forward       │  8080:80, 80 or :80; ':pf' lists forwards, d stops one"),
+        Line::from("  N - Describe Node      │  Conditions, capacity, taints and versions of the pod's node"),
+        Line::from("  E - Node Shell         │  Root shell on the pod's node via a privileged debug pod (y/n)"),
+        Line::from("  D - Debug Container    │  Attach a busybox ephemeral container, for pods without a shell (y/n)"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Deployment Operations:",
@@ -438,8 +3200,20 @@ fn render_help_view(f: &mut Frame, _app: &App, area: Rect) {
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         )]),
-        Line::from("  s - Scale              │  Change replica count"),
-        Line::from("  d - Delete             │  Delete selected deployment"),
+        Line::from("  s - Scale              │  Change replica count (of all marked ones)"),
+        Line::from("  Space - Mark           │  Mark deployments to scale together"),
+        Line::from("  w - Watch rollout      │  Follow the rollout until it finishes"),
+        Line::from("  H - Rollout History    │  Revisions with change-cause; 'u' rolls back (y/n)"),
+        Line::from("  Enter - Describe       │  Containers and env vars; 'e' edits a plain value"),
+        Line::from("  d - Delete             │  Delete selected deployment (pick b/f/o for its pods, y)"),
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            "Service Operations:",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from("  Enter - Describe       │  Selector, session affinity and endpoints"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Context & Namespace:",
@@ -447,8 +3221,17 @@ fn render_help_view(f: &mut Frame, _app: &App, area: Rect) {
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         )]),
-        Line::from("  Enter - Switch         │  Switch to selected cluster/namespace"),
-        Line::from("  Current items marked with ▶ and highlighted"),
+        Line::from("  Enter - Switch         │  Switch to selected cluster"),
+        Line::from("  Enter - Overview       │  Namespace health summary; Enter again switches"),
+        Line::from(
+            "  i - Connection Info    │  Server, TLS and auth of the context (Clusters view)",
+        ),
+        Line::from("  e - Edit Kubeconfig    │  Open the kubeconfig in $EDITOR (Clusters view)"),
+        Line::from("  V - View Kubeconfig    │  Merged kubeconfig, credentials redacted (Clusters)"),
+        Line::from("  f - Favorite           │  Pin the selected context/namespace to the top"),
+        Line::from("  Alt+1-9 - Jump         │  Nth favorite context (Clusters) or namespace"),
+        Line::from("  s - System Namespaces  │  Hide/show kube-* namespaces (Namespaces view)"),
+        Line::from("  Current items marked with ▶ and highlighted, favorites with ★"),
         Line::from("  Note: If connection fails on startup, press 4 to switch context"),
         Line::from(""),
         Line::from(vec![Span::styled(
@@ -460,6 +3243,13 @@ fn render_help_view(f: &mut Frame, _app: &App, area: Rect) {
         Line::from("  ↑/k - Scroll Up        │  Scroll logs up one line"),
         Line::from("  ↓/j - Scroll Down      │  Scroll logs down one line"),
         Line::from("  f - Follow Mode        │  Toggle real-time log following"),
+        Line::from("  p - Previous Instance  │  Logs from before the last restart"),
+        Line::from("  g - Grep               │  Only lines containing a pattern, from a longer tail"),
+        Line::from("  i/x - Include/Exclude  │  Only lines matching / not matching a regex"),
+        Line::from("  G/End - Jump to Bottom │  Scroll to the end and resume following"),
+        Line::from("  w - Wrap               │  Toggle wrapping long lines"),
+        Line::from("  o - Open in Pager      │  Read the log in $PAGER or less, then come back"),
+        Line::from("  Tab - Next Container   │  Cycle containers (red dot: crashlooping)"),
         Line::from("  Esc - Back             │  Return to pods view"),
         Line::from(""),
         Line::from(vec![Span::styled(
@@ -469,10 +3259,16 @@ fn render_help_view(f: &mut Frame, _app: &App, area: Rect) {
                 .add_modifier(Modifier::BOLD),
         )]),
         Line::from("  r - Refresh            │  Reload current view data"),
+        Line::from("  a - Activity           │  Show actions taken in this session"),
+        Line::from("  T - Theme              │  Cycle default/light/high-contrast/monochrome colors"),
         Line::from("  ↑/k - Move Up          │  Navigate selection up (or scroll in logs)"),
         Line::from("  ↓/j - Move Down        │  Navigate selection down (or scroll in logs)"),
         Line::from("  Esc - Back/Close       │  Return to previous view"),
         Line::from("  q - Quit               │  Exit application"),
+        Line::from(
+            "  Q - Quit Here          │  Exit and print the context/namespace and a kubectl prefix",
+        ),
+        Line::from("  Ctrl+C - Force Quit    │  Exit from any view or prompt (interrupts in a terminal)"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Tips:",
@@ -480,7 +3276,7 @@ fn render_help_view(f: &mut Frame, _app: &App, area: Rect) {
                 .fg(Color::Green)
                 .add_modifier(Modifier::BOLD),
         )]),
-        Line::from("  • Use ←/→ arrows or number keys (1-5) to switch between tabs"),
+        Line::from("  • Use ←/→ arrows or number keys (1-8) to switch between tabs"),
         Line::from("  • Header shows current context and namespace"),
         Line::from("  • Active tab is highlighted in the tab bar"),
         Line::from("  • Status messages appear in green (success) or red (error)"),
@@ -506,22 +3302,69 @@ fn render_help_view(f: &mut Frame, _app: &App, area: Rect) {
     f.render_widget(paragraph, area);
 }
 
-fn render_terminal_view(f: &mut Frame, app: &App, area: Rect) {
-    let title = if let Some(pod_name) = &app.terminal_pod_name {
-        format!(
-            "Terminal - Pod: {} | Ruby/Rails: 'irb' or 'bin/rails c' | PgUp/PgDn: Scroll | Esc/Ctrl+D: Exit",
-            pod_name
-        )
+fn render_terminal_view(f: &mut Frame, app: &mut App, area: Rect) {
+    let tabs = app.terminal_tabs();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(area);
+    // The shell gets exactly the space inside the borders
+    app.resize_terminals(
+        chunks[1].height.saturating_sub(2),
+        chunks[1].width.saturating_sub(2),
+    );
+
+    // Tab bar of open terminals
+    let mut tab_spans = vec![];
+    for (i, (pod_name, alive)) in tabs.iter().enumerate() {
+        let color = if *alive { Color::Green } else { Color::Red };
+        let style = if i == app.active_terminal {
+            Style::default()
+                .fg(Color::Black)
+                .bg(color)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(color)
+        };
+        let label = if *alive {
+            format!(" {} {} ", i + 1, pod_name)
+        } else {
+            format!(" {} {} (disconnected) ", i + 1, pod_name)
+        };
+        tab_spans.push(Span::styled(label, style));
+        tab_spans.push(Span::raw(" "));
+    }
+    tab_spans.push(Span::styled(
+        "Alt+←/→ or Alt+1-9: Switch",
+        Style::default().fg(Color::DarkGray),
+    ));
+    f.render_widget(Paragraph::new(Line::from(tab_spans)), chunks[0]);
+    let area = chunks[1];
+
+    let title = if let Some((pod_name, alive)) = tabs.get(app.active_terminal) {
+        if *alive {
+            format!(
+                "Terminal - Pod: {} | Ruby/Rails: 'irb' or 'bin/rails c' | PgUp/PgDn: Scroll | Esc: Back | Ctrl+D: Close | Ctrl+C: Interrupt",
+                pod_name
+            )
+        } else {
+            format!(
+                "Terminal - Pod: {} | Session ended | Ctrl+R: Reconnect | Esc: Back | Ctrl+D: Close",
+                pod_name
+            )
+        }
     } else {
         "Terminal (Press Esc or Ctrl+D to exit)".to_string()
     };
 
-    let content = if let Some(lines) = app.get_terminal_screen() {
+    let content: Vec<Line> = if let Some(lines) = app.get_terminal_screen() {
         if lines.is_empty() {
-            "Connecting to pod shell...\n\nTip: Common commands for Ruby/Rails:\n  - irb                  (Interactive Ruby)\n  - bin/rails console    (Rails console)\n  - bundle exec rails c  (Rails console via bundler)\n  - bin/console          (Custom console script)\n\nWaiting for response...".to_string()
+            "Connecting to pod shell...\n\nTip: Common commands for Ruby/Rails:\n  - irb                  (Interactive Ruby)\n  - bin/rails console    (Rails console)\n  - bundle exec rails c  (Rails console via bundler)\n  - bin/console          (Custom console script)\n\nWaiting for response...".lines().map(Line::raw).collect()
         } else {
             // Show the last N lines that fit in the viewport
             let visible_height = area.height.saturating_sub(2) as usize; // -2 for borders
+            let visible_width = area.width.saturating_sub(2) as usize;
             let total_lines = lines.len();
 
             // Calculate scroll position
@@ -539,20 +3382,23 @@ fn render_terminal_view(f: &mut Frame, app: &App, area: Rect) {
 
             let end = (start + visible_height).min(total_lines);
 
-            lines[start..end].join("\n")
+            // Each grid row stays on one screen row: clip by display width
+            // instead of wrapping, so wide characters can't shift the rows below
+            lines[start..end]
+                .iter()
+                .map(|line| Line::raw(truncate_to_width(line, visible_width)))
+                .collect()
         }
     } else {
-        "Connecting to pod...".to_string()
+        vec![Line::raw("Connecting to pod...")]
     };
 
-    let terminal = Paragraph::new(content)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(title)
-                .style(Style::default().fg(Color::Green)),
-        )
-        .wrap(Wrap { trim: false });
+    let terminal = Paragraph::new(content).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(Style::default().fg(Color::Green)),
+    );
 
     f.render_widget(terminal, area);
 }
@@ -563,9 +3409,24 @@ fn render_footer(f: &mut Frame, app: &App, area: Rect) {
         .constraints([Constraint::Length(1), Constraint::Length(2)])
         .split(area);
 
-    // Status/Error message
-    if let Some(error) = &app.error_message {
-        let error_msg = Paragraph::new(error.clone()).style(Style::default().fg(Color::Red));
+    // Status/Error message; a pending countdown trumps both
+    if let Some(countdown) = &app.countdown {
+        let pending = Paragraph::new(format!(
+            "⏳ {} in {}s — press Esc to abort",
+            countdown.action.describe(),
+            countdown.seconds_left()
+        ))
+        .style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+        f.render_widget(pending, chunks[0]);
+    } else if let Some(error) = &app.error_message {
+        let error_msg = Paragraph::new(Line::from(vec![
+            Span::styled(error.clone(), Style::default().fg(Color::Red)),
+            Span::styled(" (Y to copy)", Style::default().fg(Color::DarkGray)),
+        ]));
         f.render_widget(error_msg, chunks[0]);
     } else if !app.status_message.is_empty() {
         let status_msg =
@@ -598,11 +3459,28 @@ fn render_footer(f: &mut Frame, app: &App, area: Rect) {
             f.render_widget(help, chunks[1]);
         }
         InputMode::Scale => {
-            let input = Paragraph::new(app.input_buffer.clone())
+            // Spell out the transition so a mistyped count is caught before Enter
+            let style = if app.input_buffer == "0" {
+                Style::default().fg(Color::Red)
+            } else {
+                Style::default().fg(Color::Yellow)
+            };
+            let input = Paragraph::new(app.scale_preview().unwrap_or_default())
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Enter number of replicas (Enter to apply, Esc to cancel)"),
+                )
+                .style(style);
+
+            f.render_widget(input, chunks[1]);
+        }
+        InputMode::Filter => {
+            let input = Paragraph::new(format!("/{}▏", app.input_buffer))
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .title("Enter number of replicas (Esc to cancel)"),
+                        .title("Filter by name (Enter to keep, Esc to clear)"),
                 )
                 .style(Style::default().fg(Color::Yellow));
 
@@ -637,5 +3515,26 @@ fn render_footer(f: &mut Frame, app: &App, area: Rect) {
 
             f.render_widget(menu, chunks[1]);
         }
+        // Rendered as an overlay on top of the whole screen
+        InputMode::NamespacePicker
+        | InputMode::GlobalSearch
+        | InputMode::Rollout
+        | InputMode::EnvEdit
+        | InputMode::RowPopup
+        | InputMode::ConfirmContextSwitch
+        | InputMode::ViewPicker
+        | InputMode::MetadataEdit
+        | InputMode::ContainerPicker
+        | InputMode::LogGrep
+        | InputMode::LogInclude
+        | InputMode::LogExclude
+        | InputMode::PortForward
+        | InputMode::ConfirmCordon
+        | InputMode::ConfirmJobCleanup
+        | InputMode::ConfirmScaleToZero
+        | InputMode::ConfirmDelete
+        | InputMode::ConfirmRollback
+        | InputMode::ConfirmNodeShell
+        | InputMode::ConfirmDebugContainer => {}
     }
 }
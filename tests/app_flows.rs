@@ -0,0 +1,1881 @@
+mod common;
+
+use common::{app_with, deployment, event, pod, press, type_text, MockKubeApi};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use qui::app::{
+    parse_port_forward, App, ContainerAction, ContextSwitchStep, Detail, ExternalCommand,
+    InputMode, View, DEBUG_CONTAINER_START_TIMEOUT, USAGE_SAMPLES,
+};
+use qui::config::{PodStatusDisplay, SortKey, ThemePreset};
+use qui::events::InputEvent;
+use qui::kube_client::{
+    ContainerHealth, ContainerSummary, ContainerUsage, ContextInfo, DeletePropagation, KubeApi,
+    PodSummary, PodWatcher, RolloutState, RolloutStatus, RolloutWatcher, TerminationInfo,
+};
+use qui::{Config, PodInfo};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn selection_moves_and_stops_at_the_ends() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0"), pod("web-1"), pod("web-2")],
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    assert_eq!(app.pods.len(), 3);
+    press(&mut app, KeyCode::Up).await;
+    assert_eq!(app.pod_index, 0);
+
+    for _ in 0..5 {
+        press(&mut app, KeyCode::Down).await;
+    }
+    assert_eq!(app.pod_index, 2);
+
+    press(&mut app, KeyCode::Char('k')).await;
+    assert_eq!(app.pod_index, 1);
+}
+
+#[tokio::test]
+async fn number_keys_switch_views() {
+    let api = MockKubeApi {
+        deployments: vec![deployment("api")],
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    press(&mut app, KeyCode::Char('2')).await;
+    assert_eq!(app.current_view, View::Deployments);
+    assert_eq!(app.deployments.len(), 1);
+
+    press(&mut app, KeyCode::Char('5')).await;
+    assert_eq!(app.current_view, View::Namespaces);
+}
+
+#[tokio::test]
+async fn scaling_sends_the_typed_replica_count() {
+    let api = MockKubeApi {
+        deployments: vec![deployment("api"), deployment("worker")],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Char('2')).await;
+    press(&mut app, KeyCode::Down).await;
+    press(&mut app, KeyCode::Char('s')).await;
+    assert_eq!(app.input_mode, InputMode::Scale);
+    assert_eq!(app.scale_preview().unwrap(), "worker: 1 → ?");
+
+    type_text(&mut app, "3").await;
+    assert_eq!(app.scale_preview().unwrap(), "worker: 1 → 3 (+2)");
+    press(&mut app, KeyCode::Enter).await;
+
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(api.calls(), vec!["scale deployment default/worker 3"]);
+}
+
+#[tokio::test]
+async fn scale_to_zero_waits_out_a_cancellable_countdown() {
+    let api = MockKubeApi {
+        deployments: vec![deployment("worker")],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Char('2')).await;
+    press(&mut app, KeyCode::Char('s')).await;
+    type_text(&mut app, "0").await;
+    assert_eq!(
+        app.scale_preview().unwrap(),
+        "worker: 1 → 0 (stops every pod)"
+    );
+    press(&mut app, KeyCode::Enter).await;
+
+    assert!(app.countdown.is_some());
+    app.run_due_action().await.unwrap();
+    assert!(api.calls().is_empty());
+
+    press(&mut app, KeyCode::Esc).await;
+    assert!(app.countdown.is_none());
+
+    press(&mut app, KeyCode::Char('s')).await;
+    type_text(&mut app, "0").await;
+    press(&mut app, KeyCode::Enter).await;
+    app.countdown.as_mut().unwrap().deadline = Instant::now();
+    app.run_due_action().await.unwrap();
+
+    assert!(app.countdown.is_none());
+    assert_eq!(api.calls(), vec!["scale deployment default/worker 0"]);
+}
+
+#[tokio::test]
+async fn marked_deployments_scale_together_after_confirming_zero() {
+    let api = MockKubeApi {
+        deployments: vec![deployment("api"), deployment("db"), deployment("worker")],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Char('2')).await;
+    press(&mut app, KeyCode::Char(' ')).await;
+    press(&mut app, KeyCode::Down).await;
+    press(&mut app, KeyCode::Char(' ')).await;
+    assert_eq!(app.scale_targets(), vec!["api", "worker"]);
+
+    press(&mut app, KeyCode::Char('s')).await;
+    type_text(&mut app, "0").await;
+    assert_eq!(
+        app.scale_preview().unwrap(),
+        "2 deployments (api, worker) → 0"
+    );
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(app.input_mode, InputMode::ConfirmScaleToZero);
+    assert_eq!(app.scale_to_zero_targets, vec!["api", "worker"]);
+    assert!(api.calls().is_empty());
+
+    // Confirming still leaves the countdown to back out of
+    press(&mut app, KeyCode::Char('y')).await;
+    assert_eq!(
+        app.countdown.as_ref().unwrap().action.describe(),
+        "Scaling 2 deployments (api, worker) to 0 replicas"
+    );
+    press(&mut app, KeyCode::Esc).await;
+    assert!(app.countdown.is_none());
+    assert!(api.calls().is_empty());
+
+    press(&mut app, KeyCode::Char('s')).await;
+    type_text(&mut app, "0").await;
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Char('y')).await;
+    app.countdown.as_mut().unwrap().deadline = Instant::now();
+    app.run_due_action().await.unwrap();
+    assert_eq!(
+        api.calls(),
+        vec![
+            "scale deployment default/api 0",
+            "scale deployment default/worker 0"
+        ]
+    );
+    assert_eq!(
+        app.status_message,
+        "Scaled 2 of 2 deployments to 0 replicas"
+    );
+
+    // The marks stay, so the same set comes back up without a confirmation
+    press(&mut app, KeyCode::Char('s')).await;
+    type_text(&mut app, "2").await;
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(api.calls().len(), 4);
+    assert_eq!(api.calls()[3], "scale deployment default/worker 2");
+}
+
+#[tokio::test]
+async fn repeated_connection_failures_raise_a_banner_and_can_exit() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+    app.config.connection_loss.exit_after_secs = Some(0);
+
+    *api.unreachable.lock().unwrap() = true;
+    app.check_connection().await;
+    app.check_connection().await;
+    assert_eq!(app.consecutive_failures, 2);
+    assert!(app.connection_lost_at.is_none());
+    assert_eq!(app.connection_exit_reason(), None);
+
+    app.check_connection().await;
+    assert!(app.connection_lost_at.is_some());
+    assert!(app
+        .connection_exit_reason()
+        .unwrap()
+        .contains("3 requests in a row failed"));
+
+    // One answer is enough to trust the data again
+    *api.unreachable.lock().unwrap() = false;
+    app.check_connection().await;
+    assert_eq!(app.consecutive_failures, 0);
+    assert!(app.connection_lost_at.is_none());
+    assert!(app.status_message.contains("restored"));
+}
+
+#[tokio::test]
+async fn delete_targets_the_selected_pod() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0"), pod("web-1")],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Down).await;
+    press(&mut app, KeyCode::Char('d')).await;
+
+    assert_eq!(api.calls(), vec!["delete pod default/web-1"]);
+}
+
+#[tokio::test]
+async fn a_selection_past_a_shrunk_list_is_pulled_back_before_acting() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0"), pod("web-1"), pod("web-2")],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Down).await;
+    press(&mut app, KeyCode::Down).await;
+    // The list shrank in the background without the selection following
+    app.pods.truncate(1);
+
+    press(&mut app, KeyCode::Char('d')).await;
+    assert!(api.calls().is_empty());
+    assert_eq!(app.pod_index, 0);
+    assert!(app.status_message.contains("out of range"));
+
+    // Now that the row is visibly selected, the key acts on it
+    press(&mut app, KeyCode::Char('d')).await;
+    assert_eq!(api.calls(), vec!["delete pod default/web-0"]);
+}
+
+#[tokio::test]
+async fn quit_key_stops_the_app() {
+    let mut app = app_with(MockKubeApi::default()).await;
+
+    assert!(!press(&mut app, KeyCode::Char('q')).await);
+}
+
+#[tokio::test]
+async fn ctrl_c_quits_except_in_the_embedded_terminal() {
+    let mut app = app_with(MockKubeApi::default()).await;
+    let ctrl_c = InputEvent::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+
+    // There it belongs to the shell, to interrupt the running command
+    app.current_view = View::Terminal;
+    assert!(app.handle_event(ctrl_c).await.unwrap());
+    assert_eq!(app.current_view, View::Terminal);
+
+    app.current_view = View::Pods;
+    assert!(!app.handle_event(ctrl_c).await.unwrap());
+}
+
+#[tokio::test]
+async fn log_buffer_keeps_only_the_newest_lines() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        logs: (1..=10).map(|i| format!("line {}\n", i)).collect(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+    app.config.logs.max_lines = 3;
+
+    press(&mut app, KeyCode::Char('l')).await;
+
+    assert_eq!(app.current_view, View::Logs);
+    assert_eq!(app.logs, "line 8\nline 9\nline 10");
+}
+
+#[tokio::test]
+async fn shift_l_opens_logs_already_following() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        logs: (1..=10).map(|i| format!("line {}\n", i)).collect(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    press(&mut app, KeyCode::Char('L')).await;
+
+    assert_eq!(app.current_view, View::Logs);
+    assert!(app.logs_follow);
+    assert_eq!(app.logs_scroll, 9);
+}
+
+#[tokio::test]
+async fn follow_mode_flags_a_stalled_refresh() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        logs: "hello\n".to_string(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+    press(&mut app, KeyCode::Char('L')).await;
+    assert_eq!(app.logs_stalled_for(), None);
+
+    app.logs_fetched_at = Some(Instant::now() - Duration::from_secs(10));
+    assert!(app.logs_stalled_for().unwrap() >= Duration::from_secs(10));
+
+    app.refresh_logs().await.unwrap();
+    assert_eq!(app.logs_stalled_for(), None);
+}
+
+#[tokio::test]
+async fn scrolling_up_pauses_follow_until_jumping_back_down() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        logs: (1..=10).map(|i| format!("line {}\n", i)).collect(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+    press(&mut app, KeyCode::Char('L')).await;
+
+    press(&mut app, KeyCode::Up).await;
+    press(&mut app, KeyCode::Up).await;
+    assert!(app.logs_follow);
+    assert!(app.logs_paused);
+    // Refreshes keep coming in without dragging the view back down
+    app.refresh_logs().await.unwrap();
+    assert_eq!(app.logs_scroll, 7);
+
+    press(&mut app, KeyCode::Char('G')).await;
+    assert!(!app.logs_paused);
+    assert_eq!(app.logs_scroll, 9);
+
+    // Also starts following when it was off
+    press(&mut app, KeyCode::Char('f')).await;
+    assert!(!app.logs_follow);
+    press(&mut app, KeyCode::End).await;
+    assert!(app.logs_follow);
+}
+
+#[tokio::test]
+async fn follow_mode_marks_new_lines_even_when_lines_repeat() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        logs: "ping\nwork\nping\ndone\nping\n".to_string(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+    press(&mut app, KeyCode::Char('L')).await;
+
+    // The window slid by one line and two arrived, the last a repeat of the old last
+    app.logs = "start\nping\nwork\nping\n".to_string();
+    app.refresh_logs().await.unwrap();
+    assert_eq!(app.logs_new_from, Some(3));
+
+    // Nothing new since
+    app.refresh_logs().await.unwrap();
+    assert_eq!(app.logs_new_from, None);
+}
+
+#[tokio::test]
+async fn global_search_jumps_to_the_selected_match() {
+    let api = MockKubeApi {
+        pods: vec![pod("api-0"), pod("web-0"), pod("web-1")],
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    app.handle_event(InputEvent::Key(KeyEvent::new(
+        KeyCode::Char('f'),
+        KeyModifiers::CONTROL,
+    )))
+    .await
+    .unwrap();
+    assert_eq!(app.input_mode, InputMode::GlobalSearch);
+
+    type_text(&mut app, "web").await;
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(app.search_results.len(), 2);
+
+    press(&mut app, KeyCode::Down).await;
+    press(&mut app, KeyCode::Enter).await;
+
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(app.current_view, View::Pods);
+    assert_eq!(app.pods[app.pod_index].name, "web-1");
+}
+
+#[tokio::test]
+async fn log_timestamps_are_shown_in_the_configured_zone() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        logs: "2024-01-02T03:04:05.123456789Z started\nnot a timestamp\n".to_string(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+    app.config.logs.timestamps = true;
+
+    press(&mut app, KeyCode::Char('l')).await;
+
+    assert_eq!(app.logs, "2024-01-02 03:04:05.123 started\nnot a timestamp");
+}
+
+#[tokio::test]
+async fn disconnected_app_skips_cluster_views() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+    app.connected = false;
+    app.pods.clear();
+
+    press(&mut app, KeyCode::Char('1')).await;
+
+    assert_eq!(app.current_view, View::Pods);
+    assert!(app.pods.is_empty());
+    assert!(app.error_message.is_none());
+}
+
+#[tokio::test]
+async fn rollout_watch_shows_the_final_status() {
+    let api = MockKubeApi {
+        deployments: vec![deployment("api")],
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    press(&mut app, KeyCode::Char('2')).await;
+    press(&mut app, KeyCode::Char('w')).await;
+    assert_eq!(app.input_mode, InputMode::Rollout);
+
+    app.try_update_rollout();
+    let status = app.rollout_status.as_ref().unwrap();
+    assert_eq!(status.state, RolloutState::Complete);
+    assert!(app.rollout_watcher.is_none());
+
+    press(&mut app, KeyCode::Esc).await;
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert!(app.rollout_status.is_none());
+}
+
+#[tokio::test]
+async fn a_rollout_watch_that_stops_early_says_so() {
+    let mut app = app_with(MockKubeApi::default()).await;
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    tx.send(RolloutStatus {
+        state: RolloutState::Progressing,
+        message: "Waiting for deployment \"api\" rollout to finish".to_string(),
+        desired: 3,
+        updated: 1,
+        available: 1,
+    })
+    .unwrap();
+    app.rollout_watcher = Some(RolloutWatcher { rx });
+
+    app.try_update_rollout();
+    assert_eq!(
+        app.rollout_status.as_ref().unwrap().state,
+        RolloutState::Progressing
+    );
+
+    // The watch task ended without a final status
+    drop(tx);
+    app.try_update_rollout();
+    let status = app.rollout_status.as_ref().unwrap();
+    assert_eq!(status.state, RolloutState::WatchStopped);
+    assert_eq!(status.updated, 1);
+    assert!(status.message.starts_with("Stopped watching the rollout"));
+    assert!(app.rollout_watcher.is_none());
+}
+
+#[tokio::test]
+async fn favorite_namespaces_are_listed_first_and_jumpable() {
+    let mut config = Config::default();
+    config.favorites.namespaces = vec!["staging".to_string()];
+    let mut app = App::with_client(
+        Box::new(MockKubeApi::default()),
+        config,
+        vec![],
+        "test".to_string(),
+        vec!["default".to_string(), "staging".to_string()],
+    );
+
+    assert_eq!(app.namespaces, vec!["staging", "default"]);
+
+    app.handle_event(InputEvent::Key(KeyEvent::new(
+        KeyCode::Char('1'),
+        KeyModifiers::ALT,
+    )))
+    .await
+    .unwrap();
+
+    assert_eq!(app.current_namespace, "staging");
+    assert_eq!(app.current_view, View::Pods);
+}
+
+#[tokio::test]
+async fn namespace_overview_comes_before_switching() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0"), pod("web-1")],
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    press(&mut app, KeyCode::Char('5')).await;
+    press(&mut app, KeyCode::Down).await;
+    press(&mut app, KeyCode::Enter).await;
+
+    assert_eq!(app.current_view, View::Detail);
+    assert_eq!(app.current_namespace, "default");
+    match &app.detail {
+        Some(Detail::Namespace(summary)) => {
+            assert_eq!(summary.namespace, "staging");
+            assert_eq!(summary.pod_statuses.as_ref().unwrap()["Running"], 2);
+        }
+        other => panic!("expected a namespace overview, got {:?}", other),
+    }
+
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(app.current_view, View::Pods);
+    assert_eq!(app.current_namespace, "staging");
+}
+
+#[tokio::test]
+async fn only_plain_env_vars_can_be_edited() {
+    let api = MockKubeApi {
+        deployments: vec![deployment("api")],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Char('2')).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(matches!(app.detail, Some(Detail::Deployment(_))));
+
+    press(&mut app, KeyCode::Char('e')).await;
+    type_text(&mut app, "DB_PASSWORD=hunter2").await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(app.error_message.is_some());
+    assert!(api.calls().is_empty());
+
+    press(&mut app, KeyCode::Char('e')).await;
+    type_text(&mut app, "LOG_LEVEL=debug").await;
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(
+        api.calls(),
+        vec!["set env deployment default/api app LOG_LEVEL=debug"]
+    );
+}
+
+#[tokio::test]
+async fn shift_k_falls_back_to_kubectl_describe() {
+    let api = MockKubeApi {
+        deployments: vec![deployment("api")],
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    press(&mut app, KeyCode::Char('2')).await;
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Char('K')).await;
+    match &app.detail {
+        Some(Detail::Describe(output)) => {
+            assert_eq!(output.kind, "deployment");
+            assert!(output.text.contains("Name:         api"));
+        }
+        other => panic!("expected kubectl describe output, got {:?}", other),
+    }
+
+    // Back to the list, not to the native detail it was opened from
+    press(&mut app, KeyCode::Esc).await;
+    assert_eq!(app.current_view, View::Deployments);
+}
+
+#[tokio::test]
+async fn configmaps_view_lists_and_opens_their_data() {
+    let api = MockKubeApi {
+        configmaps: BTreeMap::from([
+            (
+                "app-config".to_string(),
+                BTreeMap::from([("LOG_LEVEL".to_string(), "debug".to_string())]),
+            ),
+            (
+                "nginx".to_string(),
+                BTreeMap::from([
+                    ("nginx.conf".to_string(), "server {\n}\n".to_string()),
+                    ("mime.types".to_string(), "types {}".to_string()),
+                ]),
+            ),
+        ]),
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Char('7')).await;
+    assert_eq!(app.current_view, View::ConfigMaps);
+    assert!(api.lists().contains(&"list configmaps default".to_string()));
+    assert_eq!(app.configmaps.len(), 2);
+
+    press(&mut app, KeyCode::Char('j')).await;
+    press(&mut app, KeyCode::Enter).await;
+    match &app.detail {
+        Some(Detail::ConfigMap(detail)) => {
+            assert_eq!(detail.name, "nginx");
+            assert_eq!(detail.data.len(), 2);
+        }
+        other => panic!("expected the configmap's data, got {:?}", other),
+    }
+
+    press(&mut app, KeyCode::Esc).await;
+    assert_eq!(app.current_view, View::ConfigMaps);
+}
+
+#[tokio::test]
+async fn pod_detail_keeps_a_usage_history_when_metrics_are_available() {
+    let mut api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        pod_metrics: Some(vec![ContainerUsage {
+            container: "web".to_string(),
+            cpu_millis: 120,
+            memory_bytes: 64 * 1024 * 1024,
+        }]),
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Enter).await;
+    assert!(matches!(app.detail, Some(Detail::Pod(_))));
+    for _ in 0..USAGE_SAMPLES + 5 {
+        app.sample_pod_usage().await;
+    }
+    let history = app.pod_usage.as_ref().expect("usage is sampled");
+    assert_eq!(history.pod, "web-0");
+    assert_eq!(history.containers[0].cpu_millis.len(), USAGE_SAMPLES);
+    assert_eq!(history.containers[0].cpu_millis.back(), Some(&120));
+
+    // Without metrics-server there's nothing to draw
+    api.pod_metrics = None;
+    let mut app = app_with(api).await;
+    press(&mut app, KeyCode::Enter).await;
+    app.sample_pod_usage().await;
+    assert!(app.pod_usage.is_none());
+}
+
+#[tokio::test]
+async fn secret_values_stay_masked_until_revealed() {
+    let api = MockKubeApi {
+        secrets: BTreeMap::from([(
+            "db".to_string(),
+            BTreeMap::from([("password".to_string(), b"hunter2".to_vec())]),
+        )]),
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Char('8')).await;
+    assert_eq!(app.current_view, View::Secrets);
+    assert_eq!(app.secrets[0].key_count, 1);
+
+    press(&mut app, KeyCode::Enter).await;
+    assert!(matches!(&app.detail, Some(Detail::Secret(detail)) if detail.name == "db"));
+    assert!(!app.secret_revealed);
+    press(&mut app, KeyCode::Char('x')).await;
+    assert!(app.secret_revealed);
+
+    // Opening it again starts masked
+    press(&mut app, KeyCode::Esc).await;
+    assert_eq!(app.current_view, View::Secrets);
+    assert!(!app.secret_revealed);
+    press(&mut app, KeyCode::Enter).await;
+    assert!(!app.secret_revealed);
+}
+
+#[tokio::test]
+async fn port_forwards_start_from_a_pod_and_stop_from_their_view() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Char('F')).await;
+    assert_eq!(app.input_mode, InputMode::PortForward);
+    type_text(&mut app, ":8080").await;
+    press(&mut app, KeyCode::Enter).await;
+
+    assert_eq!(api.calls(), vec!["port-forward default/web-0 0:8080"]);
+    let local_addr = app.port_forwards[0].local_addr;
+    assert!(app.status_message.contains(&local_addr.to_string()));
+
+    type_text(&mut app, ":pf").await;
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(app.current_view, View::PortForwards);
+    press(&mut app, KeyCode::Char('d')).await;
+    assert!(app.port_forwards.is_empty());
+
+    // The aborted task drops its listener once the runtime gets to it
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(std::net::TcpListener::bind(local_addr).is_ok());
+}
+
+#[test]
+fn port_forward_ports_follow_kubectl_syntax() {
+    assert_eq!(parse_port_forward("80"), Some((80, 80)));
+    assert_eq!(parse_port_forward("8080:80"), Some((8080, 80)));
+    assert_eq!(parse_port_forward(":80"), Some((0, 80)));
+    assert_eq!(parse_port_forward("8080:"), None);
+    assert_eq!(parse_port_forward("0"), None);
+    assert_eq!(parse_port_forward("70000"), None);
+}
+
+#[tokio::test]
+async fn full_row_popup_opens_and_closes() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    press(&mut app, KeyCode::Char('v')).await;
+    assert_eq!(app.input_mode, InputMode::RowPopup);
+
+    press(&mut app, KeyCode::Esc).await;
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(app.current_view, View::Pods);
+}
+
+#[tokio::test]
+async fn theme_presets_start_from_the_config_and_cycle_with_t() {
+    let config: Config = serde_yaml::from_str("theme: high-contrast\n").unwrap();
+    let mut app = App::with_client(
+        Box::new(MockKubeApi::default()),
+        config,
+        vec![],
+        "test".to_string(),
+        vec!["default".to_string()],
+    );
+    assert_eq!(app.theme, ThemePreset::HighContrast);
+
+    press(&mut app, KeyCode::Char('T')).await;
+    assert_eq!(app.theme, ThemePreset::Monochrome);
+    assert_eq!(app.status_message, "Theme: monochrome (T for the next one)");
+    press(&mut app, KeyCode::Char('T')).await;
+    assert_eq!(app.theme, ThemePreset::Default);
+    press(&mut app, KeyCode::Char('T')).await;
+    assert_eq!(app.theme, ThemePreset::Light);
+}
+
+#[tokio::test]
+async fn slash_filters_the_list_by_name_and_moves_over_the_matches() {
+    let api = MockKubeApi {
+        pods: vec![pod("api-0"), pod("web-0"), pod("api-1"), pod("web-1")],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Char('/')).await;
+    assert_eq!(app.input_mode, InputMode::Filter);
+    // The list narrows while typing, and the cursor lands on the first match
+    type_text(&mut app, "WEB").await;
+    assert_eq!(app.list_filter(View::Pods), Some("WEB"));
+    assert_eq!(app.selected_pod().unwrap().name, "web-0");
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(app.input_mode, InputMode::Normal);
+
+    // Navigation skips the hidden rows and stops at the last match
+    press(&mut app, KeyCode::Down).await;
+    assert_eq!(app.selected_pod().unwrap().name, "web-1");
+    press(&mut app, KeyCode::Down).await;
+    assert_eq!(app.selected_pod().unwrap().name, "web-1");
+    press(&mut app, KeyCode::Up).await;
+    assert_eq!(app.selected_pod().unwrap().name, "web-0");
+    press(&mut app, KeyCode::Char('d')).await;
+    assert_eq!(api.calls(), vec!["delete pod default/web-0"]);
+    assert_eq!(app.pods.len(), 4);
+
+    // Each list keeps its own filter
+    press(&mut app, KeyCode::Char('2')).await;
+    assert_eq!(app.list_filter(View::Deployments), None);
+    press(&mut app, KeyCode::Char('1')).await;
+    assert_eq!(app.list_filter(View::Pods), Some("WEB"));
+
+    // Reopening starts from the current filter; Esc clears it
+    press(&mut app, KeyCode::Char('/')).await;
+    assert_eq!(app.input_buffer, "WEB");
+    press(&mut app, KeyCode::Esc).await;
+    assert_eq!(app.list_filter(View::Pods), None);
+    press(&mut app, KeyCode::Up).await;
+    assert_eq!(app.selected_pod().unwrap().name, "api-0");
+}
+
+#[tokio::test]
+async fn kubectl_backed_actions_are_refused_with_a_token_file() {
+    let config: Config = serde_yaml::from_str("token_file: /tmp/ci-token\n").unwrap();
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        ..Default::default()
+    };
+    let mut app = App::with_client(
+        Box::new(api),
+        config,
+        vec![],
+        "test".to_string(),
+        vec!["default".to_string()],
+    );
+    press(&mut app, KeyCode::Char('1')).await;
+
+    // kubectl would authenticate as the kubeconfig user, not the token's identity
+    for key in ['e', 'K', 'E', 'D'] {
+        app.error_message = None;
+        press(&mut app, KeyCode::Char(key)).await;
+        assert_eq!(app.input_mode, InputMode::Normal, "{}", key);
+        assert!(app.current_view != View::Detail, "{}", key);
+        assert!(
+            app.error_message
+                .as_deref()
+                .is_some_and(|e| e.contains("/tmp/ci-token")),
+            "{}",
+            key
+        );
+    }
+}
+
+#[tokio::test]
+async fn configured_sort_orders_pods_on_load() {
+    let config: Config = serde_yaml::from_str("pods:\n  sort: \"status,name\"\n").unwrap();
+    let with_status = |name: &str, status: &str| PodInfo {
+        status: status.to_string(),
+        ..pod(name)
+    };
+    let api = MockKubeApi {
+        pods: vec![
+            with_status("a-0", "Running"),
+            with_status("m-0", "Running"),
+            with_status("z-0", "Pending"),
+        ],
+        ..Default::default()
+    };
+    let mut app = App::with_client(
+        Box::new(api),
+        config,
+        vec![],
+        "test".to_string(),
+        vec!["default".to_string()],
+    );
+
+    press(&mut app, KeyCode::Char('1')).await;
+    let names: Vec<&str> = app.pods.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["z-0", "a-0", "m-0"]);
+
+    assert_eq!(
+        SortKey::parse_list(" -age, Name ,"),
+        vec![
+            SortKey {
+                key: "age".to_string(),
+                descending: true
+            },
+            SortKey {
+                key: "name".to_string(),
+                descending: false
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn configured_start_view_is_opened_and_unknown_ones_fall_back() {
+    let start_on = |view: &str| {
+        let config = Config {
+            start_view: Some(view.to_string()),
+            ..Default::default()
+        };
+        App::with_client(
+            Box::new(MockKubeApi::default()),
+            config,
+            vec![],
+            "test".to_string(),
+            vec!["default".to_string()],
+        )
+    };
+
+    assert_eq!(start_on("deploy").current_view, View::Deployments);
+    assert_eq!(start_on("Services").current_view, View::Services);
+
+    let app = start_on("nodes");
+    assert_eq!(app.current_view, View::Pods);
+    assert!(app.status_message.contains("Unknown start_view \"nodes\""));
+}
+
+#[tokio::test]
+async fn context_switch_can_require_confirmation() {
+    let config = Config {
+        confirm_context_switch: true,
+        ..Default::default()
+    };
+    let contexts = vec![ContextInfo {
+        name: "prod".to_string(),
+        cluster: "prod".to_string(),
+        server: "https://prod.example:6443".to_string(),
+        namespace: "default".to_string(),
+        is_current: false,
+    }];
+    let mut app = App::with_client(
+        Box::new(MockKubeApi::default()),
+        config,
+        contexts,
+        "test".to_string(),
+        vec!["default".to_string()],
+    );
+
+    press(&mut app, KeyCode::Char('4')).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(app.input_mode, InputMode::ConfirmContextSwitch);
+    assert_eq!(app.current_context, "test");
+
+    press(&mut app, KeyCode::Char('n')).await;
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(app.current_context, "test");
+    assert_eq!(app.current_view, View::Clusters);
+}
+
+#[tokio::test]
+async fn pod_summary_counts_pods_by_phase() {
+    let mut pending = pod("web-2");
+    pending.status = "Pending".to_string();
+    let mut done = pod("migrate");
+    done.status = "Succeeded".to_string();
+    let api = MockKubeApi {
+        pods: vec![pod("web-0"), pod("web-1"), pending, done],
+        ..Default::default()
+    };
+
+    let summary = api.namespace_pod_summary("default").await.unwrap();
+    assert_eq!(
+        summary,
+        PodSummary {
+            running: 2,
+            pending: 1,
+            failed: 0,
+            succeeded: 1,
+        }
+    );
+}
+
+#[tokio::test]
+async fn pod_actions_follow_the_selected_pod_across_watch_updates() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0"), pod("web-1")],
+        logs: "hello\n".to_string(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+    press(&mut app, KeyCode::Down).await;
+    assert_eq!(app.selected_pod().unwrap().name, "web-1");
+
+    // A new pod sorts in above the selection
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    app.pod_watcher = Some(PodWatcher { rx });
+    tx.send(vec![pod("api-0"), pod("web-0"), pod("web-1")])
+        .unwrap();
+    app.try_update_pods();
+
+    assert_eq!(app.selected_pod().unwrap().name, "web-1");
+    press(&mut app, KeyCode::Char('l')).await;
+    assert_eq!(app.logs_pod_name.as_deref(), Some("web-1"));
+}
+
+#[tokio::test]
+async fn shift_q_quits_and_reports_where_you_were() {
+    let mut app = app_with(MockKubeApi::default()).await;
+    assert_eq!(app.exit_location(), None);
+
+    let keep_running = app
+        .handle_event(InputEvent::Key(KeyEvent::new(
+            KeyCode::Char('Q'),
+            KeyModifiers::SHIFT,
+        )))
+        .await
+        .unwrap();
+
+    assert!(!keep_running);
+    let location = app.exit_location().unwrap();
+    assert!(location.contains("kubectl --context test -n default"));
+}
+
+#[tokio::test]
+async fn all_containers_stream_into_one_prefixed_log() {
+    let mut web = pod("web-0");
+    web.containers = ["app", "proxy"]
+        .into_iter()
+        .map(|name| ContainerSummary {
+            name: name.to_string(),
+            image: format!("{}:1.0", name),
+            health: ContainerHealth::Running,
+            last_termination: None,
+        })
+        .collect();
+    let api = MockKubeApi {
+        pods: vec![web],
+        logs: "starting\nready".to_string(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    press(&mut app, KeyCode::Char('A')).await;
+    assert_eq!(app.current_view, View::Logs);
+    assert!(app.logs_follow);
+    app.try_update_log_stream();
+    assert_eq!(
+        app.logs,
+        "[app] starting\n[proxy] starting\n[app] ready\n[proxy] ready"
+    );
+    assert_eq!(app.logs_scroll, 3);
+
+    // Switching to one container would drop the others
+    press(&mut app, KeyCode::Tab).await;
+    assert_eq!(app.logs_container, None);
+    assert!(app.status_message.starts_with("Not available"));
+
+    press(&mut app, KeyCode::Char('i')).await;
+    type_text(&mut app, "^\\[proxy\\]").await;
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(app.shown_log_line_count(), 2);
+
+    press(&mut app, KeyCode::Esc).await;
+    assert!(app.log_watcher.is_none());
+}
+
+#[tokio::test]
+async fn multi_container_pods_pick_a_container_before_logs_and_exec() {
+    let mut web = pod("web-0");
+    web.containers = ["app", "proxy", "metrics"]
+        .into_iter()
+        .map(|name| ContainerSummary {
+            name: name.to_string(),
+            image: format!("{}:1.0", name),
+            health: ContainerHealth::Running,
+            last_termination: None,
+        })
+        .collect();
+    web.default_container = Some("proxy".to_string());
+    let api = MockKubeApi {
+        pods: vec![web, pod("solo-0")],
+        logs: "hello\n".to_string(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    // The picker starts on the annotated default
+    press(&mut app, KeyCode::Char('l')).await;
+    assert_eq!(app.input_mode, InputMode::ContainerPicker);
+    assert_eq!(app.container_picker_index, 1);
+    press(&mut app, KeyCode::Down).await;
+    press(&mut app, KeyCode::Down).await;
+    assert_eq!(app.container_picker_index, 2);
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(app.current_view, View::Logs);
+    assert_eq!(app.logs_container.as_deref(), Some("metrics"));
+
+    press(&mut app, KeyCode::Esc).await;
+    press(&mut app, KeyCode::Char('L')).await;
+    press(&mut app, KeyCode::Char('1')).await;
+    assert_eq!(app.logs_container.as_deref(), Some("app"));
+    assert!(app.logs_follow);
+
+    // A shell asks too, after the terminal type; Esc backs out of both
+    press(&mut app, KeyCode::Esc).await;
+    press(&mut app, KeyCode::Char('e')).await;
+    press(&mut app, KeyCode::Char('2')).await;
+    assert_eq!(app.input_mode, InputMode::ContainerPicker);
+    assert_eq!(app.container_picker, Some(ContainerAction::NativeShell));
+    press(&mut app, KeyCode::Esc).await;
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(app.container_picker, None);
+
+    // Nothing to pick from in a single-container pod
+    press(&mut app, KeyCode::Down).await;
+    press(&mut app, KeyCode::Char('l')).await;
+    assert_eq!(app.current_view, View::Logs);
+    assert_eq!(app.logs_container, None);
+}
+
+#[tokio::test]
+async fn tab_cycles_log_containers() {
+    let mut web = pod("web-0");
+    web.containers = vec![
+        ContainerSummary {
+            name: "app".to_string(),
+            image: "nginx:1.27".to_string(),
+            health: ContainerHealth::CrashLooping,
+            last_termination: None,
+        },
+        ContainerSummary {
+            name: "proxy".to_string(),
+            image: "envoy:1.31".to_string(),
+            health: ContainerHealth::Running,
+            last_termination: None,
+        },
+    ];
+    let api = MockKubeApi {
+        pods: vec![web],
+        logs: "hello\n".to_string(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    press(&mut app, KeyCode::Char('l')).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(app.logs_container.as_deref(), Some("app"));
+    assert_eq!(
+        app.logs_pod_containers()[0].health,
+        ContainerHealth::CrashLooping
+    );
+
+    press(&mut app, KeyCode::Tab).await;
+    assert_eq!(app.logs_container.as_deref(), Some("proxy"));
+    press(&mut app, KeyCode::Tab).await;
+    assert_eq!(app.logs_container.as_deref(), Some("app"));
+    press(&mut app, KeyCode::BackTab).await;
+    assert_eq!(app.logs_container.as_deref(), Some("proxy"));
+}
+
+#[tokio::test]
+async fn colon_switches_views_by_name() {
+    let api = MockKubeApi {
+        deployments: vec![deployment("worker")],
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    press(&mut app, KeyCode::Char(':')).await;
+    assert_eq!(app.input_mode, InputMode::ViewPicker);
+    type_text(&mut app, "dep").await;
+    assert_eq!(app.view_picker_matches()[0].1, View::Deployments);
+    press(&mut app, KeyCode::Enter).await;
+
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(app.current_view, View::Deployments);
+    assert_eq!(app.deployments.len(), 1);
+
+    // Aliases work too, and Tab completes to the full name
+    press(&mut app, KeyCode::Char(':')).await;
+    type_text(&mut app, "svc").await;
+    press(&mut app, KeyCode::Tab).await;
+    assert_eq!(app.input_buffer, "services");
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(app.current_view, View::Services);
+}
+
+#[tokio::test]
+async fn labels_and_annotations_are_patched_from_a_prompt() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Char('=')).await;
+    assert_eq!(app.input_mode, InputMode::MetadataEdit);
+    type_text(&mut app, "debug=true").await;
+    press(&mut app, KeyCode::Enter).await;
+
+    press(&mut app, KeyCode::Char('=')).await;
+    type_text(&mut app, "@owner=alice").await;
+    press(&mut app, KeyCode::Enter).await;
+
+    press(&mut app, KeyCode::Char('=')).await;
+    type_text(&mut app, "debug-").await;
+    press(&mut app, KeyCode::Enter).await;
+
+    press(&mut app, KeyCode::Char('=')).await;
+    type_text(&mut app, "nonsense").await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(app.error_message.is_some());
+
+    assert_eq!(
+        api.calls(),
+        vec![
+            "label pod default/web-0 debug=true",
+            "annotate pod default/web-0 owner=alice",
+            "label pod default/web-0 debug-",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn cordoning_a_pods_node_asks_first() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Char('C')).await;
+    assert_eq!(app.input_mode, InputMode::ConfirmCordon);
+    assert_eq!(app.cordon_node.as_deref(), Some("node-a"));
+    press(&mut app, KeyCode::Char('n')).await;
+    assert!(api.calls().is_empty());
+
+    press(&mut app, KeyCode::Char('C')).await;
+    press(&mut app, KeyCode::Char('y')).await;
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(api.calls(), vec!["cordon node node-a"]);
+}
+
+#[tokio::test]
+async fn selected_pods_node_is_described_in_the_detail_pane() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    press(&mut app, KeyCode::Char('N')).await;
+    assert_eq!(app.current_view, View::Detail);
+    match &app.detail {
+        Some(Detail::Node(node)) => {
+            assert_eq!(node.name, "node-a");
+            assert_eq!(node.pod_count, Some(1));
+        }
+        other => panic!("expected node detail, got {:?}", other),
+    }
+
+    press(&mut app, KeyCode::Esc).await;
+    assert_eq!(app.current_view, View::Pods);
+}
+
+#[tokio::test]
+async fn pods_can_be_grouped_by_node() {
+    let on = |name: &str, node: &str| PodInfo {
+        node: node.to_string(),
+        ..pod(name)
+    };
+    let api = MockKubeApi {
+        pods: vec![
+            on("api-0", "node-b"),
+            on("pending-0", "<none>"),
+            on("web-0", "node-a"),
+            on("web-1", "node-b"),
+        ],
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+    press(&mut app, KeyCode::Down).await;
+    press(&mut app, KeyCode::Down).await;
+    assert_eq!(app.selected_pod().unwrap().name, "web-0");
+
+    press(&mut app, KeyCode::Char('G')).await;
+    let names: Vec<&str> = app.pods.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["web-0", "api-0", "web-1", "pending-0"]);
+    assert_eq!(app.selected_pod().unwrap().name, "web-0");
+
+    press(&mut app, KeyCode::Char('G')).await;
+    let names: Vec<&str> = app.pods.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["api-0", "pending-0", "web-0", "web-1"]);
+    assert_eq!(app.selected_pod().unwrap().name, "web-0");
+}
+
+#[tokio::test]
+async fn node_shell_names_the_node_and_can_be_cancelled() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Char('E')).await;
+    assert_eq!(app.input_mode, InputMode::ConfirmNodeShell);
+    assert_eq!(app.node_shell_target.as_deref(), Some("node-a"));
+
+    press(&mut app, KeyCode::Char('n')).await;
+    assert_eq!(app.input_mode, InputMode::Normal);
+    assert_eq!(app.node_shell_target, None);
+    assert!(app.terminal_sessions.is_empty());
+    assert_eq!(app.status_message, "Node shell cancelled");
+}
+
+#[tokio::test]
+async fn debug_container_reports_clusters_without_the_feature() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        ephemeral_containers_disabled: true,
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Char('D')).await;
+    assert_eq!(app.input_mode, InputMode::ConfirmDebugContainer);
+    press(&mut app, KeyCode::Char('n')).await;
+    assert!(api.calls().is_empty());
+
+    press(&mut app, KeyCode::Char('D')).await;
+    press(&mut app, KeyCode::Char('y')).await;
+    assert_eq!(api.calls(), vec!["debug pod default/web-0 (target -)"]);
+    assert!(app
+        .error_message
+        .as_deref()
+        .unwrap()
+        .contains("ephemeral containers aren't enabled"));
+    assert!(app.terminal_sessions.is_empty());
+    assert_eq!(app.current_view, View::Pods);
+}
+
+#[tokio::test]
+async fn debug_container_is_waited_on_from_the_main_loop() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        debug_container_pending: true,
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Char('D')).await;
+    press(&mut app, KeyCode::Char('y')).await;
+    // The key handler returns right after adding the container
+    assert_eq!(api.calls(), vec!["debug pod default/web-0 (target -)"]);
+    assert!(app.debug_start.is_some());
+    assert!(app
+        .status_message
+        .contains("Waiting for debug container debugger"));
+
+    app.advance_debug_container().await;
+    assert_eq!(
+        api.calls()[1..],
+        ["check debug container default/web-0/debugger"]
+    );
+    // Checked at most once a second
+    app.advance_debug_container().await;
+    assert_eq!(api.calls().len(), 2);
+
+    let start = app.debug_start.as_mut().unwrap();
+    start.started -= DEBUG_CONTAINER_START_TIMEOUT;
+    start.last_check = None;
+    app.advance_debug_container().await;
+    assert!(app.debug_start.is_none());
+    assert!(app
+        .error_message
+        .as_deref()
+        .unwrap()
+        .contains("didn't start within 30s"));
+    assert_eq!(app.current_view, View::Pods);
+}
+
+#[tokio::test]
+async fn completed_jobs_are_deleted_together_after_one_confirmation() {
+    let api = MockKubeApi {
+        completed_jobs: vec!["backup-1".to_string(), "backup-2".to_string()],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+
+    press(&mut app, KeyCode::Char('J')).await;
+    assert_eq!(app.input_mode, InputMode::ConfirmJobCleanup);
+    assert_eq!(app.completed_jobs.len(), 2);
+    press(&mut app, KeyCode::Char('y')).await;
+
+    // Deleting them waits out the countdown, which Esc aborts
+    assert_eq!(
+        app.countdown.as_ref().unwrap().action.describe(),
+        "Deleting 2 completed jobs"
+    );
+    press(&mut app, KeyCode::Esc).await;
+    assert!(app.countdown.is_none());
+    assert!(api.calls().is_empty());
+
+    press(&mut app, KeyCode::Char('J')).await;
+    press(&mut app, KeyCode::Char('y')).await;
+    app.countdown.as_mut().unwrap().deadline = Instant::now();
+    app.run_due_action().await.unwrap();
+
+    assert_eq!(
+        api.calls(),
+        vec!["delete job default/backup-1", "delete job default/backup-2"]
+    );
+    assert_eq!(app.status_message, "Deleted 2 of 2 completed jobs");
+}
+
+#[tokio::test]
+async fn deleting_a_deployment_can_orphan_its_pods() {
+    let api = MockKubeApi {
+        deployments: vec![deployment("web")],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+    press(&mut app, KeyCode::Char('2')).await;
+
+    press(&mut app, KeyCode::Char('d')).await;
+    assert_eq!(app.input_mode, InputMode::ConfirmDelete);
+    assert_eq!(app.delete_propagation, DeletePropagation::Background);
+    press(&mut app, KeyCode::Char('o')).await;
+    assert!(api.calls().is_empty());
+    press(&mut app, KeyCode::Char('y')).await;
+
+    assert_eq!(api.calls(), vec!["delete deployment default/web (Orphan)"]);
+}
+
+#[tokio::test]
+async fn followed_events_stream_in_newest_first() {
+    let api = MockKubeApi {
+        events: vec![
+            event("old", "Normal", 30),
+            event("new", "Warning", 1),
+            event("mid", "Normal", 10),
+        ],
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+    press(&mut app, KeyCode::Char('6')).await;
+    assert!(app.events.is_empty());
+
+    press(&mut app, KeyCode::Char('f')).await;
+    assert!(app.events_follow);
+    app.try_update_events();
+
+    let names: Vec<&str> = app.events.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, vec!["new", "mid", "old"]);
+    assert_eq!(app.event_index, 0);
+
+    press(&mut app, KeyCode::Char('1')).await;
+    app.try_update_events();
+    assert!(!app.events_follow);
+}
+
+#[tokio::test]
+async fn followed_events_keep_the_configured_sort() {
+    let api = MockKubeApi {
+        events: vec![
+            event("old", "Normal", 30),
+            event("new", "Warning", 1),
+            event("mid", "Normal", 10),
+        ],
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+    app.config.events.sort = Some("type,-last-seen".to_string());
+    press(&mut app, KeyCode::Char('6')).await;
+
+    press(&mut app, KeyCode::Char('f')).await;
+    app.try_update_events();
+
+    let names: Vec<&str> = app.events.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, vec!["mid", "old", "new"]);
+}
+
+#[tokio::test]
+async fn window_resizes_are_not_taken_for_key_presses() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        logs: "hello".to_string(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+    press(&mut app, KeyCode::Char('l')).await;
+    press(&mut app, KeyCode::Char('g')).await;
+    type_text(&mut app, "err").await;
+
+    let resize = InputEvent::Resize {
+        cols: 200,
+        rows: 50,
+    };
+    assert!(app.handle_event(resize).await.unwrap());
+    assert_eq!(app.input_mode, InputMode::LogGrep);
+    assert_eq!(app.input_buffer, "err");
+}
+
+#[tokio::test]
+async fn grep_keeps_only_matching_log_lines() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        logs: "GET /health 200\nPOST /login 500\nGET /health 200\nPOST /login 200".to_string(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+    press(&mut app, KeyCode::Char('l')).await;
+
+    press(&mut app, KeyCode::Char('g')).await;
+    assert_eq!(app.input_mode, InputMode::LogGrep);
+    type_text(&mut app, "login").await;
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(app.logs, "POST /login 500\nPOST /login 200");
+
+    // An empty pattern brings the whole log back
+    press(&mut app, KeyCode::Char('g')).await;
+    for _ in 0.."login".len() {
+        press(&mut app, KeyCode::Backspace).await;
+    }
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(app.logs_grep, None);
+    assert_eq!(app.logs.lines().count(), 4);
+}
+
+#[tokio::test]
+async fn regex_filters_include_and_exclude_log_lines_as_drawn() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        logs: "GET /health 200\nPOST /login 500\nGET /health 200\nPOST /login 200\nGET /api 503"
+            .to_string(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+    press(&mut app, KeyCode::Char('l')).await;
+
+    press(&mut app, KeyCode::Char('x')).await;
+    assert_eq!(app.input_mode, InputMode::LogExclude);
+    type_text(&mut app, "/health").await;
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(app.shown_log_line_count(), 3);
+
+    press(&mut app, KeyCode::Char('i')).await;
+    type_text(&mut app, " 5\\d\\d$").await;
+    press(&mut app, KeyCode::Enter).await;
+    assert_eq!(app.shown_log_line_count(), 2);
+    assert!(app.log_line_shown("POST /login 500"));
+    assert!(!app.log_line_shown("GET /health 500"));
+    // The fetched log is untouched; only what is drawn changes
+    assert_eq!(app.logs.lines().count(), 5);
+
+    // A bad pattern is reported and keeps the filter that was there
+    press(&mut app, KeyCode::Char('i')).await;
+    assert_eq!(app.input_buffer, " 5\\d\\d$");
+    type_text(&mut app, "(").await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(app
+        .error_message
+        .as_deref()
+        .unwrap()
+        .starts_with("Invalid regex"));
+    assert_eq!(app.shown_log_line_count(), 2);
+
+    // An empty pattern clears just that filter
+    press(&mut app, KeyCode::Char('x')).await;
+    for _ in 0.."/health".len() {
+        press(&mut app, KeyCode::Backspace).await;
+    }
+    press(&mut app, KeyCode::Enter).await;
+    assert!(app.logs_exclude.is_none());
+    assert_eq!(app.shown_log_line_count(), 2);
+}
+
+#[tokio::test]
+async fn selected_pod_summary_names_node_owner_and_qos() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0"), pod("web-1")],
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+    press(&mut app, KeyCode::Down).await;
+
+    let summary = app.selected_pod().unwrap().summary_line();
+    assert!(summary.contains("node node-a"));
+    assert!(summary.contains("qos BestEffort"));
+    assert!(summary.contains("owner ReplicaSet/web-1"));
+    assert!(!summary.contains("last restart"));
+}
+
+#[tokio::test]
+async fn pod_status_display_toggles_and_is_saved() {
+    let path = std::env::temp_dir().join(format!("qui-pod-status-{}.yaml", std::process::id()));
+    let mut app = App::with_client(
+        Box::new(MockKubeApi {
+            pods: vec![pod("web-0")],
+            ..Default::default()
+        }),
+        Config::load_from(path.clone()).unwrap(),
+        vec![],
+        "test".to_string(),
+        vec!["default".to_string()],
+    );
+    press(&mut app, KeyCode::Char('1')).await;
+    assert_eq!(app.config.pod_status, PodStatusDisplay::Phase);
+
+    press(&mut app, KeyCode::Char('S')).await;
+    assert_eq!(app.config.pod_status, PodStatusDisplay::Computed);
+    let saved = std::fs::read_to_string(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert!(saved.contains("pod_status: computed"));
+}
+
+#[tokio::test]
+async fn system_namespaces_can_be_hidden_and_the_choice_is_saved() {
+    let path = std::env::temp_dir().join(format!("qui-system-ns-{}.yaml", std::process::id()));
+    let mut app = App::with_client(
+        Box::new(MockKubeApi::default()),
+        Config::load_from(path.clone()).unwrap(),
+        vec![],
+        "test".to_string(),
+        vec![
+            "default".to_string(),
+            "kube-node-lease".to_string(),
+            "kube-system".to_string(),
+            "payments".to_string(),
+        ],
+    );
+    press(&mut app, KeyCode::Char('5')).await;
+    assert_eq!(app.namespaces.len(), 4);
+
+    press(&mut app, KeyCode::Char('s')).await;
+    assert_eq!(app.namespaces, vec!["default", "payments"]);
+    assert_eq!(app.status_message, "Hiding 2 system namespace(s)");
+    let saved = std::fs::read_to_string(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert!(saved.contains("hide_system: true"));
+
+    press(&mut app, KeyCode::Char('s')).await;
+    assert_eq!(app.namespaces.len(), 4);
+}
+
+#[tokio::test]
+async fn rollback_to_an_older_revision_asks_first() {
+    let api = MockKubeApi {
+        deployments: vec![deployment("web")],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+    press(&mut app, KeyCode::Char('2')).await;
+    press(&mut app, KeyCode::Char('H')).await;
+    assert!(matches!(app.detail, Some(Detail::History(_))));
+
+    // The live revision is a no-op
+    press(&mut app, KeyCode::Char('u')).await;
+    assert_eq!(app.input_mode, InputMode::Normal);
+
+    press(&mut app, KeyCode::Down).await;
+    press(&mut app, KeyCode::Char('u')).await;
+    assert_eq!(app.input_mode, InputMode::ConfirmRollback);
+    assert!(api.calls().is_empty());
+    press(&mut app, KeyCode::Char('y')).await;
+
+    assert_eq!(api.calls(), vec!["rollback deployment default/web to 1"]);
+    assert_eq!(app.current_view, View::Deployments);
+}
+
+#[tokio::test]
+async fn confirmed_context_switch_is_stepped_by_the_main_loop() {
+    let config = Config {
+        confirm_context_switch: true,
+        ..Default::default()
+    };
+    let contexts = vec![ContextInfo {
+        name: "prod".to_string(),
+        cluster: "prod".to_string(),
+        server: "https://prod.example:6443".to_string(),
+        namespace: "default".to_string(),
+        is_current: false,
+    }];
+    let mut app = App::with_client(
+        Box::new(MockKubeApi::default()),
+        config,
+        contexts,
+        "test".to_string(),
+        vec!["default".to_string()],
+    );
+
+    press(&mut app, KeyCode::Char('4')).await;
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Char('y')).await;
+
+    // Nothing has run yet; the footer says what's about to
+    let switch = app.context_switch.as_ref().unwrap();
+    assert_eq!(switch.step, ContextSwitchStep::Switching);
+    assert_eq!(switch.context.name, "prod");
+    assert_eq!(app.status_message, "Switching to context: prod...");
+    assert_eq!(app.current_context, "test");
+}
+
+#[tokio::test]
+async fn last_error_is_kept_for_copying_after_the_footer_clears() {
+    let mut app = app_with(MockKubeApi::default()).await;
+
+    press(&mut app, KeyCode::Char('Y')).await;
+    assert_eq!(app.status_message, "No error to copy");
+
+    app.error_message = Some("Failed to delete pod: forbidden".to_string());
+    press(&mut app, KeyCode::Char('r')).await;
+
+    assert!(app.error_message.is_none());
+    assert_eq!(
+        app.last_error.as_deref(),
+        Some("Failed to delete pod: forbidden")
+    );
+}
+
+#[tokio::test]
+async fn log_preview_follows_the_selected_pod() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0"), pod("web-1")],
+        logs: "listening on :8080".to_string(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    press(&mut app, KeyCode::Char('p')).await;
+    assert!(app.show_log_preview);
+    assert_eq!(app.current_view, View::Pods);
+    let preview = app.log_preview.as_ref().unwrap();
+    assert_eq!(preview.pod, "web-0");
+    assert_eq!(preview.logs, "listening on :8080");
+
+    press(&mut app, KeyCode::Down).await;
+    app.sync_log_preview().await;
+    assert_eq!(app.log_preview.as_ref().unwrap().pod, "web-1");
+
+    press(&mut app, KeyCode::Char('p')).await;
+    assert!(!app.show_log_preview);
+    assert!(app.log_preview.is_none());
+}
+
+#[tokio::test]
+async fn logs_title_names_how_the_shown_container_last_ended() {
+    let mut web = pod("web-0");
+    web.containers = vec![
+        ContainerSummary {
+            name: "app".to_string(),
+            image: "nginx:1.27".to_string(),
+            health: ContainerHealth::Running,
+            last_termination: Some(TerminationInfo {
+                container: "app".to_string(),
+                reason: "OOMKilled".to_string(),
+                exit_code: 137,
+                finished_at: None,
+                restart_count: 4,
+            }),
+        },
+        ContainerSummary {
+            name: "proxy".to_string(),
+            image: "envoy:1.31".to_string(),
+            health: ContainerHealth::Running,
+            last_termination: None,
+        },
+    ];
+    let api = MockKubeApi {
+        pods: vec![web],
+        logs: "hello\n".to_string(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    press(&mut app, KeyCode::Char('l')).await;
+    press(&mut app, KeyCode::Enter).await;
+    // The pod's own restart total (0 here) doesn't say which restart it was
+    let termination = app.logs_last_termination().unwrap();
+    assert_eq!(
+        (
+            termination.exit_code,
+            termination.reason.as_str(),
+            termination.restart_count
+        ),
+        (137, "OOMKilled", 4)
+    );
+
+    press(&mut app, KeyCode::Tab).await;
+    assert!(app.logs_last_termination().is_none());
+
+    assert!(app.logs_wrap);
+    press(&mut app, KeyCode::Char('w')).await;
+    assert!(!app.logs_wrap);
+}
+
+#[tokio::test]
+async fn returning_to_a_view_reloads_it_only_once_stale() {
+    let api = MockKubeApi {
+        deployments: vec![deployment("web")],
+        ..Default::default()
+    };
+    let mut app = app_with(api.clone()).await;
+    let deployment_lists = |api: &MockKubeApi| {
+        api.lists()
+            .iter()
+            .filter(|l| l.starts_with("list deployments"))
+            .count()
+    };
+
+    press(&mut app, KeyCode::Char('2')).await;
+    press(&mut app, KeyCode::Char('3')).await;
+    press(&mut app, KeyCode::Char('2')).await;
+    assert_eq!(deployment_lists(&api), 1);
+
+    // `r` always reloads
+    press(&mut app, KeyCode::Char('r')).await;
+    assert_eq!(deployment_lists(&api), 2);
+}
+
+#[tokio::test]
+async fn pinned_pods_stay_on_top_and_are_flagged_when_gone() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0"), pod("web-1"), pod("web-2")],
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+
+    press(&mut app, KeyCode::Down).await;
+    press(&mut app, KeyCode::Down).await;
+    press(&mut app, KeyCode::Char('P')).await;
+
+    let names: Vec<&str> = app.pods.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["web-2", "web-0", "web-1"]);
+    assert_eq!(app.pod_index, 0);
+    assert!(app.missing_pins().is_empty());
+
+    // The pod is replaced; the pin stays and says so
+    app.pods.retain(|p| p.name != "web-2");
+    assert_eq!(app.missing_pins(), vec!["web-2"]);
+}
+
+#[tokio::test]
+async fn logs_can_be_piped_into_a_pager() {
+    let api = MockKubeApi {
+        pods: vec![pod("web-0")],
+        logs: "GET /healthz 200\nGET /api 500\n".to_string(),
+        ..Default::default()
+    };
+    let mut app = app_with(api).await;
+    let path = std::env::temp_dir().join(format!("qui-pager-{}.log", std::process::id()));
+    app.config.logs.pager = Some(format!("cat > {}", path.display()));
+
+    press(&mut app, KeyCode::Char('l')).await;
+    press(&mut app, KeyCode::Char('o')).await;
+
+    let command = app.pending_command.take().unwrap();
+    assert!(matches!(&command, ExternalCommand::Page { pager, .. } if pager.starts_with("cat")));
+    command.run().unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "GET /healthz 200\nGET /api 500\n"
+    );
+    let _ = std::fs::remove_file(path);
+}
+
+#[tokio::test]
+async fn contexts_sharing_a_cluster_are_listed_together() {
+    let context = |name: &str, cluster: &str| ContextInfo {
+        name: name.to_string(),
+        cluster: cluster.to_string(),
+        server: format!("https://{}.example:6443", cluster),
+        namespace: "default".to_string(),
+        is_current: false,
+    };
+    let config = Config {
+        favorites: qui::config::FavoritesConfig {
+            contexts: vec!["staging".to_string()],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let app = App::with_client(
+        Box::new(MockKubeApi::default()),
+        config,
+        vec![
+            context("prod-admin", "prod"),
+            context("staging", "staging"),
+            context("dev", "dev"),
+            context("prod-readonly", "prod"),
+            context("ci", "prod"),
+        ],
+        "dev".to_string(),
+        vec!["default".to_string()],
+    );
+
+    let names: Vec<&str> = app.contexts.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec!["staging", "dev", "ci", "prod-admin", "prod-readonly"]
+    );
+}
+
+#[tokio::test]
+async fn ctrl_p_reports_how_fast_the_cluster_answers() {
+    let mut app = app_with(MockKubeApi::default()).await;
+
+    app.handle_event(InputEvent::Key(KeyEvent::new(
+        KeyCode::Char('p'),
+        KeyModifiers::CONTROL,
+    )))
+    .await
+    .unwrap();
+
+    assert_eq!(app.status_message, "test answered in 42 ms");
+    assert_eq!(app.current_view, View::Pods);
+    assert!(!app.show_log_preview);
+}
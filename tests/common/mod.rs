@@ -0,0 +1,621 @@
+//! Canned cluster for driving `App` in tests without an API server.
+
+#![allow(dead_code)]
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use qui::app::App;
+use qui::events::InputEvent;
+use qui::kube_client::{
+    ConfigMapDetail, ConfigMapInfo, ContainerEnv, ContainerLogLine, ContainerUsage, ContextInfo,
+    DeletePropagation, DeploymentDetail, DeploymentHistory, DeploymentInfo, EnvValue, EnvVarInfo,
+    EventInfo, EventWatcher, KubeApi, LogQuery, NamespaceSummary, NodeDetail, PodDetail, PodInfo,
+    PodLogWatcher, PodSummary, PodWatcher, PortForwardHandle, ResourceKind, RevisionInfo,
+    RolloutState, RolloutStatus, RolloutWatcher, SearchResult, SecretDetail, SecretInfo,
+    ServiceDetail, ServiceInfo,
+};
+use qui::{Config, QuiError};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Returns the canned resources and records every mutating call as a
+/// readable string, e.g. `delete pod default/web-0`. List calls are counted
+/// apart in `lists`, so they don't clutter `calls`.
+#[derive(Default, Clone)]
+pub struct MockKubeApi {
+    pub pods: Vec<PodInfo>,
+    pub deployments: Vec<DeploymentInfo>,
+    pub services: Vec<ServiceInfo>,
+    pub logs: String,
+    pub completed_jobs: Vec<String>,
+    pub events: Vec<EventInfo>,
+    // ConfigMap name to its data
+    pub configmaps: BTreeMap<String, BTreeMap<String, String>>,
+    // Secret name to its decoded data
+    pub secrets: BTreeMap<String, BTreeMap<String, Vec<u8>>>,
+    pub ephemeral_containers_disabled: bool,
+    // The debug container never gets past waiting
+    pub debug_container_pending: bool,
+    // What metrics-server reports for every pod; None as if it isn't installed
+    pub pod_metrics: Option<Vec<ContainerUsage>>,
+    // Flip to make the API server stop answering pings
+    pub unreachable: Arc<Mutex<bool>>,
+    pub calls: Arc<Mutex<Vec<String>>>,
+    pub lists: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockKubeApi {
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn record(&self, call: String) {
+        self.calls.lock().unwrap().push(call);
+    }
+
+    pub fn lists(&self) -> Vec<String> {
+        self.lists.lock().unwrap().clone()
+    }
+
+    fn record_list(&self, call: String) {
+        self.lists.lock().unwrap().push(call);
+    }
+}
+
+#[async_trait]
+impl KubeApi for MockKubeApi {
+    async fn ping(&self) -> Result<Duration> {
+        if *self.unreachable.lock().unwrap() {
+            return Err(QuiError::Unreachable("connection refused".to_string()).into());
+        }
+        Ok(Duration::from_millis(42))
+    }
+
+    async fn list_namespaces(&self) -> Result<Vec<String>> {
+        Ok(vec!["default".to_string(), "staging".to_string()])
+    }
+
+    async fn list_pods(&self, namespace: &str) -> Result<Vec<PodInfo>> {
+        self.record_list(format!("list pods {}", namespace));
+        Ok(self.pods.clone())
+    }
+
+    async fn watch_pods(&self, _namespace: &str) -> Result<PodWatcher> {
+        // A watcher that never sends, so the listed pods stay put
+        let (_tx, rx) = mpsc::unbounded_channel();
+        Ok(PodWatcher { rx })
+    }
+
+    async fn describe_pod(&self, namespace: &str, name: &str) -> Result<PodDetail> {
+        let pod = self
+            .pods
+            .iter()
+            .find(|p| p.name == name)
+            .ok_or_else(|| anyhow!("pod {} not found", name))?;
+        Ok(PodDetail {
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+            phase: pod.status.clone(),
+            node: Some(pod.node.clone()),
+            pod_ip: None,
+            conditions: vec![],
+            node_selector: BTreeMap::new(),
+            affinity: vec![],
+            tolerations: vec![],
+            containers: vec![],
+            probes: vec![],
+            volumes: vec![],
+            mounts: vec![],
+            rollout_annotations: BTreeMap::new(),
+        })
+    }
+
+    async fn pod_metrics(
+        &self,
+        _namespace: &str,
+        _name: &str,
+    ) -> Result<Option<Vec<ContainerUsage>>> {
+        Ok(self.pod_metrics.clone())
+    }
+
+    async fn describe_node(&self, name: &str) -> Result<NodeDetail> {
+        Ok(NodeDetail {
+            name: name.to_string(),
+            roles: vec![],
+            unschedulable: false,
+            conditions: vec![],
+            resources: vec![],
+            taints: vec![],
+            labels: BTreeMap::new(),
+            kubelet_version: "v1.31.0".to_string(),
+            os_image: String::new(),
+            kernel_version: String::new(),
+            container_runtime: String::new(),
+            pod_count: Some(self.pods.len()),
+        })
+    }
+
+    async fn delete_pod(&self, namespace: &str, name: &str) -> Result<()> {
+        self.record(format!("delete pod {}/{}", namespace, name));
+        Ok(())
+    }
+
+    async fn get_pod_logs(
+        &self,
+        _namespace: &str,
+        _name: &str,
+        _query: &LogQuery,
+    ) -> Result<String> {
+        Ok(self.logs.clone())
+    }
+
+    async fn follow_pod_logs(
+        &self,
+        _namespace: &str,
+        _name: &str,
+        containers: &[String],
+        _tail_lines: i64,
+        _timestamps: bool,
+    ) -> Result<PodLogWatcher> {
+        // Every container replays the canned log, interleaved line by line
+        let (tx, rx) = mpsc::unbounded_channel();
+        for line in self.logs.lines() {
+            for container in containers {
+                let _ = tx.send(ContainerLogLine {
+                    container: container.clone(),
+                    line: line.to_string(),
+                });
+            }
+        }
+        Ok(PodLogWatcher { rx, tasks: vec![] })
+    }
+
+    async fn list_deployments(&self, namespace: &str) -> Result<Vec<DeploymentInfo>> {
+        self.record_list(format!("list deployments {}", namespace));
+        Ok(self.deployments.clone())
+    }
+
+    async fn delete_deployment(
+        &self,
+        namespace: &str,
+        name: &str,
+        propagation: DeletePropagation,
+    ) -> Result<()> {
+        self.record(format!(
+            "delete deployment {}/{} ({})",
+            namespace,
+            name,
+            propagation.as_str()
+        ));
+        Ok(())
+    }
+
+    async fn scale_deployment(&self, namespace: &str, name: &str, replicas: i32) -> Result<()> {
+        self.record(format!(
+            "scale deployment {}/{} {}",
+            namespace, name, replicas
+        ));
+        Ok(())
+    }
+
+    async fn watch_rollout(&self, _namespace: &str, name: &str) -> Result<RolloutWatcher> {
+        // Reports the rollout as already finished
+        let (tx, rx) = mpsc::unbounded_channel();
+        let _ = tx.send(RolloutStatus {
+            state: RolloutState::Complete,
+            message: format!("deployment \"{}\" successfully rolled out", name),
+            desired: 1,
+            updated: 1,
+            available: 1,
+        });
+        Ok(RolloutWatcher { rx })
+    }
+
+    async fn describe_deployment(&self, namespace: &str, name: &str) -> Result<DeploymentDetail> {
+        // One container with a plain and a secret-sourced variable
+        Ok(DeploymentDetail {
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+            replicas: "1/1".to_string(),
+            strategy: "RollingUpdate".to_string(),
+            containers: vec![ContainerEnv {
+                name: "app".to_string(),
+                image: "app:1".to_string(),
+                env: vec![
+                    EnvVarInfo {
+                        name: "LOG_LEVEL".to_string(),
+                        value: EnvValue::Plain("info".to_string()),
+                    },
+                    EnvVarInfo {
+                        name: "DB_PASSWORD".to_string(),
+                        value: EnvValue::SecretKey {
+                            name: "db".to_string(),
+                            key: "password".to_string(),
+                        },
+                    },
+                ],
+                env_from: vec![],
+            }],
+            rollout_annotations: BTreeMap::from([(
+                "checksum/config".to_string(),
+                "abc123".to_string(),
+            )]),
+            pod_count: 2,
+            stale_pods: vec![format!("{}-old", name)],
+        })
+    }
+
+    async fn deployment_history(&self, namespace: &str, name: &str) -> Result<DeploymentHistory> {
+        // Two revisions, the newer one live
+        let revision = |revision: i64, image: &str| RevisionInfo {
+            revision,
+            replica_set: format!("{}-{}", name, revision),
+            change_cause: Some(format!("image {}", image)),
+            images: vec![image.to_string()],
+            age: "1d".to_string(),
+        };
+        Ok(DeploymentHistory {
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+            current_revision: Some(2),
+            revisions: vec![revision(2, "app:2"), revision(1, "app:1")],
+        })
+    }
+
+    async fn rollback_deployment(&self, namespace: &str, name: &str, revision: i64) -> Result<()> {
+        self.record(format!(
+            "rollback deployment {}/{} to {}",
+            namespace, name, revision
+        ));
+        Ok(())
+    }
+
+    async fn set_deployment_env(
+        &self,
+        namespace: &str,
+        name: &str,
+        container: &str,
+        variable: &str,
+        value: &str,
+    ) -> Result<()> {
+        self.record(format!(
+            "set env deployment {}/{} {} {}={}",
+            namespace, name, container, variable, value
+        ));
+        Ok(())
+    }
+
+    async fn list_services(&self, namespace: &str) -> Result<Vec<ServiceInfo>> {
+        self.record_list(format!("list services {}", namespace));
+        Ok(self.services.clone())
+    }
+
+    async fn describe_service(&self, _namespace: &str, _name: &str) -> Result<ServiceDetail> {
+        Err(anyhow!("describe not supported by the mock"))
+    }
+
+    async fn list_events(&self, _namespace: &str) -> Result<Vec<EventInfo>> {
+        Ok(vec![])
+    }
+
+    async fn list_configmaps(&self, namespace: &str) -> Result<Vec<ConfigMapInfo>> {
+        self.record_list(format!("list configmaps {}", namespace));
+        Ok(self
+            .configmaps
+            .iter()
+            .map(|(name, data)| ConfigMapInfo {
+                name: name.clone(),
+                data_keys: data.len(),
+                age: "1d".to_string(),
+                created: None,
+            })
+            .collect())
+    }
+
+    async fn describe_configmap(&self, namespace: &str, name: &str) -> Result<ConfigMapDetail> {
+        let data = self
+            .configmaps
+            .get(name)
+            .ok_or_else(|| anyhow!("configmap {} not found", name))?;
+        Ok(ConfigMapDetail {
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+            data: data.clone(),
+            binary_data: BTreeMap::new(),
+        })
+    }
+
+    async fn list_secrets(&self, namespace: &str) -> Result<Vec<SecretInfo>> {
+        self.record_list(format!("list secrets {}", namespace));
+        Ok(self
+            .secrets
+            .iter()
+            .map(|(name, data)| SecretInfo {
+                name: name.clone(),
+                type_: "Opaque".to_string(),
+                key_count: data.len(),
+                age: "1d".to_string(),
+                created: None,
+            })
+            .collect())
+    }
+
+    async fn describe_secret(&self, namespace: &str, name: &str) -> Result<SecretDetail> {
+        let data = self
+            .secrets
+            .get(name)
+            .ok_or_else(|| anyhow!("secret {} not found", name))?;
+        Ok(SecretDetail {
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+            type_: "Opaque".to_string(),
+            data: data.clone(),
+        })
+    }
+
+    async fn watch_events(&self, _namespace: &str) -> Result<EventWatcher> {
+        // Replays the canned events as the initial burst
+        let (tx, rx) = mpsc::unbounded_channel();
+        for event in &self.events {
+            let _ = tx.send(event.clone());
+        }
+        Ok(EventWatcher { rx })
+    }
+
+    async fn count_recent_warnings(&self, _namespace: &str) -> Result<usize> {
+        Ok(0)
+    }
+
+    async fn namespace_summary(&self, namespace: &str) -> Result<NamespaceSummary> {
+        let mut pod_statuses = BTreeMap::new();
+        for pod in &self.pods {
+            *pod_statuses.entry(pod.status.clone()).or_insert(0) += 1;
+        }
+        Ok(NamespaceSummary {
+            namespace: namespace.to_string(),
+            pod_statuses: Some(pod_statuses),
+            deployments: Some(self.deployments.len()),
+            services: Some(self.services.len()),
+            config_maps: None,
+            warnings: Some(vec![]),
+        })
+    }
+
+    async fn namespace_pod_summary(&self, _namespace: &str) -> Result<PodSummary> {
+        Ok(PodSummary::from_pods(&self.pods))
+    }
+
+    async fn get_resource_json(
+        &self,
+        _kind: ResourceKind,
+        _namespace: &str,
+        name: &str,
+    ) -> Result<serde_json::Value> {
+        Ok(serde_json::json!({ "metadata": { "name": name } }))
+    }
+
+    async fn kubectl_describe(&self, kind: &str, namespace: &str, name: &str) -> Result<String> {
+        Ok(format!(
+            "Name:         {}\nKind:         {}\nNamespace:    {}\n",
+            name, kind, namespace
+        ))
+    }
+
+    async fn port_forward(
+        &self,
+        namespace: &str,
+        pod: &str,
+        local_port: u16,
+        remote_port: u16,
+    ) -> Result<PortForwardHandle> {
+        self.record(format!(
+            "port-forward {}/{} {}:{}",
+            namespace, pod, local_port, remote_port
+        ));
+        // A real listener, so tests can see the port freed again
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", local_port)).await?;
+        let local_addr = listener.local_addr()?;
+        let task = tokio::spawn(async move {
+            while let Ok((connection, _)) = listener.accept().await {
+                drop(connection);
+            }
+        });
+        Ok(PortForwardHandle {
+            namespace: namespace.to_string(),
+            pod: pod.to_string(),
+            local_addr,
+            remote_port,
+            last_error: Arc::new(Mutex::new(None)),
+            task,
+        })
+    }
+
+    async fn patch_labels(
+        &self,
+        kind: ResourceKind,
+        namespace: &str,
+        name: &str,
+        labels: &BTreeMap<String, Option<String>>,
+    ) -> Result<()> {
+        for (key, value) in labels {
+            self.record(format!(
+                "label {} {}/{} {}",
+                kind.as_str(),
+                namespace,
+                name,
+                describe_change(key, value)
+            ));
+        }
+        Ok(())
+    }
+
+    async fn cordon_node(&self, node: &str) -> Result<()> {
+        self.record(format!("cordon node {}", node));
+        Ok(())
+    }
+
+    async fn list_completed_jobs(&self, _namespace: &str) -> Result<Vec<String>> {
+        Ok(self.completed_jobs.clone())
+    }
+
+    async fn delete_job(&self, namespace: &str, name: &str) -> Result<()> {
+        self.record(format!("delete job {}/{}", namespace, name));
+        Ok(())
+    }
+
+    async fn patch_annotations(
+        &self,
+        kind: ResourceKind,
+        namespace: &str,
+        name: &str,
+        annotations: &BTreeMap<String, Option<String>>,
+    ) -> Result<()> {
+        for (key, value) in annotations {
+            self.record(format!(
+                "annotate {} {}/{} {}",
+                kind.as_str(),
+                namespace,
+                name,
+                describe_change(key, value)
+            ));
+        }
+        Ok(())
+    }
+
+    async fn add_debug_container(
+        &self,
+        namespace: &str,
+        pod: &str,
+        target: Option<&str>,
+    ) -> Result<String> {
+        self.record(format!(
+            "debug pod {}/{} (target {})",
+            namespace,
+            pod,
+            target.unwrap_or("-")
+        ));
+        if self.ephemeral_containers_disabled {
+            return Err(anyhow!(
+                "ephemeral containers aren't enabled on this cluster (they need Kubernetes 1.23+)"
+            ));
+        }
+        Ok("debugger".to_string())
+    }
+
+    async fn debug_container_running(
+        &self,
+        namespace: &str,
+        pod: &str,
+        container: &str,
+    ) -> Result<bool> {
+        self.record(format!(
+            "check debug container {}/{}/{}",
+            namespace, pod, container
+        ));
+        Ok(!self.debug_container_pending)
+    }
+
+    async fn search_resources(&self, query: &str) -> Result<Vec<SearchResult>> {
+        Ok(self
+            .pods
+            .iter()
+            .filter(|p| p.name.contains(query))
+            .map(|p| SearchResult {
+                kind: ResourceKind::Pod,
+                namespace: p._namespace.clone(),
+                name: p.name.clone(),
+            })
+            .collect())
+    }
+}
+
+/// `key=value` for a set, `key-` for a removal, as kubectl label writes them
+fn describe_change(key: &str, value: &Option<String>) -> String {
+    match value {
+        Some(value) => format!("{}={}", key, value),
+        None => format!("{}-", key),
+    }
+}
+
+pub fn pod(name: &str) -> PodInfo {
+    PodInfo {
+        name: name.to_string(),
+        _namespace: "default".to_string(),
+        status: "Running".to_string(),
+        ready: "1/1".to_string(),
+        restarts: 0,
+        age: "1h".to_string(),
+        created: None,
+        node: "node-a".to_string(),
+        ip: "10.0.0.1".to_string(),
+        image: "nginx:1.27".to_string(),
+        containers: vec![],
+        default_container: None,
+        last_termination: None,
+        computed_status: "Running".to_string(),
+        qos_class: "BestEffort".to_string(),
+        owner: Some(format!("ReplicaSet/{}", name)),
+        init: None,
+    }
+}
+
+pub fn event(name: &str, type_: &str, minutes_ago: i64) -> EventInfo {
+    EventInfo {
+        name: name.to_string(),
+        type_: type_.to_string(),
+        reason: "BackOff".to_string(),
+        object: "pod/web-0".to_string(),
+        message: "Back-off restarting failed container".to_string(),
+        count: 1,
+        last_seen: Some(chrono::Utc::now() - chrono::Duration::minutes(minutes_ago)),
+        age: format!("{}m", minutes_ago),
+    }
+}
+
+pub fn deployment(name: &str) -> DeploymentInfo {
+    DeploymentInfo {
+        name: name.to_string(),
+        _namespace: "default".to_string(),
+        ready: "1/1".to_string(),
+        replicas: 1,
+        up_to_date: 1,
+        available: 1,
+        age: "1h".to_string(),
+        created: None,
+    }
+}
+
+/// An app on the Pods view of a single `test` context, with the pods loaded
+pub async fn app_with(api: MockKubeApi) -> App {
+    let contexts = vec![ContextInfo {
+        name: "test".to_string(),
+        cluster: "test".to_string(),
+        server: "https://127.0.0.1:6443".to_string(),
+        namespace: "default".to_string(),
+        is_current: true,
+    }];
+    let mut app = App::with_client(
+        Box::new(api),
+        Config::default(),
+        contexts,
+        "test".to_string(),
+        vec!["default".to_string(), "staging".to_string()],
+    );
+    press(&mut app, KeyCode::Char('1')).await;
+    app
+}
+
+pub async fn press(app: &mut App, code: KeyCode) -> bool {
+    app.handle_event(InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+        .await
+        .unwrap()
+}
+
+pub async fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        press(app, KeyCode::Char(c)).await;
+    }
+}
@@ -0,0 +1,494 @@
+use qui::kube_client::{
+    parse_quantity, redact_credentials, token_file_identity, ContainerUsage, DeploymentDetail,
+    InitProgress, KubeApi, LogQuery, MountInfo, NodeDetail, NodeResource, PodDetail,
+};
+use qui::{KubeClient, PodInfo, QuiError};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+#[tokio::test]
+async fn client_builds_from_an_explicit_config() {
+    // Nothing listens here; building the client must not need a reachable server
+    let config = kube::Config::new("http://127.0.0.1:9".parse().unwrap());
+    let client = KubeClient::from_config(config);
+    assert!(client.is_ok());
+}
+
+#[tokio::test]
+async fn client_reports_disabled_tls_verification() {
+    let mut config = kube::Config::new("https://127.0.0.1:9".parse().unwrap());
+    assert!(!KubeClient::from_config(config.clone())
+        .unwrap()
+        .tls_verification_disabled());
+
+    config.accept_invalid_certs = true;
+    assert!(KubeClient::from_config(config)
+        .unwrap()
+        .tls_verification_disabled());
+}
+
+/// A client for a one-shot API server that answers the first request with `body`
+async fn client_answering(content_type: &'static str, body: &'static [u8]) -> KubeClient {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = [0u8; 4096];
+        let _ = socket.read(&mut request).await.unwrap();
+        let head = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            content_type,
+            body.len()
+        );
+        socket.write_all(head.as_bytes()).await.unwrap();
+        socket.write_all(body).await.unwrap();
+    });
+
+    let config = kube::Config::new(format!("http://{}", address).parse().unwrap());
+    KubeClient::from_config(config).unwrap()
+}
+
+#[tokio::test]
+async fn pod_metrics_are_read_in_millicores_and_bytes() {
+    let client = client_answering(
+        "application/json",
+        br#"{"kind":"PodMetrics","apiVersion":"metrics.k8s.io/v1beta1",
+            "metadata":{"name":"web-0","namespace":"default"},
+            "timestamp":"2024-05-01T10:00:00Z","window":"15s",
+            "containers":[{"name":"web","usage":{"cpu":"250123456n","memory":"131072Ki"}}]}"#,
+    )
+    .await;
+
+    let usage = client.pod_metrics("default", "web-0").await.unwrap();
+    assert_eq!(
+        usage,
+        Some(vec![ContainerUsage {
+            container: "web".to_string(),
+            cpu_millis: 250,
+            memory_bytes: 128 * 1024 * 1024,
+        }])
+    );
+}
+
+#[test]
+fn quantities_are_parsed_with_decimal_and_binary_suffixes() {
+    assert_eq!(parse_quantity("250m"), Some(0.25));
+    assert_eq!(parse_quantity("2"), Some(2.0));
+    assert_eq!(
+        parse_quantity("1.5Gi"),
+        Some(1.5 * 1024.0 * 1024.0 * 1024.0)
+    );
+    assert_eq!(parse_quantity("3M"), Some(3e6));
+    assert_eq!(parse_quantity("lots"), None);
+    assert_eq!(parse_quantity("12Xi"), None);
+}
+
+#[tokio::test]
+async fn logs_with_invalid_utf8_are_decoded_lossily() {
+    // A stray Latin-1 byte in the middle of the log
+    let client = client_answering("text/plain", b"caf\xe9 opened\nready\n").await;
+    let query = LogQuery {
+        container: None,
+        previous: false,
+        tail_lines: 100,
+        timestamps: false,
+    };
+    let logs = client.get_pod_logs("default", "web", &query).await.unwrap();
+
+    assert_eq!(logs, "caf\u{fffd} opened\nready\n");
+}
+
+#[tokio::test]
+async fn followed_logs_arrive_line_by_line_until_the_stream_ends() {
+    let client = client_answering("text/plain", b"caf\xe9 opened\r\nready\n").await;
+    let mut watcher = client
+        .follow_pod_logs("default", "web", &["app".to_string()], 100, false)
+        .await
+        .unwrap();
+
+    let mut lines = Vec::new();
+    while let Some(line) = watcher.rx.recv().await {
+        assert_eq!(line.container, "app");
+        lines.push(line.line);
+    }
+    assert_eq!(
+        lines,
+        vec!["caf\u{fffd} opened", "ready", "--- log stream ended ---"]
+    );
+}
+
+#[tokio::test]
+async fn ping_times_a_version_request() {
+    let client = client_answering(
+        "application/json",
+        br#"{"major":"1","minor":"31","gitVersion":"v1.31.0","gitCommit":"","gitTreeState":"clean","buildDate":"","goVersion":"","compiler":"gc","platform":"linux/amd64"}"#,
+    )
+    .await;
+
+    let latency = client.ping().await.unwrap();
+    assert!(latency < std::time::Duration::from_secs(5));
+}
+
+#[test]
+fn api_errors_are_classified_by_status_code() {
+    let forbidden = kube::Error::Api(kube::core::ErrorResponse {
+        status: "Failure".to_string(),
+        message: "pods is forbidden".to_string(),
+        reason: "Forbidden".to_string(),
+        code: 403,
+    });
+    let err = anyhow::Error::new(forbidden).context("listing pods");
+    let category = QuiError::classify(&err);
+    assert_eq!(
+        category,
+        QuiError::Forbidden("pods is forbidden".to_string())
+    );
+    assert!(category.hint().unwrap().contains("RBAC"));
+    assert!(!category.is_transient());
+
+    let err: anyhow::Error = QuiError::NotConnected.into();
+    assert_eq!(QuiError::classify(&err), QuiError::NotConnected);
+    assert_eq!(
+        QuiError::classify(&anyhow::anyhow!("boom")),
+        QuiError::Other("boom".to_string())
+    );
+}
+
+#[tokio::test]
+async fn client_rejects_proxies_it_cannot_use() {
+    let mut config = kube::Config::new("https://127.0.0.1:9".parse().unwrap());
+    config.proxy_url = Some("http://127.0.0.1:3128".parse().unwrap());
+    assert!(KubeClient::from_config(config.clone()).is_ok());
+
+    // Would otherwise be ignored and the API server dialled directly
+    config.proxy_url = Some("socks5://127.0.0.1:1080".parse().unwrap());
+    let err = KubeClient::from_config(config).err().unwrap();
+    assert!(err.to_string().contains("only http:// proxies"));
+}
+
+#[test]
+fn node_shell_command_chroots_into_the_host() {
+    assert_eq!(
+        qui::kube_client::node_shell_command("default", "node-a", &[]),
+        "kubectl debug node/node-a -it -n default --profile=sysadmin --image=busybox:1.36 -- chroot /host /bin/sh"
+    );
+}
+
+#[test]
+fn kubectl_runs_impersonate_like_the_client() {
+    let config = qui::Config {
+        impersonate: Some("jane".to_string()),
+        impersonate_groups: vec!["dev".to_string(), "qa".to_string()],
+        ..Default::default()
+    };
+    let identity = qui::kube_client::kubectl_identity(&config);
+    assert_eq!(identity, ["--as=jane", "--as-group=dev", "--as-group=qa"]);
+    // Flags go before the command run on the node
+    assert_eq!(
+        qui::kube_client::node_shell_command("default", "node-a", &identity),
+        "kubectl debug node/node-a -it -n default --profile=sysadmin --image=busybox:1.36 --as=jane --as-group=dev --as-group=qa -- chroot /host /bin/sh"
+    );
+}
+
+#[test]
+fn node_detail_pairs_capacity_with_allocatable() {
+    let node: k8s_openapi::api::core::v1::Node = serde_json::from_value(serde_json::json!({
+        "metadata": {
+            "name": "node-a",
+            "labels": {
+                "node-role.kubernetes.io/control-plane": "",
+                "kubernetes.io/os": "linux"
+            }
+        },
+        "spec": {
+            "unschedulable": true,
+            "taints": [{ "key": "dedicated", "value": "db", "effect": "NoSchedule" }]
+        },
+        "status": {
+            "capacity": { "cpu": "4", "memory": "16Gi" },
+            "allocatable": { "cpu": "3800m", "memory": "15Gi" },
+            "conditions": [{ "type": "Ready", "status": "True", "reason": "KubeletReady" }],
+            "nodeInfo": {
+                "kubeletVersion": "v1.31.2",
+                "osImage": "Ubuntu 24.04",
+                "kernelVersion": "6.8.0",
+                "containerRuntimeVersion": "containerd://1.7.22",
+                "architecture": "amd64",
+                "bootID": "",
+                "kubeProxyVersion": "",
+                "machineID": "",
+                "operatingSystem": "linux",
+                "systemUUID": ""
+            }
+        }
+    }))
+    .unwrap();
+
+    let detail = NodeDetail::from_node(&node, Some(7));
+    assert_eq!(detail.roles, vec!["control-plane"]);
+    assert!(detail.unschedulable);
+    assert_eq!(detail.taints, vec!["dedicated=db:NoSchedule"]);
+    assert_eq!(
+        detail.resources[0],
+        NodeResource {
+            name: "cpu".to_string(),
+            capacity: "4".to_string(),
+            allocatable: "3800m".to_string(),
+        }
+    );
+    assert_eq!(detail.container_runtime, "containerd://1.7.22");
+    assert_eq!(detail.pod_count, Some(7));
+}
+
+#[test]
+fn init_progress_names_what_blocks_the_next_init_container() {
+    let pod: k8s_openapi::api::core::v1::Pod = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "web-0", "namespace": "default" },
+        "spec": {
+            "initContainers": [
+                { "name": "proxy", "restartPolicy": "Always" },
+                { "name": "migrate" },
+                { "name": "warm-cache" }
+            ],
+            "containers": [{ "name": "app" }]
+        },
+        "status": {
+            "phase": "Pending",
+            "initContainerStatuses": [
+                { "name": "proxy", "image": "", "imageID": "", "ready": true, "restartCount": 0,
+                  "state": { "running": {} } },
+                { "name": "migrate", "image": "", "imageID": "", "ready": false, "restartCount": 4,
+                  "state": { "waiting": { "reason": "CrashLoopBackOff" } } },
+                { "name": "warm-cache", "image": "", "imageID": "", "ready": false, "restartCount": 0,
+                  "state": { "waiting": { "reason": "PodInitializing" } } }
+            ]
+        }
+    }))
+    .unwrap();
+
+    let init = PodInfo::from_pod(&pod).init.unwrap();
+    assert_eq!(
+        init,
+        InitProgress {
+            done: 1,
+            total: 3,
+            blocked: Some("CrashLoopBackOff".to_string()),
+        }
+    );
+    assert_eq!(init.to_string(), "1/3 CrashLoopBackOff");
+    assert!(!init.is_complete());
+}
+
+#[test]
+fn pod_detail_lists_volumes_and_where_they_are_mounted() {
+    let pod: k8s_openapi::api::core::v1::Pod = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "web-0", "namespace": "default" },
+        "spec": {
+            "volumes": [
+                { "name": "config", "configMap": { "name": "app-config" } },
+                { "name": "data", "persistentVolumeClaim": { "claimName": "web-data" } },
+                { "name": "scratch", "emptyDir": {} }
+            ],
+            "containers": [
+                {
+                    "name": "app",
+                    "volumeMounts": [
+                        { "name": "config", "mountPath": "/etc/app", "readOnly": true },
+                        { "name": "data", "mountPath": "/var/lib/app", "subPath": "web" }
+                    ]
+                },
+                { "name": "sidecar" }
+            ]
+        }
+    }))
+    .unwrap();
+
+    let detail = PodDetail::from_pod(&pod);
+    let sources: Vec<&str> = detail.volumes.iter().map(|v| v.source.as_str()).collect();
+    assert_eq!(
+        sources,
+        vec!["configMap app-config", "pvc web-data", "emptyDir"]
+    );
+
+    assert_eq!(detail.mounts.len(), 1);
+    let (container, mounts) = &detail.mounts[0];
+    assert_eq!(container, "app");
+    assert_eq!(
+        mounts[0],
+        MountInfo {
+            volume: "config".to_string(),
+            path: "/etc/app".to_string(),
+            sub_path: None,
+            read_only: true,
+        }
+    );
+    assert_eq!(mounts[1].sub_path.as_deref(), Some("web"));
+}
+
+#[test]
+fn default_container_annotation_picks_the_log_and_exec_target() {
+    let pod_with = |default: &str| -> k8s_openapi::api::core::v1::Pod {
+        serde_json::from_value(serde_json::json!({
+            "metadata": {
+                "name": "web-0",
+                "namespace": "default",
+                "annotations": { "kubectl.kubernetes.io/default-container": default }
+            },
+            "spec": {
+                "containers": [
+                    { "name": "istio-proxy", "image": "istio/proxyv2:1.23" },
+                    { "name": "app", "image": "web:2.1" }
+                ]
+            }
+        }))
+        .unwrap()
+    };
+
+    let info = PodInfo::from_pod(&pod_with("app"));
+    assert_eq!(info.default_container.as_deref(), Some("app"));
+    assert_eq!(info.log_container().as_deref(), Some("app"));
+    assert_eq!(info.image, "web:2.1");
+
+    // Naming a container the pod doesn't have falls back to the first, as in kubectl
+    let info = PodInfo::from_pod(&pod_with("missing"));
+    assert_eq!(info.default_container, None);
+    assert_eq!(info.log_container().as_deref(), Some("istio-proxy"));
+    assert_eq!(info.image, "istio/proxyv2:1.23");
+}
+
+#[test]
+fn rollout_annotations_flag_pods_left_on_an_old_config() {
+    let deployment: k8s_openapi::api::apps::v1::Deployment =
+        serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "web", "namespace": "default" },
+            "spec": {
+                "selector": { "matchLabels": { "app": "web" } },
+                "template": {
+                    "metadata": {
+                        "annotations": {
+                            "checksum/config": "new",
+                            "kubectl.kubernetes.io/restartedAt": "2026-10-01T10:00:00Z",
+                            "prometheus.io/scrape": "true"
+                        }
+                    },
+                    "spec": { "containers": [{ "name": "app" }] }
+                }
+            }
+        }))
+        .unwrap();
+    let pod = |name: &str, checksum: &str| -> k8s_openapi::api::core::v1::Pod {
+        serde_json::from_value(serde_json::json!({
+            "metadata": {
+                "name": name,
+                "namespace": "default",
+                "annotations": {
+                    "checksum/config": checksum,
+                    "kubectl.kubernetes.io/restartedAt": "2026-10-01T10:00:00Z",
+                    "prometheus.io/scrape": "true"
+                }
+            },
+            "spec": { "containers": [{ "name": "app" }] }
+        }))
+        .unwrap()
+    };
+    let pods = vec![pod("web-a", "new"), pod("web-b", "old")];
+
+    let mut detail = DeploymentDetail::from_deployment(&deployment);
+    // Unrelated annotations are left out
+    assert_eq!(
+        detail.rollout_annotations.keys().collect::<Vec<_>>(),
+        vec!["checksum/config", "kubectl.kubernetes.io/restartedAt"]
+    );
+    detail.compare_pods(&pods);
+    assert_eq!(detail.pod_count, 2);
+    assert_eq!(detail.stale_pods, vec!["web-b"]);
+
+    let pod_detail = PodDetail::from_pod(&pods[1]);
+    assert_eq!(pod_detail.rollout_annotations["checksum/config"], "old");
+}
+
+#[test]
+fn token_file_identity_reads_the_subject_of_the_jwt() {
+    let path = std::env::temp_dir().join(format!("qui-token-{}", std::process::id()));
+    std::fs::write(
+        &path,
+        "eyJhbGciOiJSUzI1NiJ9.eyJpc3MiOiJrdWJlcm5ldGVzL3NlcnZpY2VhY2NvdW50Iiwic3ViIjoic3lzdGVtOnNlcnZpY2VhY2NvdW50OmNpOnF1aSJ9.c2ln\n",
+    )
+    .unwrap();
+    assert_eq!(
+        token_file_identity(&path).as_deref(),
+        Some("system:serviceaccount:ci:qui")
+    );
+
+    // Opaque tokens don't say who they belong to
+    std::fs::write(&path, "abcdef0123456789").unwrap();
+    assert_eq!(token_file_identity(&path), None);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn kubeconfig_credentials_are_redacted_at_any_depth() {
+    let mut kubeconfig = serde_json::json!({
+        "clusters": [{ "name": "prod", "cluster": {
+            "server": "https://prod:6443",
+            "certificate-authority-data": "LS0tLS1CRUdJTi..."
+        }}],
+        "users": [
+            { "name": "admin", "user": { "token": "s3cret", "client-key-data": "LS0t" } },
+            { "name": "oidc", "user": { "auth-provider": { "name": "oidc", "config": {
+                "client-id": "qui",
+                "id-token": "eyJ..."
+            }}}}
+        ]
+    });
+    redact_credentials(&mut kubeconfig);
+
+    let users = &kubeconfig["users"];
+    assert_eq!(users[0]["user"]["token"], "<redacted>");
+    assert_eq!(users[0]["user"]["client-key-data"], "<redacted>");
+    assert_eq!(
+        users[1]["user"]["auth-provider"]["config"]["id-token"],
+        "<redacted>"
+    );
+    assert_eq!(
+        users[1]["user"]["auth-provider"]["config"]["client-id"],
+        "qui"
+    );
+    let cluster = &kubeconfig["clusters"][0]["cluster"];
+    assert_eq!(cluster["certificate-authority-data"], "<redacted>");
+    assert_eq!(cluster["server"], "https://prod:6443");
+}
+
+#[test]
+fn exec_plugin_env_values_and_secret_args_are_redacted() {
+    let mut kubeconfig = serde_json::json!({
+        "users": [{ "name": "eks", "user": { "exec": {
+            "command": "aws-iam-authenticator",
+            "args": ["token", "-i", "prod", "--token", "abc123", "--api-key=k3y", "--region=eu-west-1"],
+            "env": [
+                { "name": "AWS_PROFILE", "value": "prod" },
+                { "name": "AWS_SECRET_ACCESS_KEY", "value": "wJalr..." }
+            ]
+        }}}]
+    });
+    redact_credentials(&mut kubeconfig);
+
+    let exec = &kubeconfig["users"][0]["user"]["exec"];
+    assert_eq!(exec["command"], "aws-iam-authenticator");
+    assert_eq!(exec["env"][0]["name"], "AWS_PROFILE");
+    assert_eq!(exec["env"][0]["value"], "<redacted>");
+    assert_eq!(exec["env"][1]["name"], "AWS_SECRET_ACCESS_KEY");
+    assert_eq!(exec["env"][1]["value"], "<redacted>");
+    assert_eq!(
+        exec["args"],
+        serde_json::json!([
+            "token",
+            "-i",
+            "prod",
+            "--token",
+            "<redacted>",
+            "--api-key=<redacted>",
+            "--region=eu-west-1"
+        ])
+    );
+}
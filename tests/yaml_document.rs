@@ -0,0 +1,37 @@
+use qui::app::YamlDocument;
+use qui::kube_client::ResourceKind;
+use serde_json::json;
+
+fn document(show_managed_fields: bool, show_status: bool) -> YamlDocument {
+    YamlDocument {
+        kind: ResourceKind::Pod,
+        name: "web-0".to_string(),
+        object: json!({
+            "metadata": {
+                "name": "web-0",
+                "managedFields": [{ "manager": "kubectl" }],
+            },
+            "spec": { "nodeName": "node-a" },
+            "status": { "phase": "Running" },
+        }),
+        show_managed_fields,
+        show_status,
+    }
+}
+
+#[test]
+fn hides_managed_fields_and_status_by_default() {
+    let yaml = document(false, false).to_yaml();
+
+    assert!(yaml.contains("nodeName: node-a"));
+    assert!(!yaml.contains("managedFields"));
+    assert!(!yaml.contains("phase"));
+}
+
+#[test]
+fn shows_managed_fields_and_status_when_toggled() {
+    let yaml = document(true, true).to_yaml();
+
+    assert!(yaml.contains("managedFields"));
+    assert!(yaml.contains("phase: Running"));
+}